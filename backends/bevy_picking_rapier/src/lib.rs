@@ -48,19 +48,32 @@ impl Plugin for RapierBackend {
     fn build(&self, app: &mut App) {
         app.init_resource::<RapierBackendSettings>()
             .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend))
+            .add_systems(PickingProbe, update_hits.in_set(PickSet::Backend))
             .register_type::<RapierBackendSettings>()
             .register_type::<RapierPickable>();
     }
 }
 
 /// Runtime settings for the [`RapierBackend`].
-#[derive(Resource, Default, Reflect)]
+#[derive(Resource, Reflect)]
 #[reflect(Resource, Default)]
 pub struct RapierBackendSettings {
     /// When set to `true` raycasting will only happen between cameras and entities marked with
     /// [`RapierPickable`]. Off by default. This setting is provided to give you fine-grained
     /// control over which cameras and entities should be used by the rapier backend at runtime.
     pub require_markers: bool,
+    /// Only entities whose [`PickLayers`] intersect this mask will be raycasted against. Entities
+    /// without [`PickLayers`] are on every layer. Defaults to [`PickLayers::ALL`].
+    pub pick_layers: PickLayers,
+}
+
+impl Default for RapierBackendSettings {
+    fn default() -> Self {
+        Self {
+            require_markers: false,
+            pick_layers: PickLayers::ALL,
+        }
+    }
 }
 
 /// Optional. Marks cameras and target entities that should be used in the rapier picking backend.
@@ -78,6 +91,7 @@ pub fn update_hits(
     pickables: Query<&Pickable>,
     marked_targets: Query<&RapierPickable>,
     layers: Query<&RenderLayers>,
+    pick_layers: Query<&PickLayers>,
     rapier_context: Option<Res<RapierContext>>,
     mut output_events: EventWriter<PointerHits>,
 ) {
@@ -103,12 +117,18 @@ pub fn update_hits(
             let entity_layers = layers.get(entity).copied().unwrap_or_default();
             let render_layers_match = cam_layers.intersects(&entity_layers);
 
+            let pick_layers_match = pick_layers
+                .get(entity)
+                .copied()
+                .unwrap_or_default()
+                .intersects(&backend_settings.pick_layers);
+
             let is_pickable = pickables
                 .get(entity)
                 .map(|p| *p != Pickable::IGNORE)
                 .unwrap_or(true);
 
-            marker_requirement && render_layers_match && is_pickable
+            marker_requirement && render_layers_match && pick_layers_match && is_pickable
         };
         if let Some((entity, hit_data)) = rapier_context
             .cast_ray_and_get_normal(
@@ -124,11 +144,14 @@ pub fn update_hits(
                 (entity, hit_data)
             })
         {
-            output_events.send(PointerHits::new(
-                ray_id.pointer,
-                vec![(entity, hit_data)],
-                camera.order as f32,
-            ));
+            output_events.send(
+                PointerHits::new(
+                    ray_id.pointer,
+                    vec![(entity, hit_data)],
+                    camera.order as f32,
+                )
+                .with_backend(BackendId::of::<RapierBackend>()),
+            );
         }
     }
 }
@@ -10,14 +10,22 @@
 //!
 //! For fine-grained control, see the [`RaycastBackendSettings::require_markers`] setting.
 //!
+//! Pointers with a [`PointerRadius`](bevy_picking_core::pointer::PointerRadius) cast every ray of
+//! [`RayMap::area_rays`], and report each entity hit by any of them, with its nearest hit, sorted
+//! from nearest to farthest. Each ray still stops at the first entity that blocks lower entities,
+//! so a blocking entity only hides what is behind it from the rays that hit it. Entities behind it
+//! that are hit by other rays of the area are still reported.
+//!
 
 #![allow(clippy::too_many_arguments, clippy::type_complexity)]
 #![deny(missing_docs)]
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
+use bevy_math::Ray3d;
 use bevy_reflect::prelude::*;
 use bevy_render::{prelude::*, view::RenderLayers};
+use bevy_utils::{hashbrown::hash_map::Entry, HashMap};
 
 use bevy_mod_raycast::prelude::*;
 use bevy_picking_core::backend::prelude::*;
@@ -41,6 +49,9 @@ pub struct RaycastBackendSettings {
     /// When set to Ignore, hidden items can be raycasted against.
     /// See [`RaycastSettings::visibility`] for more information.
     pub raycast_visibility: RaycastVisibility,
+    /// Only entities whose [`PickLayers`] intersect this mask will be raycasted against. Entities
+    /// without [`PickLayers`] are on every layer. Defaults to [`PickLayers::ALL`].
+    pub pick_layers: PickLayers,
 }
 
 impl Default for RaycastBackendSettings {
@@ -48,6 +59,7 @@ impl Default for RaycastBackendSettings {
         Self {
             require_markers: false,
             raycast_visibility: RaycastVisibility::MustBeVisibleAndInView,
+            pick_layers: PickLayers::ALL,
         }
     }
 }
@@ -65,6 +77,7 @@ impl Plugin for RaycastBackend {
     fn build(&self, app: &mut App) {
         app.init_resource::<RaycastBackendSettings>()
             .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend))
+            .add_systems(PickingProbe, update_hits.in_set(PickSet::Backend))
             .register_type::<RaycastPickable>()
             .register_type::<RaycastBackendSettings>();
     }
@@ -74,11 +87,13 @@ impl Plugin for RaycastBackend {
 /// [`PointerHits`].
 pub fn update_hits(
     backend_settings: Res<RaycastBackendSettings>,
+    focus_debug: Res<FocusDebugSettings>,
     ray_map: Res<RayMap>,
     picking_cameras: Query<(&Camera, Option<&RaycastPickable>, Option<&RenderLayers>)>,
     pickables: Query<&Pickable>,
     marked_targets: Query<&RaycastPickable>,
     layers: Query<&RenderLayers>,
+    pick_layers: Query<&PickLayers>,
     mut raycast: Raycast,
     mut output_events: EventWriter<PointerHits>,
 ) {
@@ -102,35 +117,225 @@ pub fn update_hits(
                 let entity_layers = layers.get(entity).copied().unwrap_or_default();
                 let render_layers_match = cam_layers.intersects(&entity_layers);
 
+                let pick_layers_match = pick_layers
+                    .get(entity)
+                    .copied()
+                    .unwrap_or_default()
+                    .intersects(&backend_settings.pick_layers);
+
                 let is_pickable = pickables
                     .get(entity)
                     .map(|p| p.is_hoverable)
                     .unwrap_or(true);
 
-                marker_requirement && render_layers_match && is_pickable
+                marker_requirement && render_layers_match && pick_layers_match && is_pickable
             },
             early_exit_test: &|entity_hit| {
-                pickables
-                    .get(entity_hit)
-                    .is_ok_and(|pickable| pickable.should_block_lower)
+                !focus_debug.ignore_blocking
+                    && pickables
+                        .get(entity_hit)
+                        .is_ok_and(|pickable| pickable.should_block_lower)
             },
         };
-        let picks = raycast
-            .cast_ray(ray, &settings)
-            .iter()
-            .map(|(entity, hit)| {
-                let hit_data = HitData::new(
-                    ray_id.camera,
-                    hit.distance(),
-                    Some(hit.position()),
-                    Some(hit.normal()),
-                );
-                (*entity, hit_data)
-            })
-            .collect::<Vec<_>>();
+        let hit_data = |ray: Ray3d, hit: &IntersectionData| {
+            let face = hit.triangle().and_then(|[a, b, c]| {
+                Face::from_triangle([a.into(), b.into(), c.into()], *ray.direction)
+            });
+            HitData::new(
+                ray_id.camera,
+                hit.distance(),
+                Some(hit.position()),
+                Some(hit.normal()),
+            )
+            .with_face(face)
+            .with_part(hit.triangle_index().map(|index| index as u64))
+        };
+        let area_rays = ray_map.area_rays(ray_id);
+        let picks = if area_rays.is_empty() {
+            raycast
+                .cast_ray(ray, &settings)
+                .iter()
+                .map(|(entity, hit)| (*entity, hit_data(ray, hit)))
+                .collect::<Vec<_>>()
+        } else {
+            // Keep the nearest hit of each entity hit by any of the rays.
+            let mut nearest = HashMap::<Entity, HitData>::new();
+            for &area_ray in area_rays {
+                for (entity, hit) in raycast.cast_ray(area_ray, &settings) {
+                    match nearest.entry(*entity) {
+                        Entry::Occupied(mut entry) if hit.distance() < entry.get().depth => {
+                            entry.insert(hit_data(area_ray, hit));
+                        }
+                        Entry::Occupied(_) => {}
+                        Entry::Vacant(entry) => {
+                            entry.insert(hit_data(area_ray, hit));
+                        }
+                    }
+                }
+            }
+            let mut picks = nearest.into_iter().collect::<Vec<_>>();
+            // The map is unordered, so sort the hits to report them in a deterministic order.
+            picks.sort_by(|(a_entity, a), (b_entity, b)| {
+                a.depth.total_cmp(&b.depth).then(a_entity.cmp(b_entity))
+            });
+            picks
+        };
         let order = camera.order as f32;
         if !picks.is_empty() {
-            output_events.send(PointerHits::new(ray_id.pointer, picks, order));
+            output_events.send(
+                PointerHits::new(ray_id.pointer, picks, order)
+                    .with_backend(BackendId::of::<RaycastBackend>()),
+            );
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_math::{primitives::Cuboid, Vec2, Vec3};
+    use bevy_picking_core::{
+        backend::ray::NdcMapper,
+        pointer::{Location, PointerId},
+        CorePlugin, PointerCoreBundle,
+    };
+    use bevy_render::{
+        camera::{camera_system, ManualTextureViews, NormalizedRenderTarget},
+        texture::Image,
+    };
+    use bevy_transform::prelude::*;
+    use bevy_window::{
+        PrimaryWindow, Window, WindowCreated, WindowRef, WindowResized, WindowScaleFactorChanged,
+    };
+
+    use super::*;
+
+    /// A headless app with a window, a camera looking at the origin, and the mouse pointer at the
+    /// center of the window.
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, RaycastBackend))
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<AssetEvent<Image>>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_systems(First, camera_system::<Projection>)
+            .insert_resource(RaycastBackendSettings {
+                raycast_visibility: RaycastVisibility::Ignore,
+                ..Default::default()
+            });
+        let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+        let camera_transform = Transform::from_xyz(0.0, 0.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y);
+        app.world.spawn((
+            Camera::default(),
+            Projection::default(),
+            GlobalTransform::from(camera_transform),
+        ));
+        let target = WindowRef::Entity(window).normalize(None).unwrap();
+        let location = Location {
+            target: NormalizedRenderTarget::Window(target),
+            position: Vec2::new(640.0, 360.0),
+        };
+        app.world
+            .spawn(PointerCoreBundle::new(PointerId::Mouse).with_location(location));
+        app
+    }
+
+    /// Spawns a unit cube centered at `z`.
+    fn spawn_cube(app: &mut App, z: f32) -> Entity {
+        let mesh = Mesh::from(Cuboid::default());
+        let aabb = mesh.compute_aabb().unwrap();
+        let mesh = app.world.resource_mut::<Assets<Mesh>>().add(mesh);
+        let transform = GlobalTransform::from_translation(Vec3::Z * z);
+        let visibility = (InheritedVisibility::VISIBLE, ViewVisibility::default());
+        app.world.spawn((mesh, aabb, transform, visibility)).id()
+    }
+
+    fn hit_entities(app: &App) -> Vec<Entity> {
+        app.world
+            .resource::<Events<PointerHits>>()
+            .iter_current_update_events()
+            .flat_map(|hits| hits.picks.iter().map(|(entity, _)| *entity))
+            .collect()
+    }
+
+    #[test]
+    fn pick_layers_filter_hit_entities() {
+        let mut app = app();
+        app.world
+            .resource_mut::<RaycastBackendSettings>()
+            .pick_layers = PickLayers::layer(1);
+        let near = spawn_cube(&mut app, 1.0);
+        let far = spawn_cube(&mut app, -1.0);
+        app.world.entity_mut(near).insert(PickLayers::layer(0));
+        app.world
+            .entity_mut(far)
+            .insert(PickLayers::layer(0).with(1));
+
+        app.update();
+
+        // The near cube is not on the backend's layer, so it neither is hit nor blocks the far one.
+        assert_eq!(hit_entities(&app), vec![far]);
+    }
+
+    #[test]
+    fn entities_without_pick_layers_are_on_every_layer() {
+        let mut app = app();
+        app.world
+            .resource_mut::<RaycastBackendSettings>()
+            .pick_layers = PickLayers::layer(5);
+        let cube = spawn_cube(&mut app, 0.0);
+
+        app.update();
+
+        assert_eq!(hit_entities(&app), vec![cube]);
+    }
+
+    #[test]
+    fn area_hits_are_sorted_by_depth() {
+        let mut app = app();
+        let pointer = app
+            .world
+            .query_filtered::<Entity, With<PointerId>>()
+            .single(&app.world);
+        app.world.entity_mut(pointer).insert(PointerRadius(10.0));
+        let cubes = [0.0, 2.0, -2.0, 1.0, -1.0].map(|z| spawn_cube(&mut app, z));
+
+        app.update();
+
+        assert_eq!(
+            hit_entities(&app),
+            vec![cubes[1], cubes[3], cubes[0], cubes[4], cubes[2]]
+        );
+    }
+
+    #[test]
+    fn ndc_mappers_change_the_generated_ray() {
+        let mut app = app();
+        let cube = spawn_cube(&mut app, 0.0);
+        let camera = app
+            .world
+            .query_filtered::<Entity, With<Camera>>()
+            .single(&app.world);
+        let mouse_ray = |app: &App| {
+            let ray_map = app.world.resource::<RayMap>();
+            ray_map.pointer_rays(PointerId::Mouse).next().unwrap().1
+        };
+
+        app.update();
+        let standard = mouse_ray(&app);
+        assert_eq!(hit_entities(&app), vec![cube]);
+
+        // Maps every position to the top right corner of the viewport, away from the cube.
+        let mapper = NdcMapper::new(|_, _| Some(Vec2::new(0.9, 0.9)));
+        app.world.entity_mut(camera).insert(mapper);
+        app.update();
+        let mapped = mouse_ray(&app);
+        assert!(standard.direction.x.abs() < 1e-4 && standard.direction.y.abs() < 1e-4);
+        assert!(mapped.direction.x > 0.0 && mapped.direction.y > 0.0);
+        assert!(hit_entities(&app).is_empty());
+    }
+}
@@ -28,17 +28,30 @@ pub struct SpriteBackend;
 
 impl Plugin for SpriteBackend {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreUpdate, sprite_picking.in_set(PickSet::Backend));
+        app.add_systems(PreUpdate, sprite_picking.in_set(PickSet::Backend))
+            .add_systems(PickingProbe, sprite_picking.in_set(PickSet::Backend));
     }
 }
 
 /// Checks if any sprite entities are under each pointer
 pub fn sprite_picking(
-    pointers: Query<(&PointerId, &PointerLocation)>,
-    cameras: Query<(Entity, &Camera, &GlobalTransform, &OrthographicProjection)>,
+    pointers: Query<(
+        &PointerId,
+        &PointerLocation,
+        Option<&SmoothedHitTestPosition>,
+        Option<&PointerHitRadius>,
+    )>,
+    cameras: Query<(
+        Entity,
+        &Camera,
+        &GlobalTransform,
+        &OrthographicProjection,
+        Option<&PickingCamera>,
+    )>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
     images: Res<Assets<Image>>,
     texture_atlas_layout: Res<Assets<TextureAtlasLayout>>,
+    focus_debug: Res<FocusDebugSettings>,
     sprite_query: Query<
         (
             Entity,
@@ -60,14 +73,22 @@ pub fn sprite_picking(
             .unwrap_or(Ordering::Equal)
     });
 
-    for (pointer, location) in pointers.iter().filter_map(|(pointer, pointer_location)| {
-        pointer_location.location().map(|loc| (pointer, loc))
-    }) {
+    for (pointer, location, hit_radius) in
+        pointers
+            .iter()
+            .filter_map(|(pointer, pointer_location, smoothed, hit_radius)| {
+                pointer_location
+                    .hit_test_location(smoothed)
+                    .map(|loc| (pointer, loc, hit_radius.map_or(0.0, |radius| radius.0)))
+            })
+    {
         let mut blocked = false;
-        let Some((cam_entity, camera, cam_transform, cam_ortho)) = cameras
+        let Some((cam_entity, camera, cam_transform, cam_ortho, _)) = cameras
             .iter()
-            .filter(|(_, camera, _, _)| camera.is_active)
-            .find(|(_, camera, _, _)| {
+            .filter(|(_, camera, _, _, picking_camera)| {
+                camera.is_active && PickingCamera::is_enabled(*picking_camera)
+            })
+            .find(|(_, camera, ..)| {
                 camera
                     .target
                     .normalize(Some(match primary_window.get_single() {
@@ -85,6 +106,14 @@ pub fn sprite_picking(
         else {
             continue;
         };
+        // The pointer's hit radius, converted from logical pixels to world units
+        let hit_radius_world = if hit_radius > 0.0 {
+            camera
+                .viewport_to_world_2d(cam_transform, location.position + Vec2::X * hit_radius)
+                .map_or(0.0, |edge| edge.distance(cursor_pos_world))
+        } else {
+            0.0
+        };
 
         let picks: Vec<(Entity, HitData)> = sorted_sprites
             .iter()
@@ -124,20 +153,101 @@ pub fn sprite_picking(
                         .inverse()
                         .transform_point3((cursor_pos_world, 0.0).into());
 
-                    let is_cursor_in_sprite = rect.contains(cursor_pos_sprite.truncate());
+                    // Transform the hit radius to the sprite coordinate system
+                    let hit_radius_sprite = sprite_transform
+                        .affine()
+                        .inverse()
+                        .transform_vector3(Vec3::X * hit_radius_world)
+                        .length();
+
+                    let is_cursor_in_sprite = Rect::from_corners(
+                        rect.min - hit_radius_sprite,
+                        rect.max + hit_radius_sprite,
+                    )
+                    .contains(cursor_pos_sprite.truncate());
                     blocked = is_cursor_in_sprite
+                        && !focus_debug.ignore_blocking
                         && pickable.map(|p| p.should_block_lower) != Some(false);
 
                     // HitData requires a depth as calculated from the camera's near clipping plane
                     let depth = -cam_ortho.near - sprite_transform.translation().z;
 
-                    is_cursor_in_sprite
-                        .then_some((entity, HitData::new(cam_entity, depth, None, None)))
+                    let position = cursor_pos_world.extend(sprite_transform.translation().z);
+                    is_cursor_in_sprite.then_some((
+                        entity,
+                        HitData::new(cam_entity, depth, Some(position), None),
+                    ))
                 },
             )
             .collect();
 
         let order = camera.order as f32;
-        output.send(PointerHits::new(*pointer, picks, order));
+        output.send(
+            PointerHits::new(*pointer, picks, order).with_backend(BackendId::of::<SpriteBackend>()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_picking_core::{pointer::Location, CorePlugin, PointerCoreBundle};
+    use bevy_render::camera::{camera_system, ManualTextureViews, NormalizedRenderTarget};
+    use bevy_utils::Uuid;
+    use bevy_window::{Window, WindowCreated, WindowRef, WindowResized, WindowScaleFactorChanged};
+
+    use super::*;
+
+    #[test]
+    fn hit_radius_expands_sprite_bounds() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, SpriteBackend))
+            .init_resource::<Assets<Image>>()
+            .init_resource::<Assets<TextureAtlasLayout>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<AssetEvent<Image>>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_systems(First, camera_system::<OrthographicProjection>);
+        let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+        app.world.spawn((
+            Camera::default(),
+            OrthographicProjection::default(),
+            GlobalTransform::default(),
+        ));
+        let mut visibility = ViewVisibility::default();
+        visibility.set();
+        let sprite = Sprite {
+            custom_size: Some(Vec2::splat(100.0)),
+            ..Default::default()
+        };
+        let square = (sprite, Handle::<Image>::default(), visibility);
+        let square = app.world.spawn((square, GlobalTransform::default())).id();
+
+        // The camera is centered on the window, so the right edge of the square is 50 logical
+        // pixels right of its center. Both pointers are just outside of it.
+        let target = WindowRef::Entity(window).normalize(None).unwrap();
+        let location = Location {
+            target: NormalizedRenderTarget::Window(target),
+            position: Vec2::new(640.0 + 55.0, 360.0),
+        };
+        let virtual_pointer = PointerId::Custom(Uuid::new_v4());
+        app.world
+            .spawn(PointerCoreBundle::new(PointerId::Mouse).with_location(location.clone()));
+        app.world.spawn((
+            PointerCoreBundle::new(virtual_pointer).with_location(location),
+            PointerHitRadius(10.0),
+        ));
+
+        app.update();
+
+        let hits = app.world.resource::<Events<PointerHits>>();
+        let hit = |pointer_id| {
+            hits.iter_current_update_events()
+                .filter(|hits| hits.pointer == pointer_id)
+                .any(|hits| hits.picks.iter().any(|(entity, _)| *entity == square))
+        };
+        assert!(!hit(PointerId::Mouse));
+        assert!(hit(virtual_pointer));
     }
 }
@@ -24,7 +24,7 @@
 
 use bevy_app::prelude::*;
 use bevy_ecs::{prelude::*, query::QueryData};
-use bevy_math::Vec2;
+use bevy_math::{Rect, Vec2};
 use bevy_render::prelude::*;
 use bevy_transform::prelude::*;
 use bevy_ui::{prelude::*, RelativeCursorPosition, UiStack};
@@ -43,7 +43,8 @@ pub mod prelude {
 pub struct BevyUiBackend;
 impl Plugin for BevyUiBackend {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreUpdate, ui_picking.in_set(PickSet::Backend));
+        app.add_systems(PreUpdate, ui_picking.in_set(PickSet::Backend))
+            .add_systems(PickingProbe, ui_picking.in_set(PickSet::Backend));
     }
 }
 
@@ -66,28 +67,44 @@ pub struct NodeQuery {
 /// Bevy's [`UiStack`] orders all nodes in the order they will be rendered, which is the same order
 /// we need for determining picking.
 pub fn ui_picking(
-    pointers: Query<(&PointerId, &PointerLocation)>,
-    camera_query: Query<(Entity, &Camera, Has<IsDefaultUiCamera>)>,
+    pointers: Query<(
+        &PointerId,
+        &PointerLocation,
+        Option<&SmoothedHitTestPosition>,
+        Option<&PointerHitRadius>,
+    )>,
+    camera_query: Query<(
+        Entity,
+        &Camera,
+        Has<IsDefaultUiCamera>,
+        Option<&PickingCamera>,
+    )>,
     default_ui_camera: DefaultUiCamera,
     primary_window: Query<Entity, With<PrimaryWindow>>,
     ui_scale: Res<UiScale>,
     ui_stack: Res<UiStack>,
+    focus_debug: Res<FocusDebugSettings>,
     mut node_query: Query<NodeQuery>,
     mut output: EventWriter<PointerHits>,
 ) {
-    // For each camera, the pointer and its position
-    let mut pointer_pos_by_camera = HashMap::<Entity, HashMap<PointerId, Vec2>>::new();
+    // For each camera, the pointer, its position, and its hit radius
+    let mut pointer_pos_by_camera = HashMap::<Entity, HashMap<PointerId, (Vec2, f32)>>::new();
 
-    for (pointer_id, pointer_location) in
-        pointers.iter().filter_map(|(pointer, pointer_location)| {
-            Some(*pointer).zip(pointer_location.location().cloned())
-        })
+    for (pointer_id, pointer_location, hit_radius) in
+        pointers
+            .iter()
+            .filter_map(|(pointer, pointer_location, smoothed, hit_radius)| {
+                pointer_location
+                    .hit_test_location(smoothed)
+                    .map(|loc| (*pointer, loc, hit_radius.map_or(0.0, |radius| radius.0)))
+            })
     {
         // This pointer is associated with a render target, which could be used by multiple
         // cameras. We want to ensure we return all cameras with a matching target.
         for camera in camera_query
             .iter()
-            .map(|(entity, camera, _)| {
+            .filter(|(.., picking_camera)| PickingCamera::is_enabled(*picking_camera))
+            .map(|(entity, camera, ..)| {
                 (
                     entity,
                     camera.target.normalize(primary_window.get_single().ok()),
@@ -97,7 +114,7 @@ pub fn ui_picking(
             .filter(|(_entity, target)| target == &pointer_location.target)
             .map(|(cam_entity, _target)| cam_entity)
         {
-            let Ok((_, camera_data, _)) = camera_query.get(camera) else {
+            let Ok((_, camera_data, ..)) = camera_query.get(camera) else {
                 continue;
             };
             let mut pointer_pos = pointer_location.position;
@@ -105,10 +122,11 @@ pub fn ui_picking(
                 pointer_pos -= viewport.min;
             }
             let scaled_pointer_pos = pointer_pos / **ui_scale;
+            let scaled_hit_radius = hit_radius / **ui_scale;
             pointer_pos_by_camera
                 .entry(camera)
                 .or_default()
-                .insert(pointer_id, scaled_pointer_pos);
+                .insert(pointer_id, (scaled_pointer_pos, scaled_hit_radius));
         }
     }
 
@@ -159,16 +177,27 @@ pub fn ui_picking(
 
         let pointers_on_this_cam = pointer_pos_by_camera.get(&camera_entity);
 
-        // The mouse position relative to the node
-        // (0., 0.) is the top-left corner, (1., 1.) is the bottom-right corner
-        // Coordinates are relative to the entire node, not just the visible region.
-        for (pointer_id, cursor_position) in pointers_on_this_cam.iter().flat_map(|h| h.iter()) {
-            let relative_cursor_position = (*cursor_position - node_rect.min) / node_rect.size();
+        for (pointer_id, (cursor_position, hit_radius)) in
+            pointers_on_this_cam.iter().flat_map(|h| h.iter())
+        {
+            let is_hit = if *hit_radius > 0.0 {
+                Rect::from_corners(
+                    visible_rect.min - *hit_radius,
+                    visible_rect.max + *hit_radius,
+                )
+                .contains(*cursor_position)
+            } else {
+                // The mouse position relative to the node
+                // (0., 0.) is the top-left corner, (1., 1.) is the bottom-right corner
+                // Coordinates are relative to the entire node, not just the visible region.
+                let relative_cursor_position =
+                    (*cursor_position - node_rect.min) / node_rect.size();
+                visible_rect
+                    .normalize(node_rect)
+                    .contains(relative_cursor_position)
+            };
 
-            if visible_rect
-                .normalize(node_rect)
-                .contains(relative_cursor_position)
-            {
+            if is_hit {
                 hit_nodes
                     .entry((camera_entity, *pointer_id))
                     .or_default()
@@ -178,6 +207,10 @@ pub fn ui_picking(
     }
 
     for ((camera, pointer), hovered_nodes) in hit_nodes.iter() {
+        let cursor_position = pointer_pos_by_camera
+            .get(camera)
+            .and_then(|pointers| pointers.get(pointer))
+            .map(|(cursor_position, _)| *cursor_position);
         // As soon as a node with a `Block` focus policy is detected, the iteration will stop on it
         // because it "captures" the interaction.
         let mut iter = node_query.iter_many_mut(hovered_nodes.iter());
@@ -193,9 +226,18 @@ pub fn ui_picking(
                 continue;
             };
 
-            picks.push((node.entity, HitData::new(camera_entity, depth, None, None)));
+            // Hit positions are in the space of the `GlobalTransform` of nodes, in logical pixels.
+            let position = cursor_position.map(|cursor_position| {
+                cursor_position.extend(node.global_transform.translation().z)
+            });
+            picks.push((
+                node.entity,
+                HitData::new(camera_entity, depth, position, None),
+            ));
 
-            if let Some(pickable) = node.pickable {
+            if focus_debug.ignore_blocking {
+                // Every hit is reported while debugging, see `FocusDebugSettings`.
+            } else if let Some(pickable) = node.pickable {
                 // If an entity has a `Pickable` component, we will use that as the source of truth.
                 if pickable.should_block_lower {
                     break;
@@ -210,10 +252,72 @@ pub fn ui_picking(
 
         let order = camera_query
             .get(*camera)
-            .map(|(_, cam, _)| cam.order)
+            .map(|(_, cam, ..)| cam.order)
             .unwrap_or_default() as f32
             + 0.5; // bevy ui can run on any camera, it's a special case
 
-        output.send(PointerHits::new(*pointer, picks, order));
+        output.send(
+            PointerHits::new(*pointer, picks, order).with_backend(BackendId::of::<BevyUiBackend>()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_picking_core::{pointer::Location, CorePlugin, PointerCoreBundle};
+    use bevy_reflect::Struct;
+    use bevy_render::camera::NormalizedRenderTarget;
+    use bevy_utils::Uuid;
+    use bevy_window::{Window, WindowRef};
+
+    use super::*;
+
+    /// Returns a node with the size the layout would have calculated for it.
+    fn node(size: Vec2) -> Node {
+        let mut node = Node::default();
+        let calculated_size = node.field_mut("calculated_size").unwrap();
+        *calculated_size.downcast_mut::<Vec2>().unwrap() = size;
+        node
+    }
+
+    #[test]
+    fn hit_radius_expands_node_bounds() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, BevyUiBackend))
+            .init_resource::<UiScale>()
+            .init_resource::<UiStack>();
+        let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+        app.world.spawn(Camera::default());
+        let mut visibility = ViewVisibility::default();
+        visibility.set();
+        let transform = GlobalTransform::from_xyz(100.0, 100.0, 0.0);
+        let button = (node(Vec2::splat(50.0)), transform, visibility);
+        let button = app.world.spawn(button).id();
+        app.world.resource_mut::<UiStack>().uinodes.push(button);
+
+        // The right edge of the node is at 125 logical pixels. Both pointers are just outside of it.
+        let target = WindowRef::Entity(window).normalize(None).unwrap();
+        let location = Location {
+            target: NormalizedRenderTarget::Window(target),
+            position: Vec2::new(130.0, 100.0),
+        };
+        let virtual_pointer = PointerId::Custom(Uuid::new_v4());
+        app.world
+            .spawn(PointerCoreBundle::new(PointerId::Mouse).with_location(location.clone()));
+        app.world.spawn((
+            PointerCoreBundle::new(virtual_pointer).with_location(location),
+            PointerHitRadius(10.0),
+        ));
+
+        app.update();
+
+        let hits = app.world.resource::<Events<PointerHits>>();
+        let hit = |pointer_id| {
+            hits.iter_current_update_events()
+                .filter(|hits| hits.pointer == pointer_id)
+                .any(|hits| hits.picks.iter().any(|(entity, _)| *entity == button))
+        };
+        assert!(!hit(PointerId::Mouse));
+        assert!(hit(virtual_pointer));
     }
 }
@@ -47,19 +47,32 @@ impl Plugin for XpbdBackend {
     fn build(&self, app: &mut App) {
         app.init_resource::<XpbdBackendSettings>()
             .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend))
+            .add_systems(PickingProbe, update_hits.in_set(PickSet::Backend))
             .register_type::<XpbdBackendSettings>()
             .register_type::<XpbdPickable>();
     }
 }
 
 /// Runtime settings for the [`XpbdBackend`].
-#[derive(Resource, Default, Reflect)]
+#[derive(Resource, Reflect)]
 #[reflect(Resource, Default)]
 pub struct XpbdBackendSettings {
     /// When set to `true` raycasting will only happen between cameras and entities marked with
     /// [`XpbdPickable`]. Off by default. This setting is provided to give you fine-grained
     /// control over which cameras and entities should be used by the xpbd backend at runtime.
     pub require_markers: bool,
+    /// Only entities whose [`PickLayers`] intersect this mask will be raycasted against. Entities
+    /// without [`PickLayers`] are on every layer. Defaults to [`PickLayers::ALL`].
+    pub pick_layers: PickLayers,
+}
+
+impl Default for XpbdBackendSettings {
+    fn default() -> Self {
+        Self {
+            require_markers: false,
+            pick_layers: PickLayers::ALL,
+        }
+    }
 }
 
 /// Optional. Marks cameras and target entities that should be used in the xpbd picking backend.
@@ -76,6 +89,7 @@ pub fn update_hits(
     pickables: Query<&Pickable>,
     marked_targets: Query<&XpbdPickable>,
     layers: Query<&RenderLayers>,
+    pick_layers: Query<&PickLayers>,
     backend_settings: Res<XpbdBackendSettings>,
     spatial_query: Option<Res<SpatialQueryPipeline>>,
     mut output_events: EventWriter<PointerHits>,
@@ -109,12 +123,18 @@ pub fn update_hits(
                     let entity_layers = layers.get(entity).copied().unwrap_or_default();
                     let render_layers_match = cam_layers.intersects(&entity_layers);
 
+                    let pick_layers_match = pick_layers
+                        .get(entity)
+                        .copied()
+                        .unwrap_or_default()
+                        .intersects(&backend_settings.pick_layers);
+
                     let is_pickable = pickables
                         .get(entity)
                         .map(|p| *p != Pickable::IGNORE)
                         .unwrap_or(true);
 
-                    marker_requirement && render_layers_match && is_pickable
+                    marker_requirement && render_layers_match && pick_layers_match && is_pickable
                 },
             )
             .map(|ray_hit_data| {
@@ -127,11 +147,14 @@ pub fn update_hits(
                 (ray_hit_data.entity, hit_data)
             })
         {
-            output_events.send(PointerHits::new(
-                ray_id.pointer,
-                vec![(entity, hit_data)],
-                camera.order as f32,
-            ));
+            output_events.send(
+                PointerHits::new(
+                    ray_id.pointer,
+                    vec![(entity, hit_data)],
+                    camera.order as f32,
+                )
+                .with_backend(BackendId::of::<XpbdBackend>()),
+            );
         }
     }
 }
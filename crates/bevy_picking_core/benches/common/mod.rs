@@ -0,0 +1,53 @@
+//! Helpers shared by the benchmarks. Each benchmark includes this module with `mod common;`, which
+//! also installs the [`CountingAllocator`] as its global allocator.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use bevy_ecs::entity::Entity;
+use bevy_math::Vec2;
+use bevy_picking_core::pointer::Location;
+use bevy_render::camera::NormalizedRenderTarget;
+use bevy_window::WindowRef;
+
+/// Counts every allocation made through the global allocator.
+pub struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made since the benchmark started.
+pub fn allocation_count() -> usize {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+/// Returns a [`Location`] at the origin of a window that does not need to exist.
+pub fn origin() -> Location {
+    let window = WindowRef::Entity(Entity::PLACEHOLDER)
+        .normalize(None)
+        .unwrap();
+    Location {
+        target: NormalizedRenderTarget::Window(window),
+        position: Vec2::ZERO,
+    }
+}
@@ -0,0 +1,83 @@
+//! Measures the time and heap allocations of [`update_focus`] in a scene with many cameras,
+//! pointers, and hit entities. Run with `cargo bench -p bevy_picking_core --bench focus`.
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_picking_core::{
+    backend::{HitData, PointerHits},
+    focus::update_focus,
+    pointer::{Location, PointerId},
+    CorePlugin, InteractionPlugin, PointerCoreBundle,
+};
+
+use common::{allocation_count, origin};
+
+const CAMERAS: usize = 4;
+const POINTERS: usize = 8;
+const HITS_PER_CAMERA: usize = 250;
+const WARMUP_FRAMES: usize = 10;
+const FRAMES: usize = 200;
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((CorePlugin, InteractionPlugin));
+    let world = &mut app.world;
+
+    let location: Location = origin();
+    let pointers: Vec<PointerId> = (0..POINTERS as u64).map(PointerId::Touch).collect();
+    for pointer in &pointers {
+        world.spawn(PointerCoreBundle::new(*pointer).with_location(location.clone()));
+    }
+    let cameras: Vec<Entity> = (0..CAMERAS).map(|_| world.spawn_empty().id()).collect();
+    let entities: Vec<Entity> = (0..HITS_PER_CAMERA * CAMERAS)
+        .map(|_| world.spawn_empty().id())
+        .collect();
+
+    // Every pointer hits every entity, with depths that need sorting, from cameras of increasing
+    // order.
+    let frame_hits: Vec<PointerHits> = pointers
+        .iter()
+        .flat_map(|pointer| {
+            let entities = &entities;
+            cameras.iter().enumerate().map(move |(order, camera)| {
+                let picks = entities[order * HITS_PER_CAMERA..][..HITS_PER_CAMERA]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, entity)| {
+                        let depth = ((i * 7919) % HITS_PER_CAMERA) as f32;
+                        (*entity, HitData::new(*camera, depth, None, None))
+                    })
+                    .collect();
+                PointerHits::new(*pointer, picks, order as f32)
+            })
+        })
+        .collect();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(update_focus);
+
+    let mut allocations = 0;
+    let mut elapsed = Duration::ZERO;
+    for frame in 0..WARMUP_FRAMES + FRAMES {
+        world.send_event_batch(frame_hits.iter().cloned());
+        let start_allocations = allocation_count();
+        let start = Instant::now();
+        schedule.run(world);
+        if frame >= WARMUP_FRAMES {
+            elapsed += start.elapsed();
+            allocations += allocation_count() - start_allocations;
+        }
+        world.resource_mut::<Events<PointerHits>>().update();
+    }
+
+    println!(
+        "update_focus with {CAMERAS} cameras, {POINTERS} pointers, and {} hits per pointer:",
+        CAMERAS * HITS_PER_CAMERA
+    );
+    println!("  {:?} per frame", elapsed / FRAMES as u32);
+    println!("  {} allocations per frame", allocations / FRAMES);
+}
@@ -0,0 +1,81 @@
+//! Measures the time and heap allocations of a picking frame in a scene with many pickable entities.
+//! Frames where no pointer has hits, which take the idle fast path of the focus and interaction
+//! systems, are contrasted with frames where each pointer hovers a single entity, which take the
+//! general path. Run with `cargo bench -p bevy_picking_core --bench idle`.
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_picking_core::{
+    backend::{HitData, PointerHits},
+    focus::PickingInteraction,
+    pointer::PointerId,
+    CorePlugin, InteractionPlugin, Pickable, PointerCoreBundle,
+};
+
+use common::{allocation_count, origin};
+
+const ENTITIES: usize = 100_000;
+const POINTERS: usize = 2;
+const WARMUP_FRAMES: usize = 100;
+const FRAMES: usize = 2_000;
+
+/// Runs the picking plugins for [`FRAMES`] frames, where each pointer hovers the first entity if
+/// `hovered`, and returns the average time and allocations of a frame.
+fn run(hovered: bool) -> (Duration, usize) {
+    let mut app = App::new();
+    app.add_plugins((CorePlugin, InteractionPlugin));
+    let world = &mut app.world;
+
+    let pointers: Vec<PointerId> = (0..POINTERS as u64).map(PointerId::Touch).collect();
+    for pointer in &pointers {
+        world.spawn(PointerCoreBundle::new(*pointer).with_location(origin()));
+    }
+    let entities: Vec<Entity> = (0..ENTITIES)
+        .map(|_| {
+            world
+                .spawn((Pickable::default(), PickingInteraction::default()))
+                .id()
+        })
+        .collect();
+
+    // Backends report the hits under every pointer each frame, even when nothing is hit.
+    let frame_hits: Vec<PointerHits> = pointers
+        .iter()
+        .map(|pointer| {
+            let picks = match hovered {
+                true => vec![(
+                    entities[0],
+                    HitData::new(Entity::PLACEHOLDER, 0.0, None, None),
+                )],
+                false => Vec::new(),
+            };
+            PointerHits::new(*pointer, picks, 0.0)
+        })
+        .collect();
+
+    let mut allocations = 0;
+    let mut elapsed = Duration::ZERO;
+    for frame in 0..WARMUP_FRAMES + FRAMES {
+        app.world.send_event_batch(frame_hits.iter().cloned());
+        let start_allocations = allocation_count();
+        let start = Instant::now();
+        app.update();
+        if frame >= WARMUP_FRAMES {
+            elapsed += start.elapsed();
+            allocations += allocation_count() - start_allocations;
+        }
+    }
+    (elapsed / FRAMES as u32, allocations / FRAMES)
+}
+
+fn main() {
+    println!("picking frame with {ENTITIES} pickable entities and {POINTERS} pointers:");
+    for (name, hovered) in [("nothing hovered", false), ("one entity hovered", true)] {
+        let (elapsed, allocations) = run(hovered);
+        println!("  {name}: {elapsed:?} and {allocations} allocations per frame");
+    }
+}
@@ -23,27 +23,102 @@
 //! - Backends do not need to consider the [`Pickable`](crate::Pickable) component, though they may
 //!   use it for optimization purposes. For example, a backend that traverses a spatial hierarchy
 //!   may want to early exit if it intersects an entity that blocks lower entities from being
-//!   picked.
+//!   picked. Backends that do should not exit early while the
+//!   [`FocusDebugSettings::ignore_blocking`](crate::focus::FocusDebugSettings) is set.
 //!
 //! ### Raycasting Backends
 //!
 //! Backends that require a ray to cast into the scene should use [`ray::RayMap`]. This
 //! automatically constructs rays in world space for all cameras and pointers, handling details like
 //! viewports and DPI for you.
+//!
+//! ### Priority
+//!
+//! Backends should identify the [`PointerHits`] they produce with a [`BackendId`], using
+//! [`PointerHits::with_backend`]. This lets users prefer the hits of one backend over another with
+//! the [`BackendPriority`] resource, for example to always prefer UI over the world. Backends that
+//! report hits at nearly the same depth, such as a sprite drawn exactly on a mesh, can be separated
+//! with a [`BackendDepthBias`] instead.
+//!
+//! ### On-Demand Probing
+//!
+//! Backends that can hit test a pointer at an arbitrary location should also add their hit testing
+//! system to the [`PickingProbe`] schedule, in [`PickSet::Backend`](crate::PickSet::Backend). This
+//! allows tools like [`probe_hover`](crate::focus::probe_hover) and [`pick_at`] to ask what a
+//! pointer would hit without waiting for the next frame.
 
-use bevy_ecs::prelude::*;
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    prelude::*,
+    schedule::ScheduleLabel,
+};
 use bevy_math::Vec3;
-use bevy_reflect::Reflect;
+use bevy_reflect::prelude::*;
+use bevy_utils::{HashMap, Uuid};
+
+use crate::pointer::{Location, PointerId};
 
 /// Common imports for implementing a picking backend.
 pub mod prelude {
-    pub use super::{ray::RayMap, HitData, PointerHits};
+    pub use super::{
+        pick_at, ray::RayMap, BackendId, Face, HitData, PickingCamera, PickingProbe, PointerHits,
+    };
     pub use crate::{
-        pointer::{PointerId, PointerLocation},
-        PickSet, Pickable,
+        focus::FocusDebugSettings,
+        pointer::{
+            PointerHitRadius, PointerId, PointerLocation, PointerRadius, SmoothedHitTestPosition,
+        },
+        PickLayers, PickSet, Pickable,
     };
 }
 
+/// A schedule that runs the hit testing systems of every backend on demand, outside of the normal
+/// picking pipeline.
+///
+/// The [`ray::RayMap`] is repopulated in [`PickSet::ProcessInput`](crate::PickSet::ProcessInput)
+/// before backends run in [`PickSet::Backend`](crate::PickSet::Backend), mirroring the normal
+/// pipeline. Backends that only have data for the real pointer position, like the egui backend,
+/// should not be added to this schedule.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PickingProbe;
+
+/// Enables or disables picking through a camera, without removing or deactivating the camera.
+///
+/// While disabled, no rays are built for the camera in the [`RayMap`](ray::RayMap), and the
+/// included backends skip it, so nothing rendered by it can be hovered. Cameras without this
+/// component can be picked through. Custom backends should check [`PickingCamera::is_enabled`].
+#[derive(Debug, Clone, Copy, Component, Reflect, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct PickingCamera {
+    /// Whether picking is enabled for this camera.
+    pub enabled: bool,
+}
+
+impl Default for PickingCamera {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl PickingCamera {
+    /// Returns `true` if a camera with this optional component can be picked through.
+    pub fn is_enabled(camera: Option<&Self>) -> bool {
+        camera.map_or(true, |camera| camera.enabled)
+    }
+}
+
+/// Hit tests `location` with every backend in the [`PickingProbe`] schedule, returning the
+/// entities a pointer there would hover, sorted from nearest to farthest.
+///
+/// Hits are resolved exactly like normal hovering, so entities below one that
+/// [`should_block_lower`](crate::Pickable::should_block_lower) are not returned. A temporary
+/// [`PointerId::Custom`] pointer is used for the test, so no real pointer entity is needed and no
+/// picking state is changed. See [`probe_hover`](crate::focus::probe_hover) for details.
+pub fn pick_at(world: &mut World, location: Location) -> Vec<(Entity, HitData)> {
+    crate::focus::probe_hover(world, PointerId::Custom(Uuid::new_v4()), location)
+}
+
 /// An event produced by a picking backend after it has run its hit tests, describing the entities
 /// under a pointer.
 ///
@@ -74,7 +149,16 @@ pub struct PointerHits {
     /// to properly sort them, we need a way to make bevy_ui's order a tiny bit higher, like adding
     /// 0.5 to the order. We can't use integers, and we want users to be using camera.order by
     /// default, so this is the best solution at the moment.
+    ///
+    /// The [`BackendPriority`] of the backend is compared before the order.
     pub order: f32,
+    /// The backend that produced these picks, used to look up its [`BackendPriority`].
+    pub backend: Option<BackendId>,
+    /// The [`FrameCount`](bevy_core::FrameCount) of the frame whose pointer locations were hit
+    /// tested, for backends that report hits in a later frame, such as backends that read an id
+    /// buffer back from the GPU. `None` means the hits are for the current frame, which is the case
+    /// for synchronous backends. See [`LateHitSettings`].
+    pub frame: Option<u32>,
 }
 
 impl PointerHits {
@@ -84,12 +168,162 @@ impl PointerHits {
             pointer,
             picks,
             order,
+            backend: None,
+            frame: None,
         }
     }
+
+    /// Identifies the backend that produced these picks.
+    pub fn with_backend(mut self, backend: BackendId) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Marks these picks as a hit test of the pointer locations of an earlier frame, with the
+    /// [`FrameCount`](bevy_core::FrameCount) of that frame. See [`LateHitSettings`].
+    pub fn with_frame(mut self, frame: u32) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+}
+
+/// Controls how [`PointerHits`] that are reported in a later frame than the one they were hit
+/// tested in, with [`PointerHits::with_frame`], are reconciled with the pointer's current location.
+///
+/// Late hits are used as if they were reported on time, as long as the pointer's hit test
+/// location, recorded in the [`PointerLocationHistory`](crate::pointer::PointerLocationHistory)
+/// during the frame they were hit tested in, is within `tolerance` logical pixels of its current
+/// one, on the same render target. Otherwise the pointer has moved away since, and the hits are
+/// stale: the last valid hits of the same backend for that pointer are used in their place, so
+/// hover stays on the entities that were last known to be under the pointer, instead of jumping to
+/// entities under an outdated position, or flickering off while the pointer moves. Hits that are
+/// older than `max_age` frames are always stale.
+///
+/// Hits without a frame are always used, so synchronous backends are not affected. Late hits are
+/// also always used if the [`FrameCount`](bevy_core::FrameCount) resource doesn't exist.
+#[derive(Debug, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct LateHitSettings {
+    /// The number of frames after which late hits are always stale. Defaults to `4`.
+    pub max_age: u32,
+    /// How far, in logical pixels, the pointer can have moved since late hits were hit tested for
+    /// them to still be valid. Defaults to `2.0`.
+    pub tolerance: f32,
+}
+
+impl Default for LateHitSettings {
+    fn default() -> Self {
+        Self {
+            max_age: 4,
+            tolerance: 2.0,
+        }
+    }
+}
+
+impl LateHitSettings {
+    /// Returns `true` if `hits` are valid for the pointer's current location, given the `history`
+    /// of pointer locations and the current `frame`.
+    pub fn is_valid(
+        &self,
+        hits: &PointerHits,
+        history: &crate::pointer::PointerLocationHistory,
+        frame: Option<u32>,
+    ) -> bool {
+        let (Some(hit_frame), Some(frame)) = (hits.frame, frame) else {
+            return true;
+        };
+        let age = frame.wrapping_sub(hit_frame);
+        if age == 0 {
+            return true;
+        }
+        if age > self.max_age {
+            return false;
+        }
+        match (
+            history.location_at(hits.pointer, hit_frame),
+            history.location_at(hits.pointer, frame),
+        ) {
+            (Some(then), Some(now)) => {
+                then.target == now.target && then.position.distance(now.position) <= self.tolerance
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Identifies a picking backend. See [`BackendPriority`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub struct BackendId(pub &'static str);
+
+impl BackendId {
+    /// Returns the id of the backend added by the plugin `T`. This is the id used by the included
+    /// backends, for example `BackendId::of::<BevyUiBackend>()`.
+    pub fn of<T: ?Sized>() -> Self {
+        Self(std::any::type_name::<T>())
+    }
+}
+
+/// Maps backends to their priority, which is compared before the order and depth of hits when
+/// deciding which entities are on top. Hits from a backend with a higher priority are always above
+/// hits from backends with a lower priority, regardless of their camera order or depth.
+///
+/// Backends without a priority, and [`PointerHits`] without a [`BackendId`], have a priority of `0`,
+/// so hits are only sorted by order and depth unless a priority is set.
+///
+/// ```
+/// # use bevy_picking_core::backend::{BackendId, BackendPriority};
+/// # struct BevyUiBackend;
+/// let mut priority = BackendPriority::default();
+/// // Always prefer UI, regardless of camera order.
+/// priority.insert(BackendId::of::<BevyUiBackend>(), 1);
+/// assert_eq!(priority.get_priority(Some(BackendId::of::<BevyUiBackend>())), 1);
+/// ```
+#[derive(Debug, Default, Clone, Deref, DerefMut, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct BackendPriority(pub HashMap<BackendId, i32>);
+
+impl BackendPriority {
+    /// Returns the priority of `backend`.
+    pub fn get_priority(&self, backend: Option<BackendId>) -> i32 {
+        backend
+            .and_then(|backend| self.get(&backend).copied())
+            .unwrap_or_default()
+    }
+}
+
+/// Maps backends to a bias that is added to the depth of their hits when deciding which entities
+/// are on top, so one backend can win over another when they report hits at nearly the same depth.
+/// Negative biases move hits nearer to the pointer. The depth reported in each [`HitData`] is not
+/// changed.
+///
+/// The bias is applied after the [`DepthComparison`](crate::focus::DepthComparison), so it is in
+/// the same units as the compared depths. Backends without a bias, and [`PointerHits`] without a
+/// [`BackendId`], have a bias of `0.0`.
+///
+/// ```
+/// # use bevy_picking_core::backend::{BackendDepthBias, BackendId};
+/// # struct SpriteBackend;
+/// let mut depth_bias = BackendDepthBias::default();
+/// // Prefer sprites over meshes at the same depth.
+/// depth_bias.insert(BackendId::of::<SpriteBackend>(), -0.01);
+/// assert_eq!(depth_bias.get_bias(Some(BackendId::of::<SpriteBackend>())), -0.01);
+/// ```
+#[derive(Debug, Default, Clone, Deref, DerefMut, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct BackendDepthBias(pub HashMap<BackendId, f32>);
+
+impl BackendDepthBias {
+    /// Returns the depth bias of `backend`.
+    pub fn get_bias(&self, backend: Option<BackendId>) -> f32 {
+        backend
+            .and_then(|backend| self.get(&backend).copied())
+            .unwrap_or_default()
+    }
 }
 
 /// Holds data from a successful pointer hit test. See [`HitData::depth`] for important details.
 #[derive(Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct HitData {
     /// The camera entity used to detect this hit. Useful when you need to find the ray that was
     /// casted for this hit when using a raycasting backend.
@@ -100,9 +334,25 @@ pub struct HitData {
     /// point, in world space.
     pub depth: f32,
     /// The position of the intersection in the world, if the data is available from the backend.
+    /// For UI nodes, this is in the space of their `GlobalTransform`, in logical pixels.
     pub position: Option<Vec3>,
     /// The normal vector of the hit test, if the data is available from the backend.
     pub normal: Option<Vec3>,
+    /// Which side of the surface was hit, if the data is available from the backend. See [`Face`].
+    pub face: Option<Face>,
+    /// The part of the entity that was hit, such as a tile index in a tilemap or a triangle index
+    /// in a mesh. `None` means the hit is for the entity as a whole.
+    ///
+    /// The meaning of the index is defined by the backend. Hover state is still tracked per entity,
+    /// so moving between parts of the same entity sends `Move` events with the new part, but no
+    /// `Over` or `Out` events.
+    pub part: Option<u64>,
+}
+
+impl MapEntities for HitData {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.camera = entity_mapper.map_entity(self.camera);
+    }
 }
 
 impl HitData {
@@ -113,6 +363,56 @@ impl HitData {
             depth,
             position,
             normal,
+            face: None,
+            part: None,
+        }
+    }
+
+    /// Sets the [`Face`] that was hit.
+    pub fn with_face(mut self, face: Option<Face>) -> Self {
+        self.face = face;
+        self
+    }
+
+    /// Sets the part of the entity that was hit.
+    pub fn with_part(mut self, part: Option<u64>) -> Self {
+        self.part = part;
+        self
+    }
+}
+
+/// The side of a surface that was hit, as reported in [`HitData::face`].
+///
+/// Mesh backends determine this from the winding order of the hit triangle in world space. As in
+/// the rest of Bevy, a triangle with counter-clockwise winding, as seen by the viewer, is a front
+/// face. This means a mesh with a negative scale on one axis has its faces flipped, matching how it
+/// is rendered, and that the result does not depend on the mesh's vertex normals.
+///
+/// Backends that cull back faces, which is the default for mesh raycasting, will only ever report
+/// [`Face::Front`]. Backends that cannot determine the side of a hit, such as 2D or physics
+/// backends, leave the face as `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum Face {
+    /// The hit was on the front face of the surface.
+    Front,
+    /// The hit was on the back face of the surface.
+    Back,
+}
+
+impl Face {
+    /// Returns the [`Face`] of a world space triangle that was hit by a ray with the given
+    /// direction, using counter-clockwise winding for front faces. Returns `None` if the ray is
+    /// parallel to the triangle, or the triangle is degenerate.
+    pub fn from_triangle(triangle: [Vec3; 3], ray_direction: Vec3) -> Option<Self> {
+        let [a, b, c] = triangle;
+        let facing = (b - a).cross(c - a).dot(ray_direction);
+        if facing < 0.0 {
+            Some(Face::Front)
+        } else if facing > 0.0 {
+            Some(Face::Back)
+        } else {
+            None
         }
     }
 }
@@ -120,9 +420,14 @@ impl HitData {
 pub mod ray {
     //! Types and systems for constructing rays from cameras and pointers.
 
-    use crate::backend::prelude::{PointerId, PointerLocation};
+    use std::sync::Arc;
+
+    use crate::{
+        backend::prelude::{PickingCamera, PointerId, PointerLocation},
+        pointer::{Location, PointerRadius, SmoothedHitTestPosition},
+    };
     use bevy_ecs::prelude::*;
-    use bevy_math::Ray3d;
+    use bevy_math::{primitives::Direction3d, Ray3d, Vec2};
     use bevy_reflect::Reflect;
     use bevy_render::camera::Camera;
     use bevy_transform::prelude::GlobalTransform;
@@ -146,6 +451,50 @@ pub mod ray {
         }
     }
 
+    /// Overrides how a pointer position is converted to normalized device coordinates (NDC) when
+    /// building rays for this camera in [`RayMap::repopulate`]. This is useful for cameras with
+    /// lens distortion or a non-standard projection, such as fisheye, anamorphic, or VR cameras,
+    /// where the standard mapping would skew picks.
+    ///
+    /// The mapper receives the pointer position relative to the camera's viewport, in logical
+    /// pixels, with the origin at the top left and `+y` pointing down. It also receives the logical
+    /// size of the viewport. It should return the position in NDC, where `(-1, -1)` is the bottom
+    /// left and `(1, 1)` is the top right of the viewport, or `None` if the position should not
+    /// produce a ray. The default mapping is [`NdcMapper::standard`].
+    #[derive(Component, Clone)]
+    pub struct NdcMapper(pub Arc<dyn Fn(Vec2, Vec2) -> Option<Vec2> + Send + Sync>);
+
+    impl NdcMapper {
+        /// Create a new [`NdcMapper`] from a function of the viewport position and viewport size.
+        pub fn new(mapper: impl Fn(Vec2, Vec2) -> Option<Vec2> + Send + Sync + 'static) -> Self {
+            Self(Arc::new(mapper))
+        }
+
+        /// The standard mapping from a viewport position to NDC, used when a camera has no
+        /// [`NdcMapper`].
+        pub fn standard(viewport_position: Vec2, viewport_size: Vec2) -> Option<Vec2> {
+            let flipped = Vec2::new(viewport_position.x, viewport_size.y - viewport_position.y);
+            Some(flipped * 2.0 / viewport_size - Vec2::ONE)
+        }
+
+        /// Maps a viewport position to NDC using this mapper.
+        pub fn map(&self, viewport_position: Vec2, viewport_size: Vec2) -> Option<Vec2> {
+            (self.0)(viewport_position, viewport_size)
+        }
+    }
+
+    impl Default for NdcMapper {
+        fn default() -> Self {
+            Self::new(Self::standard)
+        }
+    }
+
+    impl std::fmt::Debug for NdcMapper {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("NdcMapper").finish_non_exhaustive()
+        }
+    }
+
     /// A map from [`RayId`] to [`Ray3d`].
     ///
     /// This map is cleared and re-populated every frame before any backends run. Ray-based picking
@@ -167,9 +516,29 @@ pub mod ray {
     ///     }
     /// }
     /// ```
+    ///
+    /// Gameplay code can reuse the same rays as the backends, instead of recomputing them from the
+    /// camera, with [`RayMap::get`] or [`RayMap::pointer_rays`]. Rays are available to any system
+    /// that runs after [`PickSet::ProcessInput`](crate::PickSet::ProcessInput), such as systems in
+    /// the `Update` schedule.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_picking_core::backend::ray::RayMap;
+    /// # use bevy_picking_core::pointer::PointerId;
+    /// fn cursor_raycast(ray_map: Res<RayMap>) {
+    ///     for (camera, ray) in ray_map.pointer_rays(PointerId::Mouse) {
+    ///         // Run a custom raycast with the ray under the cursor for this camera.
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Pointers with a [`PointerRadius`] also have a set of sample rays spread over their radius,
+    /// available with [`RayMap::area_rays`], for backends that support area picking.
     #[derive(Clone, Debug, Default, Resource)]
     pub struct RayMap {
         map: HashMap<RayId, Ray3d>,
+        area_map: HashMap<RayId, Vec<Ray3d>>,
     }
 
     impl RayMap {
@@ -183,29 +552,87 @@ pub mod ray {
             &self.map
         }
 
+        /// Returns the ray cast for the current frame from the pointer and camera of `ray_id`, if
+        /// the pointer is over the camera's viewport.
+        pub fn get(&self, ray_id: RayId) -> Option<Ray3d> {
+            self.map.get(&ray_id).copied()
+        }
+
+        /// Iterates over the rays cast for the current frame from `pointer_id`, along with the
+        /// camera each ray was cast from. A pointer has one ray for each camera whose viewport it
+        /// is over.
+        pub fn pointer_rays(
+            &self,
+            pointer_id: PointerId,
+        ) -> impl Iterator<Item = (Entity, Ray3d)> + '_ {
+            self.map
+                .iter()
+                .filter(move |(ray_id, _)| ray_id.pointer == pointer_id)
+                .map(|(ray_id, ray)| (ray_id.camera, *ray))
+        }
+
+        /// Returns the sample rays spread over the [`PointerRadius`] of the pointer of `ray_id`,
+        /// including the ray at its center. This is empty for pointers without a radius, or that
+        /// are not over the camera's viewport.
+        ///
+        /// Area picking backends should report every entity hit by any of these rays, using the
+        /// nearest hit of each entity.
+        pub fn area_rays(&self, ray_id: RayId) -> &[Ray3d] {
+            self.area_map.get(&ray_id).map_or(&[], Vec::as_slice)
+        }
+
         /// Clears the [`RayMap`] and re-populates it with one ray for each
         /// combination of pointer entity and camera entity where the pointer
-        /// intersects the camera's viewport.
+        /// intersects the camera's viewport. Cameras disabled with a [`PickingCamera`] are
+        /// skipped.
         pub fn repopulate(
             mut ray_map: ResMut<Self>,
             primary_window_entity: Query<Entity, With<PrimaryWindow>>,
-            cameras: Query<(Entity, &Camera, &GlobalTransform)>,
-            pointers: Query<(&PointerId, &PointerLocation)>,
+            cameras: Query<(
+                Entity,
+                &Camera,
+                &GlobalTransform,
+                Option<&NdcMapper>,
+                Option<&PickingCamera>,
+            )>,
+            pointers: Query<(
+                &PointerId,
+                &PointerLocation,
+                Option<&SmoothedHitTestPosition>,
+                Option<&PointerRadius>,
+            )>,
         ) {
+            let ray_map = &mut *ray_map;
             ray_map.map.clear();
+            ray_map.area_map.clear();
 
-            for (camera_entity, camera, camera_tfm) in &cameras {
-                if !camera.is_active {
+            for (camera_entity, camera, camera_tfm, ndc_mapper, picking_camera) in &cameras {
+                if !camera.is_active || !PickingCamera::is_enabled(picking_camera) {
                     continue;
                 }
 
-                for (&pointer_id, pointer_loc) in &pointers {
-                    if let Some(ray) =
-                        make_ray(&primary_window_entity, camera, camera_tfm, pointer_loc)
-                    {
-                        ray_map
-                            .map
-                            .insert(RayId::new(camera_entity, pointer_id), ray);
+                for (&pointer_id, pointer_loc, smoothed, radius) in &pointers {
+                    let Some(pointer_loc) = pointer_loc.hit_test_location(smoothed) else {
+                        continue;
+                    };
+                    let make_ray = |offset| {
+                        make_ray(
+                            &primary_window_entity,
+                            camera,
+                            camera_tfm,
+                            ndc_mapper,
+                            &pointer_loc,
+                            offset,
+                        )
+                    };
+                    let Some(ray) = make_ray(Vec2::ZERO) else {
+                        continue;
+                    };
+                    let ray_id = RayId::new(camera_entity, pointer_id);
+                    ray_map.map.insert(ray_id, ray);
+                    if let Some(radius) = radius.filter(|radius| radius.is_area()) {
+                        let area_rays = area_offsets(radius.0).filter_map(make_ray).collect();
+                        ray_map.area_map.insert(ray_id, area_rays);
                     }
                 }
             }
@@ -216,17 +643,45 @@ pub mod ray {
         primary_window_entity: &Query<Entity, With<PrimaryWindow>>,
         camera: &Camera,
         camera_tfm: &GlobalTransform,
-        pointer_loc: &PointerLocation,
+        ndc_mapper: Option<&NdcMapper>,
+        pointer_loc: &Location,
+        offset: Vec2,
     ) -> Option<Ray3d> {
-        let pointer_loc = pointer_loc.location()?;
         if !pointer_loc.is_in_viewport(camera, primary_window_entity) {
             return None;
         }
-        let mut viewport_pos = pointer_loc.position;
+        let mut viewport_pos = pointer_loc.position + offset;
         if let Some(viewport) = &camera.viewport {
             let viewport_logical = camera.to_logical(viewport.physical_position)?;
             viewport_pos -= viewport_logical;
         }
-        camera.viewport_to_world(camera_tfm, viewport_pos)
+        let Some(ndc_mapper) = ndc_mapper else {
+            return camera.viewport_to_world(camera_tfm, viewport_pos);
+        };
+        let ndc = ndc_mapper.map(viewport_pos, camera.logical_viewport_size()?)?;
+        let world_near_plane = camera.ndc_to_world(camera_tfm, ndc.extend(1.0))?;
+        // Using EPSILON because an ndc with Z = 0 returns NaNs.
+        let world_far_plane = camera.ndc_to_world(camera_tfm, ndc.extend(f32::EPSILON))?;
+        let direction = Direction3d::new(world_far_plane - world_near_plane).ok()?;
+        Some(Ray3d {
+            origin: world_near_plane,
+            direction,
+        })
+    }
+
+    /// The number of rings of sample rays spread over a [`PointerRadius`], around the center ray.
+    const AREA_RINGS: usize = 3;
+
+    /// Offsets of the sample rays of a pointer with a radius, in logical pixels: the center, and
+    /// [`AREA_RINGS`] evenly spaced rings, each with six more samples than the last.
+    fn area_offsets(radius: f32) -> impl Iterator<Item = Vec2> {
+        std::iter::once(Vec2::ZERO).chain((1..=AREA_RINGS).flat_map(move |ring| {
+            let ring_radius = radius * ring as f32 / AREA_RINGS as f32;
+            let samples = 6 * ring;
+            (0..samples).map(move |sample| {
+                let angle = std::f32::consts::TAU * sample as f32 / samples as f32;
+                Vec2::from_angle(angle) * ring_radius
+            })
+        }))
     }
 }
@@ -0,0 +1,91 @@
+//! Per-frame counts of picking activity, for finding out where picking stops working.
+//!
+//! The [`PickingDiagnostics`] resource is updated every frame, once the focus and event systems
+//! have run. Reading its counts from the start of the picking pipeline to its end can narrow down
+//! why an entity doesn't react to a pointer:
+//!
+//! - No [`pointers`](PickingDiagnostics::pointers): no input plugin spawned a pointer.
+//! - No [`pointer_hits`](PickingDiagnostics::pointer_hits): no backend is running, or no backend
+//!   found a camera to hit test with.
+//! - No [`hits`](PickingDiagnostics::hits): backends are running, but the entity is not under the
+//!   pointer, or it is not pickable by the backend that should pick it.
+//! - No [`hovered`](PickingDiagnostics::hovered) entities: hits are reported, but the focus systems
+//!   filter them out, such as because of a [`Pickable`](crate::Pickable) component or a blocking
+//!   entity in front.
+//! - No [`events`](PickingDiagnostics::events): entities are hovered but no events are sent, such
+//!   as because of the [`PickingEventSettings`](crate::PickingEventSettings).
+//!
+//! When all of these look right, events reach the event listeners, and the problem is in how they
+//! are handled.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_picking_core::diagnostics::PickingDiagnostics;
+//! fn log_diagnostics(diagnostics: Res<PickingDiagnostics>) {
+//!     if diagnostics.hits > 0 && diagnostics.hovered == 0 {
+//!         println!("hits are reported, but nothing is hovered");
+//!     }
+//! }
+//! ```
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use bevy_utils::HashMap;
+
+use crate::{
+    backend::PointerHits,
+    events::{AnyPointerEvent, Pointer},
+    focus::HoverMap,
+    pointer::PointerId,
+};
+
+/// Counts of the picking activity of the last frame. See the [module docs](self).
+#[derive(Debug, Default, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource, Default)]
+pub struct PickingDiagnostics {
+    /// The number of pointers.
+    pub pointers: usize,
+    /// The number of [`PointerHits`] events sent by backends.
+    pub pointer_hits: usize,
+    /// The total number of hits in those [`PointerHits`] events.
+    pub hits: usize,
+    /// The total number of entities in the [`HoverMap`], across all pointers.
+    pub hovered: usize,
+    /// The number of [`Pointer`] events sent, by the name of their kind, such as `"Click"`. Kinds
+    /// without any events are left out, and events discarded by the
+    /// [`PickingEventSettings`](crate::PickingEventSettings) are not counted.
+    pub events: HashMap<String, usize>,
+}
+
+impl PickingDiagnostics {
+    /// The number of [`Pointer`] events of the kind named `kind` sent during the last frame.
+    pub fn event_count(&self, kind: &str) -> usize {
+        self.events.get(kind).copied().unwrap_or_default()
+    }
+}
+
+/// Updates the [`PickingDiagnostics`] with the activity of this frame.
+pub fn update_picking_diagnostics(
+    pointers: Query<(), With<PointerId>>,
+    mut pointer_hits: EventReader<PointerHits>,
+    hover_map: Res<HoverMap>,
+    mut pointer_events: EventReader<Pointer<AnyPointerEvent>>,
+    mut diagnostics: ResMut<PickingDiagnostics>,
+) {
+    let diagnostics = &mut *diagnostics;
+    diagnostics.pointers = pointers.iter().count();
+    diagnostics.pointer_hits = 0;
+    diagnostics.hits = 0;
+    for hits in pointer_hits.read() {
+        diagnostics.pointer_hits += 1;
+        diagnostics.hits += hits.picks.len();
+    }
+    diagnostics.hovered = hover_map.values().map(|hovered| hovered.len()).sum();
+    diagnostics.events.clear();
+    for event in pointer_events.read() {
+        *diagnostics
+            .events
+            .entry(event.event.kind().to_string())
+            .or_default() += 1;
+    }
+}
@@ -1,24 +1,73 @@
 //! Processes data from input and backends, producing interaction events.
 
-use std::fmt::Debug;
+use std::{
+    any::{Any, TypeId},
+    fmt::Debug,
+    sync::Arc,
+    time::Duration,
+};
 
 use crate::{
-    backend::HitData,
-    focus::{HoverMap, PreviousHoverMap},
+    backend::{
+        ray::{RayId, RayMap},
+        HitData,
+    },
+    focus::{
+        hover_entered, hover_left, EventHoverMaps, HoverMap, PreviousEventHoverMaps,
+        PreviousHoverMap,
+    },
     pointer::{
-        self, InputMove, InputPress, Location, PointerButton, PointerId, PointerLocation,
-        PointerMap, PressDirection,
+        self, CurrentModifiers, DebouncedPresses, DisabledPointers, InputMove, InputPress,
+        InputScroll, KeyModifiers, Location, PointerButton, PointerId, PointerInteraction,
+        PointerLocation, PointerLocationMapper, PointerMap, PointerPress, PointerPressure,
+        PointerVelocity, PressDirection, ScrollUnit,
     },
+    PickingEventSettings,
 };
 use bevy_derive::{Deref, DerefMut};
-use bevy_ecs::prelude::*;
-use bevy_eventlistener::prelude::*;
-use bevy_math::Vec2;
+use bevy_ecs::{
+    entity::{Entities, EntityHashMap, MapEntities},
+    event::EventId,
+    prelude::*,
+    system::{SystemParam, SystemState},
+};
+use bevy_eventlistener::{event_dispatcher::EventDispatcher, prelude::*, EventListenerSet};
+use bevy_hierarchy::{BuildWorldChildren, Parent};
+use bevy_math::{
+    primitives::{Direction3d, Plane3d},
+    Rect, Vec2, Vec3,
+};
 use bevy_reflect::prelude::*;
-use bevy_utils::{tracing::debug, HashMap};
+use bevy_render::camera::Camera;
+use bevy_time::{Real, Time};
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::{tracing::debug, HashMap, HashSet};
+use bevy_window::{PrimaryWindow, Window};
 
 /// Stores the common data needed for all `PointerEvent`s.
+///
+/// ### Reflection and serialization
+///
+/// Every built-in pointer event is [`Reflect`], and registered by the
+/// [`InteractionPlugin`](crate::InteractionPlugin) along with its payload, so events can be shown
+/// in inspectors and serialized with bevy's reflection serializers. The primitive and math types
+/// they contain, such as `Entity` and `Vec2`, are registered by bevy's default plugins. With the
+/// `serialize` feature, they also implement serde's `Serialize` and `Deserialize`. Only pointer
+/// locations on windows can be serialized with serde, because image and texture view targets are
+/// asset handles.
+///
+/// Entities, such as the `target` and the camera of each hit, are serialized as their raw ids,
+/// which are only meaningful in the world the event was recorded in. When loading recorded events
+/// into another world, remap their entities with [`MapEntities`].
+///
+/// ### Bubbling
+///
+/// Pointer events bubble up the entity hierarchy by default, running the [`On`] listeners of the
+/// target's ancestors. Event types can be opted out of bubbling with [`PointerBubbling`], so they
+/// only run the target's own listener, and bubbling stops at [`BubbleBoundary`] entities. Both are
+/// applied when the events are dispatched by a [`PointerListenerPlugin`].
 #[derive(Clone, PartialEq, Debug, Reflect, Event, EntityEvent)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[can_bubble]
 pub struct Pointer<E: Debug + Clone + Reflect> {
     /// The target of this event
@@ -26,7 +75,8 @@ pub struct Pointer<E: Debug + Clone + Reflect> {
     pub target: Entity,
     /// The pointer that triggered this event
     pub pointer_id: PointerId,
-    /// The location of the pointer during this event
+    /// The location of the pointer during this event, including the window or other render target
+    /// the pointer was on. See [`Pointer::window`].
     pub pointer_location: Location,
     /// Additional event-specific data. [`Drop`] for example, has an additional field to describe
     /// the `Entity` that is being dropped on the target.
@@ -60,6 +110,411 @@ impl<E: Debug + Clone + Reflect> Pointer<E> {
             event,
         }
     }
+
+    /// Returns the window the pointer was on during this event, if it was on a window. In apps with
+    /// multiple windows, this can be used to handle events from each window differently.
+    pub fn window(&self) -> Option<Entity> {
+        self.pointer_location.window()
+    }
+}
+
+impl<E: Debug + Clone + Reflect + MapEntities> MapEntities for Pointer<E> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.target = entity_mapper.map_entity(self.target);
+        self.pointer_location.map_entities(entity_mapper);
+        self.event.map_entities(entity_mapper);
+    }
+}
+
+macro_rules! impl_map_entities {
+    ($($kind:ident),* => |$event:ident, $mapper:ident| $body:block) => {
+        $(
+            impl MapEntities for $kind {
+                fn map_entities<M: EntityMapper>(&mut self, $mapper: &mut M) {
+                    let $event = self;
+                    $body
+                }
+            }
+        )*
+    };
+}
+
+impl_map_entities!(
+    Over,
+    Out,
+    HoverStart,
+    Down,
+    Up,
+    Click,
+    DoubleClick,
+    LongPress,
+    Pinch,
+    Rotate,
+    Move,
+    Scroll,
+    DragStart
+    => |event, mapper| {
+        event.hit.map_entities(mapper);
+    }
+);
+impl_map_entities!(Drag, DragEnd => |event, mapper| {
+    if let Some(hit) = &mut event.hit {
+        hit.map_entities(mapper);
+    }
+});
+impl_map_entities!(DragEnter, DragOver, DragLeave => |event, mapper| {
+    event.dragged = mapper.map_entity(event.dragged);
+    event.hit.map_entities(mapper);
+});
+impl_map_entities!(Drop => |event, mapper| {
+    event.dropped = mapper.map_entity(event.dropped);
+    event.hit.map_entities(mapper);
+});
+impl_map_entities!(DragViewportExit, DragViewportEnter, Idle, Wake => |_event, _mapper| {});
+impl_map_entities!(WindowEnter, WindowLeave => |event, mapper| {
+    event.window = mapper.map_entity(event.window);
+});
+
+/// Pointer events that are caused by a specific [`PointerButton`].
+pub trait PointerButtonEvent: Debug + Clone + Reflect {
+    /// The button that caused this event.
+    fn button(&self) -> PointerButton;
+}
+
+macro_rules! impl_pointer_button_event {
+    ($($kind:ident),*) => {
+        $(
+            impl PointerButtonEvent for $kind {
+                fn button(&self) -> PointerButton {
+                    self.button
+                }
+            }
+        )*
+    };
+}
+
+impl_pointer_button_event!(
+    Down,
+    Up,
+    Click,
+    DoubleClick,
+    LongPress,
+    DragStart,
+    Drag,
+    DragEnd,
+    DragEnter,
+    DragOver,
+    DragLeave,
+    Drop,
+    DragViewportExit,
+    DragViewportEnter
+);
+
+impl<E: PointerButtonEvent> Pointer<E> {
+    /// Creates an event listener that only runs `callback` when the event was caused by `button`.
+    /// Events with other buttons still bubble as usual.
+    ///
+    /// For example, `Pointer::<Click>::on_button(PointerButton::Secondary, open_context_menu)` only
+    /// runs `open_context_menu` for right clicks.
+    pub fn on_button<Marker>(
+        button: PointerButton,
+        callback: impl IntoSystem<(), (), Marker>,
+    ) -> On<Self> {
+        let mut callback = IntoSystem::into_system(callback);
+        let mut is_initialized = false;
+        On::<Self>::run(move |world: &mut World| {
+            if world.resource::<ListenerInput<Self>>().button() != button {
+                return;
+            }
+            if !is_initialized {
+                callback.initialize(world);
+                is_initialized = true;
+            }
+            callback.run((), world);
+            callback.apply_deferred(world);
+        })
+    }
+}
+
+/// Selects which types of [`Pointer`] events bubble up the entity hierarchy. All of them do by
+/// default.
+///
+/// Events that do not bubble only run the [`On`] listener of their target, and are dispatched
+/// without traversing the hierarchy at all, which is cheaper in scenes with many independent
+/// pickable entities. This is decided when events are dispatched, so it applies to every event
+/// type whose listeners are added with a [`PointerListenerPlugin`], including events you send
+/// yourself. Each event type is configured on its own, so the [`AnyPointerEvent`]s they are
+/// forwarded as still bubble unless they are disabled too.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_picking_core::events::*;
+/// # let mut app = App::new();
+/// let mut bubbling = PointerBubbling::default();
+/// bubbling.disable::<Over>().disable::<Out>();
+/// app.insert_resource(bubbling);
+/// ```
+///
+/// [`InteractionPlugin`]: crate::InteractionPlugin
+#[derive(Debug, Default, Clone, Resource, Reflect)]
+#[reflect(Resource, Default)]
+pub struct PointerBubbling {
+    /// The event types, such as [`Over`], whose [`Pointer`] events do not bubble.
+    #[reflect(ignore)]
+    disabled: HashSet<TypeId>,
+}
+
+impl PointerBubbling {
+    /// Stops [`Pointer<E>`] events from bubbling.
+    pub fn disable<E: Debug + Clone + Reflect>(&mut self) -> &mut Self {
+        self.disabled.insert(TypeId::of::<E>());
+        self
+    }
+
+    /// Lets [`Pointer<E>`] events bubble again.
+    pub fn enable<E: Debug + Clone + Reflect>(&mut self) -> &mut Self {
+        self.disabled.remove(&TypeId::of::<E>());
+        self
+    }
+
+    /// Returns `true` if [`Pointer<E>`] events bubble.
+    pub fn bubbles<E: Debug + Clone + Reflect>(&self) -> bool {
+        self.disabled.is_empty() || !self.disabled.contains(&TypeId::of::<E>())
+    }
+}
+
+/// Sends [`Pointer<E>`] events, applying the [`PickingEventSettings`]. Used in place of an
+/// [`EventWriter`] by the systems that send pointer events.
+///
+/// Events whose category is disabled in the [`PickingEventSettings`] are not sent. They are kept
+/// in the [`WithheldPointerEvents<E>`] instead, if that resource exists.
+#[derive(SystemParam)]
+pub struct PointerEventWriter<'w, E: Debug + Clone + Reflect> {
+    events: EventWriter<'w, Pointer<E>>,
+    settings: Res<'w, PickingEventSettings>,
+    withheld: Option<ResMut<'w, WithheldPointerEvents<E>>>,
+}
+
+impl<E: Debug + Clone + Reflect> PointerEventWriter<'_, E> {
+    /// Sends `event`. Returns `None` without sending it if its category is disabled in the
+    /// [`PickingEventSettings`].
+    pub fn send(&mut self, event: Pointer<E>) -> Option<EventId<Pointer<E>>> {
+        if !self.settings.allows::<E>() {
+            if let Some(withheld) = self.withheld.as_mut() {
+                withheld.0.push(event);
+            }
+            None
+        } else {
+            Some(self.events.send(event))
+        }
+    }
+}
+
+/// The [`Pointer<E>`] events that were not sent because their category is disabled in the
+/// [`PickingEventSettings`].
+///
+/// Some interactions are tracked from the events of another category: drags start from [`Down`]
+/// events, and drag-over events follow [`Over`], [`Move`], and [`Out`] events. The systems that
+/// track them also read the withheld events of these types, so disabling clicks or hover does not
+/// stop drags. Withheld events are removed when they are read.
+#[derive(Debug, Resource)]
+pub struct WithheldPointerEvents<E: Debug + Clone + Reflect>(Vec<Pointer<E>>);
+
+impl<E: Debug + Clone + Reflect> Default for WithheldPointerEvents<E> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<E: Debug + Clone + Reflect> WithheldPointerEvents<E> {
+    /// Removes and returns the withheld events, in the order they were withheld.
+    pub fn drain(&mut self) -> impl Iterator<Item = Pointer<E>> + '_ {
+        self.0.drain(..)
+    }
+}
+
+/// Stops [`Pointer`] events from bubbling past this entity.
+///
+/// When an event bubbles up to an entity with this component, that entity's [`On`] listener still
+/// runs, but no listeners above it in the hierarchy do. This behaves like calling
+/// `stop_propagation` at the end of the boundary's listener, without needing to add it to every
+/// listener in a nested UI. Explicit calls to `stop_propagation` below the boundary still stop the
+/// event early, and events that target the boundary's ancestors directly are unaffected.
+///
+/// This applies to the events dispatched by a [`PointerListenerPlugin`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct BubbleBoundary;
+
+/// Adds the [`On<Pointer<E>>`](On) listeners of an event type, like an [`EventListenerPlugin`], and
+/// applies the [`PointerBubbling`] and [`BubbleBoundary`]s when dispatching the events.
+///
+/// The [`InteractionPlugin`](crate::InteractionPlugin) adds this for the built-in pointer events.
+/// Use it instead of an [`EventListenerPlugin`] for your own pointer event types.
+pub struct PointerListenerPlugin<E: Debug + Clone + Reflect>(std::marker::PhantomData<E>);
+
+impl<E: Debug + Clone + Reflect> Default for PointerListenerPlugin<E> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<E: Debug + Clone + Reflect> bevy_app::Plugin for PointerListenerPlugin<E> {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_event::<Pointer<E>>()
+            .insert_resource(EventDispatcher::<Pointer<E>>::default())
+            .add_systems(
+                bevy_app::PreUpdate,
+                (
+                    build_pointer_listener_graph::<E>,
+                    EventDispatcher::<Pointer<E>>::bubble_events,
+                    EventDispatcher::<Pointer<E>>::cleanup,
+                )
+                    .chain()
+                    .run_if(on_event::<Pointer<E>>())
+                    .in_set(EventListenerSet),
+            );
+    }
+}
+
+/// The parameters of [`EventDispatcher::build`].
+type ListenerGraphParams<E> = (
+    EventReader<'static, 'static, Pointer<E>>,
+    Query<'static, 'static, (Option<&'static mut On<Pointer<E>>>, Option<&'static Parent>)>,
+    ResMut<'static, EventDispatcher<Pointer<E>>>,
+    Local<'static, HashSet<Entity>>,
+    Local<'static, HashMap<Entity, Entity>>,
+);
+
+/// The listeners that [`Pointer<E>`] events are dispatched to this frame, for
+/// [`build_pointer_listener_graph`].
+///
+/// [`EventDispatcher::build`] can only follow [`Parent`]s, so the chains of listeners found for the
+/// events are copied into this private world, where each entity is the child of the next listener
+/// it bubbles to. The graph is built from that world, which takes the callbacks out of the copied
+/// listeners, and the emptied listeners are swapped back. The callbacks still run in the app's
+/// world, and are returned to their listeners by [`EventDispatcher::cleanup`].
+struct ListenerChains<E: Debug + Clone + Reflect> {
+    world: World,
+    graph: SystemState<ListenerGraphParams<E>>,
+    /// Each copied entity, and the next listener it bubbles to.
+    links: Vec<(Entity, Entity)>,
+    /// The copied listeners.
+    copied: Vec<Entity>,
+    /// Targets of this frame's events that do not reach any listener.
+    unreached: HashSet<Entity>,
+    /// Listeners that take the place of the copied ones while the graph is built, reused every
+    /// frame.
+    fillers: Vec<On<Pointer<E>>>,
+}
+
+impl<E: Debug + Clone + Reflect> FromWorld for ListenerChains<E> {
+    fn from_world(_: &mut World) -> Self {
+        let mut world = World::new();
+        world.init_resource::<Events<Pointer<E>>>();
+        world.init_resource::<EventDispatcher<Pointer<E>>>();
+        let graph = SystemState::new(&mut world);
+        Self {
+            world,
+            graph,
+            links: Vec::new(),
+            copied: Vec::new(),
+            unreached: HashSet::new(),
+            fillers: Vec::new(),
+        }
+    }
+}
+
+/// Builds the graph of listeners that [`Pointer<E>`] events bubble through.
+///
+/// Starting at the target of each event, the [`On<Pointer<E>>`](On) listeners are collected by
+/// following [`Parent`]s up the hierarchy, stopping after the first [`BubbleBoundary`]. Event types
+/// disabled in the [`PointerBubbling`] only collect the listener of their target, without reading
+/// the hierarchy. The hierarchy itself is never modified.
+fn build_pointer_listener_graph<E: Debug + Clone + Reflect>(
+    mut events: EventReader<Pointer<E>>,
+    bubbling: Res<PointerBubbling>,
+    mut listeners: Query<&mut On<Pointer<E>>>,
+    (parents, boundaries): (Query<&Parent>, Query<(), With<BubbleBoundary>>),
+    mut dispatcher: ResMut<EventDispatcher<Pointer<E>>>,
+    mut chains: Local<ListenerChains<E>>,
+) {
+    let bubbles = bubbling.bubbles::<E>();
+    let ListenerChains {
+        world,
+        graph,
+        links,
+        copied,
+        unreached,
+        fillers,
+    } = &mut *chains;
+
+    for event in events.read() {
+        if unreached.contains(&event.target) {
+            continue;
+        }
+        // Each entity is copied once, along with the rest of the chain above it.
+        let mut entity = event.target;
+        while world.get_entity(entity).is_none() {
+            let is_listener = listeners.contains(entity);
+            let mut next = None;
+            if bubbles {
+                let mut current = entity;
+                while !boundaries.contains(current) {
+                    let Ok(parent) = parents.get(current) else {
+                        break;
+                    };
+                    current = parent.get();
+                    if listeners.contains(current) {
+                        next = Some(current);
+                        break;
+                    }
+                }
+            }
+            if !is_listener && next.is_none() {
+                unreached.insert(entity);
+                break;
+            }
+            let mut copy = world
+                .get_or_spawn(entity)
+                .expect("the entity was not copied");
+            if let Ok(mut listener) = listeners.get_mut(entity) {
+                let filler = fillers.pop().unwrap_or_else(|| On::run(|| {}));
+                copy.insert(std::mem::replace(&mut *listener, filler));
+                copied.push(entity);
+            }
+            let Some(next) = next else {
+                break;
+            };
+            links.push((entity, next));
+            entity = next;
+        }
+        if world.get_entity(event.target).is_some() {
+            world.send_event(event.clone());
+        }
+    }
+    for (entity, next) in links.drain(..) {
+        world.entity_mut(entity).set_parent(next);
+    }
+
+    std::mem::swap(&mut *dispatcher, &mut *world.resource_mut());
+    let (events, chain_listeners, chain_dispatcher, dead, cache) = graph.get_mut(world);
+    EventDispatcher::build(events, chain_listeners, chain_dispatcher, dead, cache);
+    std::mem::swap(&mut *dispatcher, &mut *world.resource_mut());
+
+    for entity in copied.drain(..) {
+        let mut copy = world.entity_mut(entity);
+        let (Some(mut copied_listener), Ok(mut listener)) =
+            (copy.get_mut::<On<Pointer<E>>>(), listeners.get_mut(entity))
+        else {
+            continue;
+        };
+        std::mem::swap(&mut *copied_listener, &mut *listener);
+        fillers.extend(copy.take::<On<Pointer<E>>>());
+    }
+    world.clear_entities();
+    world.resource_mut::<Events<Pointer<E>>>().clear();
+    unreached.clear();
 }
 
 /// Fires when a pointer is no longer available.
@@ -70,8 +525,112 @@ pub struct PointerCancel {
     pub pointer_id: PointerId,
 }
 
+/// Fires whenever a pointer moves, whether or not it is over an entity. Unlike [`Move`], this is a
+/// global event without a target, so it is not bubbled or sent to event listeners. Useful for
+/// updating a custom cursor or crosshair.
+///
+/// This is opt-in, see [`PointerMovedSettings`].
+#[derive(Event, Clone, PartialEq, Debug, Reflect)]
+pub struct PointerMoved {
+    /// ID of the pointer that moved.
+    pub pointer_id: PointerId,
+    /// The location of the pointer after moving.
+    pub location: Location,
+    /// The change in position since the last move.
+    pub delta: Vec2,
+}
+
+/// Controls whether [`PointerMoved`] events are sent.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct PointerMovedSettings {
+    /// Sends a [`PointerMoved`] event every time a pointer moves. Disabled by default.
+    pub is_enabled: bool,
+}
+
+impl PointerMovedSettings {
+    /// Whether or not [`PointerMoved`] events should be sent.
+    pub fn should_run(state: Res<Self>) -> bool {
+        state.is_enabled
+    }
+}
+
+/// Sends a [`PointerMoved`] event for every pointer move input.
+pub fn send_pointer_moved_events(
+    mut input_moves: EventReader<InputMove>,
+    disabled: DisabledPointers,
+    mapper: Res<PointerLocationMapper>,
+    mut pointer_moved: EventWriter<PointerMoved>,
+) {
+    for InputMove {
+        pointer_id,
+        location,
+        delta,
+        ..
+    } in input_moves
+        .read()
+        .filter(|input| !disabled.contains(input.pointer_id))
+        .map(|input| mapper.map_move(input))
+    {
+        pointer_moved.send(PointerMoved {
+            pointer_id,
+            location,
+            delta,
+        });
+    }
+}
+
+/// Fires whenever a pointer button is pressed, whether or not it is over an entity. Unlike
+/// [`Down`], this is a global event without a target, so it is not bubbled or sent to event
+/// listeners, and it is also sent when pressing empty space. Useful for dismissing popups and menus
+/// when pressing outside of them, by checking whether the [`target`](Self::target) is one of their
+/// descendants.
+#[derive(Event, Clone, PartialEq, Debug, Reflect)]
+pub struct PointerDownGlobal {
+    /// ID of the pointer that was pressed.
+    pub pointer_id: PointerId,
+    /// The button that was pressed.
+    pub button: PointerButton,
+    /// The location of the pointer when it was pressed.
+    pub location: Location,
+    /// The nearest entity hovered by the pointer when it was pressed, if any. This is the
+    /// [`PointerInteraction::nearest_entity`] of the pointer.
+    pub target: Option<Entity>,
+}
+
+/// Sends a [`PointerDownGlobal`] event for every press of a pointer with a location.
+pub fn send_pointer_down_global_events(
+    mut input_presses: DebouncedPresses,
+    disabled: DisabledPointers,
+    pointer_map: Res<PointerMap>,
+    pointers: Query<(&PointerLocation, Option<&PointerInteraction>)>,
+    mut pointer_down: EventWriter<PointerDownGlobal>,
+) {
+    for press in input_presses
+        .read()
+        .filter(|press| press.direction == PressDirection::Down)
+        .filter(|press| !disabled.contains(press.pointer_id))
+    {
+        let Some((location, interaction)) = pointer_map
+            .get_entity(press.pointer_id)
+            .and_then(|entity| pointers.get(entity).ok())
+            .and_then(|(pointer, interaction)| Some((pointer.location.clone()?, interaction)))
+        else {
+            continue;
+        };
+        let target = interaction.and_then(PointerInteraction::nearest_entity);
+        pointer_down.send(PointerDownGlobal {
+            pointer_id: press.pointer_id,
+            button: press.button,
+            location,
+            target,
+        });
+    }
+}
+
 /// Fires when a the pointer crosses into the bounds of the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Over {
     /// Information about the picking intersection.
     pub hit: HitData,
@@ -79,79 +638,370 @@ pub struct Over {
 
 /// Fires when a the pointer crosses out of the bounds of the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Out {
     /// Information about the latest prior picking intersection.
     pub hit: HitData,
 }
 
+/// Fires when a pointer has hovered the `target` entity continuously for the duration of its
+/// [`HoverDelay`]. Only entities with a [`HoverDelay`] receive this event, and it is sent at most
+/// once each time a pointer hovers the entity. This is useful for tooltips, which should only
+/// appear once the pointer rests on an element, instead of on every [`Over`].
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct HoverStart {
+    /// Information about the picking intersection.
+    pub hit: HitData,
+}
+
+/// Fires once when a pointer has not moved for the [`IdleSettings::timeout`]. The `target` is the
+/// pointer's own entity, so listeners for this event are added to pointer entities. Useful for
+/// hiding cursors and UI that only matter while the pointer is in use.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Idle;
+
+/// Fires when an [`Idle`] pointer moves again. Like [`Idle`], the `target` is the pointer's own
+/// entity.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wake {
+    /// How long the pointer went without moving, including the [`IdleSettings::timeout`].
+    pub idle_for: Duration,
+}
+
+/// Fires when a pointer enters the bounds of a window, including when it moves from one window to
+/// another. Like [`Idle`], the `target` is the pointer's own entity. Useful for showing a custom
+/// cursor only while the pointer is over a window.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowEnter {
+    /// The window entity the pointer entered.
+    pub window: Entity,
+}
+
+/// Fires when a pointer leaves the bounds of a window, including when it moves to another window,
+/// before the [`WindowEnter`] of the other window. Like [`Idle`], the `target` is the pointer's
+/// own entity.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowLeave {
+    /// The window entity the pointer left.
+    pub window: Entity,
+}
+
 /// Fires when a pointer button is pressed over the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Down {
     /// Pointer button pressed to trigger this event.
     pub button: PointerButton,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// The [`PointerPressure`] when the button was pressed.
+    pub pressure: Option<f32>,
+    /// The modifier keys held when the button was pressed, see
+    /// [`ModifierCapture`](pointer::ModifierCapture).
+    pub modifiers: KeyModifiers,
 }
 
 /// Fires when a pointer button is released over the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Up {
     /// Pointer button lifted to trigger this event.
     pub button: PointerButton,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// How long the button was held before it was lifted, see [`PointerPress::held_duration`].
+    pub held: Duration,
+    /// The modifier keys held when the button was lifted, see
+    /// [`ModifierCapture`](pointer::ModifierCapture).
+    pub modifiers: KeyModifiers,
 }
 
 /// Fires when a pointer sends a pointer down event followed by a pointer up event, with the same
 /// `target` entity for both events.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Click {
     /// Pointer button pressed and lifted to trigger this event.
     pub button: PointerButton,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// The number of consecutive clicks on the `target` this click completes, starting at 1. A
+    /// click continues the count when it follows the previous one within the
+    /// [`ClickSettings::multi_click_window`], and within the
+    /// [`ClickSettings::multi_click_tolerance`] of its position.
+    pub click_count: u16,
+    /// How long the button was held before it was lifted, see [`PointerPress::held_duration`].
+    pub held: Duration,
+    /// The modifier keys held when the button was lifted, see
+    /// [`ModifierCapture`](pointer::ModifierCapture).
+    pub modifiers: KeyModifiers,
+}
+
+/// Fires when a pointer clicks the same `target` entity twice, with the same button, within the
+/// [`ClickSettings::multi_click_window`] and [`ClickSettings::multi_click_tolerance`]. This is sent
+/// along with the [`Click`] whose `click_count` is 2.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoubleClick {
+    /// Pointer button clicked twice to trigger this event.
+    pub button: PointerButton,
+    /// Information about the picking intersection.
+    pub hit: HitData,
+}
+
+/// Fires once when a pointer button has been held down on the `target` entity for longer than the
+/// [`LongPressSettings::threshold`], without the pointer moving off the entity or further than the
+/// [`LongPressSettings::tolerance`]. Releasing the button or moving away first cancels it.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct LongPress {
+    /// Pointer button held to trigger this event.
+    pub button: PointerButton,
+    /// How long the button has been held.
+    pub duration: Duration,
+    /// Information about the picking intersection when the button was pressed.
+    pub hit: HitData,
+}
+
+/// Fires while two touch pointers hovering the `target` entity move closer together or further
+/// apart. See [`send_touch_gesture_events`] for how touches are paired.
+///
+/// The event's `pointer_id` is the first pointer of the pair, and its location is the midpoint
+/// between the two touches.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pinch {
+    /// The two touch pointers performing the gesture.
+    pub pointers: [PointerId; 2],
+    /// The ratio of the current distance between the touches to the distance at the last
+    /// [`Pinch`]. Values greater than 1 mean the touches are spreading apart.
+    pub scale_delta: f32,
+    /// The current distance between the touches, in logical pixels.
+    pub distance: f32,
+    /// Information about the picking intersection of the first pointer.
+    pub hit: HitData,
+}
+
+/// Fires while two touch pointers hovering the `target` entity rotate around each other. See
+/// [`send_touch_gesture_events`] for how touches are paired.
+///
+/// The event's `pointer_id` is the first pointer of the pair, and its location is the midpoint
+/// between the two touches.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotate {
+    /// The two touch pointers performing the gesture.
+    pub pointers: [PointerId; 2],
+    /// The change in angle of the line between the touches since the last [`Rotate`], in radians.
+    /// Positive values are clockwise on screen.
+    pub angle_delta: f32,
+    /// Information about the picking intersection of the first pointer.
+    pub hit: HitData,
 }
 
 /// Fires while a pointer is moving over the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Move {
     /// Information about the picking intersection.
     pub hit: HitData,
-    /// The change in position since the last move event.
+    /// The change in position since the last move event. This is the change in the
+    /// [`Location::position`] of the pointer, which is in logical pixels of the window for the
+    /// mouse cursor and touches, or the raw mouse motion while the mouse has a
+    /// [`PointerGrab`](pointer::PointerGrab). It is the [`logical_delta`](Self::logical_delta)
+    /// instead if the [`MoveDeltaSettings`] normalize it.
+    pub delta: Vec2,
+    /// The change in position since the last move event, in logical pixels of the window of the
+    /// pointer's location, at its current scale factor. Unlike the change in
+    /// [`Location::position`], this is not affected by changes of the scale factor between moves.
+    /// This is the unnormalized [`delta`](Self::delta) if the location is not on a window.
+    pub logical_delta: Vec2,
+    /// The change in position since the last move event, in physical pixels of the window of the
+    /// pointer's location, or `None` if the location is not on a window.
+    pub physical_delta: Option<Vec2>,
+    /// The [`PointerPressure`] after the move.
+    pub pressure: Option<f32>,
+}
+
+/// Controls whether pointers that move several times in a frame send a [`Move`] event for every
+/// move, or a single one. Input devices with high polling rates can move a pointer many times per
+/// frame, which sends many [`Move`] events to listeners that often only need the latest position.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct MoveThrottle {
+    /// Coalesces every move of a pointer within a frame into a single [`Move`] event, with the
+    /// final location and pressure of the pointer and the sum of the deltas of every move, so the
+    /// total motion is unchanged. Disabled by default, which sends a [`Move`] event for each move.
+    pub coalesce: bool,
+}
+
+/// Controls the space of the [`Move::delta`]. The [`Move::logical_delta`] and
+/// [`Move::physical_delta`] are reported either way, using the scale factor of the window of the
+/// pointer's location.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct MoveDeltaSettings {
+    /// Reports the [`Move::logical_delta`] as the [`Move::delta`], so it is not affected by
+    /// changes of the window scale factor between moves. Disabled by default, which reports the
+    /// change in [`Location::position`].
+    pub normalize: bool,
+}
+
+/// Returns the logical and physical deltas of a move, see [`Move::logical_delta`] and
+/// [`Move::physical_delta`]. The scale factor of the window at the last move of each pointer is
+/// kept in `last_scales`.
+fn scaled_deltas(
+    input: &InputMove,
+    windows: &Query<&Window>,
+    last_scales: &mut HashMap<PointerId, (Entity, f32)>,
+) -> (Vec2, Option<Vec2>) {
+    let Some((window, scale)) = input
+        .location
+        .window()
+        .and_then(|window| Some((window, windows.get(window).ok()?.scale_factor())))
+    else {
+        last_scales.remove(&input.pointer_id);
+        return (input.delta, None);
+    };
+    let last_scale = match last_scales.insert(input.pointer_id, (window, scale)) {
+        Some((last_window, last_scale)) if last_window == window => last_scale,
+        _ => scale,
+    };
+    if last_scale == scale {
+        return (input.delta, Some(input.delta * scale));
+    }
+    let start = input.location.position - input.delta;
+    let physical_delta = input.location.position * scale - start * last_scale;
+    (physical_delta / scale, Some(physical_delta))
+}
+
+impl MoveThrottle {
+    /// Returns the moves to send [`Move`] events for, coalescing them per pointer if enabled.
+    fn throttle(&self, moves: impl Iterator<Item = InputMove>) -> Vec<InputMove> {
+        if !self.coalesce {
+            return moves.collect();
+        }
+        let mut coalesced: Vec<InputMove> = Vec::new();
+        for input in moves {
+            match coalesced
+                .iter_mut()
+                .find(|coalesced| coalesced.pointer_id == input.pointer_id)
+            {
+                Some(coalesced) => {
+                    coalesced.location = input.location;
+                    coalesced.delta += input.delta;
+                    coalesced.pressure = input.pressure;
+                }
+                None => coalesced.push(input),
+            }
+        }
+        coalesced
+    }
+}
+
+/// Fires when a pointer scrolls while over the `target` entity.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scroll {
+    /// The amount scrolled on each axis, in [`unit`](Self::unit)s, inverted on the axes inverted by
+    /// the [`ScrollSettings`]. Use [`Scroll::pixel_delta`] to scroll consistently with every input
+    /// device.
     pub delta: Vec2,
+    /// The unit of the [`delta`](Self::delta).
+    pub unit: ScrollUnit,
+    /// Information about the picking intersection.
+    pub hit: HitData,
+    /// Whether this event continues a scroll after its input stopped, because of
+    /// [`ScrollMomentum`]. Widgets that should not coast can ignore these events.
+    pub momentum: bool,
+}
+
+impl Scroll {
+    /// The [`delta`](Self::delta) in logical pixels, converting lines with
+    /// [`ScrollSettings::lines_to_pixels`].
+    pub fn pixel_delta(&self, settings: &ScrollSettings) -> Vec2 {
+        settings.to_pixels(self.delta, self.unit)
+    }
 }
 
 /// Fires when the `target` entity receives a pointer down event followed by a pointer move event.
+///
+/// Each button of a pointer drags independently, so holding several buttons while moving starts a
+/// drag for each of them, with its own [`Drag`] and [`DragEnd`] events. Releasing one of the
+/// buttons only ends the drags of that button.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct DragStart {
     /// Pointer button pressed and moved to trigger this event.
     pub button: PointerButton,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// The modifier keys held when the button was pressed, see
+    /// [`ModifierCapture`](pointer::ModifierCapture).
+    pub modifiers: KeyModifiers,
 }
 
 /// Fires while the `target` entity is being dragged.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Drag {
     /// Pointer button pressed and moved to trigger this event.
     pub button: PointerButton,
     /// The total distance vector of a drag, measured from drag start to the current position.
+    /// Constrained by the target's [`DragConstraint`], then scaled and snapped by its
+    /// [`DragModifier`], if any.
     pub distance: Vec2,
-    /// The change in position since the last drag event.
+    /// The change in position since the last drag event. Constrained by the target's
+    /// [`DragConstraint`], then scaled and snapped by its [`DragModifier`], if any.
     pub delta: Vec2,
+    /// The unconstrained and unmodified [`distance`](Self::distance).
+    pub raw_distance: Vec2,
+    /// The unconstrained and unmodified [`delta`](Self::delta).
+    pub raw_delta: Vec2,
+    /// The total distance of the drag in world space, on the target's [`DragPlane`]. This is `None`
+    /// if the target has no [`DragPlane`], or if the pointer's ray doesn't intersect it.
+    pub world_distance: Option<Vec3>,
+    /// The change in position on the target's [`DragPlane`] since the last drag event, in world
+    /// space. This is `None` if the target has no [`DragPlane`], or if the pointer's ray doesn't
+    /// intersect it.
+    pub world_delta: Option<Vec3>,
+    /// Information about the picking intersection with the `target`, if the pointer is currently
+    /// over it. This is `None` once the pointer has moved off of the dragged entity.
+    pub hit: Option<HitData>,
 }
 
 /// Fires when a pointer is dragging the `target` entity and a pointer up event is received.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct DragEnd {
     /// Pointer button pressed, moved, and lifted to trigger this event.
     pub button: PointerButton,
     /// The vector of drag movement measured from start to final pointer position.
     pub distance: Vec2,
+    /// The [`smoothed`](pointer::PointerVelocity::smoothed) velocity of the pointer when the drag
+    /// ended, in logical pixels per second. Useful for throwing or flicking the dragged entity.
+    pub velocity: Vec2,
+    /// Information about the picking intersection with the `target`, if the pointer was over it
+    /// when the drag ended.
+    pub hit: Option<HitData>,
+    /// The [`DragPayload`] of the `target` when the drag started, if it had one.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub payload: Option<DragPayload>,
+    /// Whether the drag was cancelled instead of being ended by a button release, such as when the
+    /// window lost focus during the drag, or when the `target` was despawned. The entities dragged
+    /// over still receive a [`DragLeave`], but no [`Drop`].
+    pub cancelled: bool,
 }
 
 /// Fires when a pointer dragging the `dragged` entity enters the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct DragEnter {
     /// Pointer button pressed to enter drag.
     pub button: PointerButton,
@@ -159,10 +1009,15 @@ pub struct DragEnter {
     pub dragged: Entity,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// Where the pointer entered the `target`, in the local space of its [`GlobalTransform`]. This
+    /// is `None` if the backend doesn't report hit positions, or if the `target` has no
+    /// [`GlobalTransform`].
+    pub local_position: Option<Vec3>,
 }
 
 /// Fires while the `dragged` entity is being dragged over the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct DragOver {
     /// Pointer button pressed while dragging over.
     pub button: PointerButton,
@@ -170,10 +1025,15 @@ pub struct DragOver {
     pub dragged: Entity,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// Where the pointer is over the `target`, in the local space of its [`GlobalTransform`], for
+    /// example to insert the dragged entity before or after the `target` in a list. This is `None`
+    /// if the backend doesn't report hit positions, or if the `target` has no [`GlobalTransform`].
+    pub local_position: Option<Vec3>,
 }
 
 /// Fires when a pointer dragging the `dragged` entity leaves the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct DragLeave {
     /// Pointer button pressed while leaving drag.
     pub button: PointerButton,
@@ -185,6 +1045,7 @@ pub struct DragLeave {
 
 /// Fires when a pointer drops the `dropped` entity onto the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Drop {
     /// Pointer button lifted to drop.
     pub button: PointerButton,
@@ -192,23 +1053,249 @@ pub struct Drop {
     pub dropped: Entity,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// The [`DragPayload`] of the `dropped` entity when the drag started, if it had one.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub payload: Option<DragPayload>,
+}
+
+/// Fires when a pointer dragging the `target` entity leaves the viewport the drag started in.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DragViewportExit {
+    /// Pointer button pressed while dragging.
+    pub button: PointerButton,
+    /// The edges of the viewport the pointer crossed to leave it.
+    pub edges: ViewportEdges,
+}
+
+/// Fires when a pointer dragging the `target` entity re-enters the viewport the drag started in.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct DragViewportEnter {
+    /// Pointer button pressed while dragging.
+    pub button: PointerButton,
+    /// The edges of the viewport the pointer crossed to re-enter it.
+    pub edges: ViewportEdges,
+}
+
+/// The edges of a viewport, used by [`DragViewportExit`] and [`DragViewportEnter`]. More than one
+/// edge is set when the pointer crosses a corner.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewportEdges {
+    /// The left edge.
+    pub left: bool,
+    /// The right edge.
+    pub right: bool,
+    /// The top edge.
+    pub top: bool,
+    /// The bottom edge.
+    pub bottom: bool,
+}
+
+impl ViewportEdges {
+    /// Returns the edges of `viewport` that `position` lies beyond. The result is empty if the
+    /// position is inside the viewport.
+    pub fn outside(viewport: Rect, position: Vec2) -> Self {
+        Self {
+            left: position.x < viewport.min.x,
+            right: position.x > viewport.max.x,
+            top: position.y < viewport.min.y,
+            bottom: position.y > viewport.max.y,
+        }
+    }
+
+    /// Returns `true` if no edge is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A single event type that wraps every built-in pointer event kind.
+///
+/// Every [`Pointer<E>`] event produced by the [`InteractionPlugin`](crate::InteractionPlugin) is
+/// also forwarded as a `Pointer<AnyPointerEvent>`, so you can attach a single
+/// `On::<Pointer<AnyPointerEvent>>` listener to an entity to log or route all interactions with it,
+/// instead of registering one listener per event type.
+///
+/// ### Ordering
+///
+/// Events are forwarded grouped by kind, in the order the variants of this enum are declared. For
+/// any single kind, events keep the order they were originally sent in, but there is no ordering
+/// guarantee between kinds beyond what the focus systems already provide. For example, within a
+/// frame, all `Over` events are forwarded before all `Down` events.
+///
+/// ### Performance
+///
+/// Forwarding clones every pointer event once, and the forwarded events bubble like any other
+/// pointer event. This is cheap for the typical handful of events per frame, but noisy events like
+/// [`Move`] and [`Drag`] are forwarded too. Prefer the specific event types for hot paths.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnyPointerEvent {
+    /// A forwarded [`Over`] event.
+    Over(Over),
+    /// A forwarded [`Out`] event.
+    Out(Out),
+    /// A forwarded [`HoverStart`] event.
+    HoverStart(HoverStart),
+    /// A forwarded [`Down`] event.
+    Down(Down),
+    /// A forwarded [`Up`] event.
+    Up(Up),
+    /// A forwarded [`Click`] event.
+    Click(Click),
+    /// A forwarded [`DoubleClick`] event.
+    DoubleClick(DoubleClick),
+    /// A forwarded [`LongPress`] event.
+    LongPress(LongPress),
+    /// A forwarded [`Pinch`] event.
+    Pinch(Pinch),
+    /// A forwarded [`Rotate`] event.
+    Rotate(Rotate),
+    /// A forwarded [`Move`] event.
+    Move(Move),
+    /// A forwarded [`Scroll`] event.
+    Scroll(Scroll),
+    /// A forwarded [`DragStart`] event.
+    DragStart(DragStart),
+    /// A forwarded [`Drag`] event.
+    Drag(Drag),
+    /// A forwarded [`DragEnd`] event.
+    DragEnd(DragEnd),
+    /// A forwarded [`DragEnter`] event.
+    DragEnter(DragEnter),
+    /// A forwarded [`DragOver`] event.
+    DragOver(DragOver),
+    /// A forwarded [`DragLeave`] event.
+    DragLeave(DragLeave),
+    /// A forwarded [`Drop`] event.
+    Drop(Drop),
+    /// A forwarded [`DragViewportExit`] event.
+    DragViewportExit(DragViewportExit),
+    /// A forwarded [`DragViewportEnter`] event.
+    DragViewportEnter(DragViewportEnter),
+    /// A forwarded [`Idle`] event.
+    Idle(Idle),
+    /// A forwarded [`Wake`] event.
+    Wake(Wake),
+    /// A forwarded [`WindowEnter`] event.
+    WindowEnter(WindowEnter),
+    /// A forwarded [`WindowLeave`] event.
+    WindowLeave(WindowLeave),
+}
+
+macro_rules! impl_any_pointer_event {
+    ($($kind:ident),*) => {
+        $(
+            impl From<$kind> for AnyPointerEvent {
+                fn from(event: $kind) -> Self {
+                    AnyPointerEvent::$kind(event)
+                }
+            }
+        )*
+
+        impl AnyPointerEvent {
+            /// The name of the kind of the forwarded event, such as `"Click"`.
+            pub fn kind(&self) -> &'static str {
+                match self {
+                    $(AnyPointerEvent::$kind(_) => stringify!($kind),)*
+                }
+            }
+        }
+
+        impl MapEntities for AnyPointerEvent {
+            fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+                match self {
+                    $(AnyPointerEvent::$kind(event) => event.map_entities(entity_mapper),)*
+                }
+            }
+        }
+    };
+}
+
+impl_any_pointer_event!(
+    Over,
+    Out,
+    HoverStart,
+    Down,
+    Up,
+    Click,
+    DoubleClick,
+    LongPress,
+    Pinch,
+    Rotate,
+    Move,
+    Scroll,
+    DragStart,
+    Drag,
+    DragEnd,
+    DragEnter,
+    DragOver,
+    DragLeave,
+    Drop,
+    DragViewportExit,
+    DragViewportEnter,
+    Idle,
+    Wake,
+    WindowEnter,
+    WindowLeave
+);
+
+/// Forwards [`Pointer<E>`] events as [`Pointer<AnyPointerEvent>`] events.
+pub fn forward_any_pointer_events<E: Debug + Clone + Reflect + Into<AnyPointerEvent>>(
+    mut pointer_events: EventReader<Pointer<E>>,
+    mut any_pointer_events: PointerEventWriter<AnyPointerEvent>,
+) {
+    for Pointer {
+        target,
+        pointer_id,
+        pointer_location,
+        event,
+    } in pointer_events.read().cloned()
+    {
+        any_pointer_events.send(Pointer::new(
+            pointer_id,
+            pointer_location,
+            target,
+            event.into(),
+        ));
+    }
 }
 
 /// Generates pointer events from input and focus data
 pub fn pointer_events(
     // Input
-    mut input_presses: EventReader<InputPress>,
+    mut input_presses: DebouncedPresses,
     mut input_moves: EventReader<pointer::InputMove>,
+    (move_throttle, disabled, mapper): (
+        Res<MoveThrottle>,
+        DisabledPointers,
+        Res<PointerLocationMapper>,
+    ),
+    (move_deltas, windows, mut last_scales): (
+        Res<MoveDeltaSettings>,
+        Query<&Window>,
+        Local<HashMap<PointerId, (Entity, f32)>>,
+    ),
     pointer_map: Res<PointerMap>,
     pointers: Query<&PointerLocation>,
+    (presses, pressures, modifiers): (
+        Query<&PointerPress>,
+        Query<&PointerPressure>,
+        CurrentModifiers,
+    ),
     hover_map: Res<HoverMap>,
     previous_hover_map: Res<PreviousHoverMap>,
+    event_hover_maps: Res<EventHoverMaps>,
+    previous_event_hover_maps: Res<PreviousEventHoverMaps>,
     // Output
-    mut pointer_move: EventWriter<Pointer<Move>>,
-    mut pointer_over: EventWriter<Pointer<Over>>,
-    mut pointer_out: EventWriter<Pointer<Out>>,
-    mut pointer_up: EventWriter<Pointer<Up>>,
-    mut pointer_down: EventWriter<Pointer<Down>>,
+    mut pointer_move: PointerEventWriter<Move>,
+    mut pointer_over: PointerEventWriter<Over>,
+    mut pointer_out: PointerEventWriter<Out>,
+    mut pointer_up: PointerEventWriter<Up>,
+    mut pointer_down: PointerEventWriter<Down>,
 ) {
     let pointer_location = |pointer_id: PointerId| {
         pointer_map
@@ -217,12 +1304,24 @@ pub fn pointer_events(
             .and_then(|pointer| pointer.location.clone())
     };
 
-    for InputMove {
-        pointer_id,
-        location,
-        delta,
-    } in input_moves.read().cloned()
-    {
+    last_scales.retain(|pointer_id, _| pointer_map.get_entity(*pointer_id).is_some());
+    for input in move_throttle.throttle(
+        input_moves
+            .read()
+            .filter(|input| !disabled.contains(input.pointer_id))
+            .map(|input| mapper.map_move(input)),
+    ) {
+        let (logical_delta, physical_delta) = scaled_deltas(&input, &windows, &mut last_scales);
+        let InputMove {
+            pointer_id,
+            location,
+            delta,
+            pressure,
+        } = input;
+        let delta = match move_deltas.normalize {
+            true => logical_delta,
+            false => delta,
+        };
         for (hovered_entity, hit) in hover_map
             .get(&pointer_id)
             .iter()
@@ -232,20 +1331,36 @@ pub fn pointer_events(
                 pointer_id,
                 location.clone(),
                 hovered_entity,
-                Move { hit, delta },
+                Move {
+                    hit,
+                    delta,
+                    logical_delta,
+                    physical_delta,
+                    pressure,
+                },
             ));
         }
     }
 
-    for press_event in input_presses.read() {
+    for press_event in input_presses
+        .read()
+        .filter(|press| !disabled.contains(press.pointer_id))
+    {
         let button = press_event.button;
+        let pointer_entity = pointer_map.get_entity(press_event.pointer_id);
+        let held = pointer_entity
+            .and_then(|entity| presses.get(entity).ok())
+            .map_or(Duration::ZERO, |press| press.held_duration(button));
+        let pressure = pointer_entity
+            .and_then(|entity| pressures.get(entity).ok())
+            .and_then(|pressure| pressure.0);
+        let modifiers = modifiers.get();
         // We use the previous hover map because we want to consider pointers that just left the
         // entity. Without this, touch inputs would never send up events because they are lifted up
         // and leave the bounds of the entity at the same time.
-        for (hovered_entity, hit) in previous_hover_map
-            .get(&press_event.pointer_id)
-            .iter()
-            .flat_map(|h| h.iter().map(|(entity, data)| (*entity, data.clone())))
+        for (hovered_entity, hit) in previous_event_hover_maps
+            .pressable(press_event.pointer_id)
+            .map(|(entity, data)| (entity, data.clone()))
         {
             if let PressDirection::Up = press_event.direction {
                 let Some(location) = pointer_location(press_event.pointer_id) else {
@@ -259,14 +1374,18 @@ pub fn pointer_events(
                     press_event.pointer_id,
                     location,
                     hovered_entity,
-                    Up { button, hit },
+                    Up {
+                        button,
+                        hit,
+                        held,
+                        modifiers,
+                    },
                 ));
             }
         }
-        for (hovered_entity, hit) in hover_map
-            .get(&press_event.pointer_id)
-            .iter()
-            .flat_map(|h| h.iter().map(|(entity, data)| (*entity, data.clone())))
+        for (hovered_entity, hit) in event_hover_maps
+            .pressable(press_event.pointer_id)
+            .map(|(entity, data)| (entity, data.clone()))
         {
             if let PressDirection::Down = press_event.direction {
                 let Some(location) = pointer_location(press_event.pointer_id) else {
@@ -280,24 +1399,20 @@ pub fn pointer_events(
                     press_event.pointer_id,
                     location,
                     hovered_entity,
-                    Down { button, hit },
+                    Down {
+                        button,
+                        hit,
+                        pressure,
+                        modifiers,
+                    },
                 ));
             }
         }
     }
 
-    // If the entity is hovered...
-    for (pointer_id, hovered_entity, hit) in hover_map
-        .iter()
-        .flat_map(|(id, hashmap)| hashmap.iter().map(|data| (*id, *data.0, data.1.clone())))
-    {
-        // ...but was not hovered last frame...
-        if !previous_hover_map
-            .get(&pointer_id)
-            .iter()
-            .any(|e| e.contains_key(&hovered_entity))
-        {
-            let Some(location) = pointer_location(pointer_id) else {
+    for pointer_id in hover_map.keys() {
+        for (hovered_entity, hit) in hover_entered(&hover_map, &previous_hover_map, *pointer_id) {
+            let Some(location) = pointer_location(*pointer_id) else {
                 debug!(
                     "Unable to get location for pointer {:?} during pointer over",
                     pointer_id
@@ -305,26 +1420,17 @@ pub fn pointer_events(
                 continue;
             };
             pointer_over.send(Pointer::new(
-                pointer_id,
+                *pointer_id,
                 location,
                 hovered_entity,
-                Over { hit },
+                Over { hit: hit.clone() },
             ));
         }
     }
 
-    // If the entity was hovered by a specific pointer last frame...
-    for (pointer_id, hovered_entity, hit) in previous_hover_map
-        .iter()
-        .flat_map(|(id, hashmap)| hashmap.iter().map(|data| (*id, *data.0, data.1.clone())))
-    {
-        // ...but is now not being hovered by that same pointer...
-        if !hover_map
-            .get(&pointer_id)
-            .iter()
-            .any(|e| e.contains_key(&hovered_entity))
-        {
-            let Some(location) = pointer_location(pointer_id) else {
+    for pointer_id in previous_hover_map.keys() {
+        for (hovered_entity, hit) in hover_left(&hover_map, &previous_hover_map, *pointer_id) {
+            let Some(location) = pointer_location(*pointer_id) else {
                 debug!(
                     "Unable to get location for pointer {:?} during pointer out",
                     pointer_id
@@ -332,45 +1438,1256 @@ pub fn pointer_events(
                 continue;
             };
             pointer_out.send(Pointer::new(
+                *pointer_id,
+                location,
+                hovered_entity,
+                Out { hit: hit.clone() },
+            ));
+        }
+    }
+}
+
+/// Delays the [`HoverStart`] event for this entity until a pointer has hovered it continuously for
+/// this long. Entities without this component never receive [`HoverStart`] events. Moving the
+/// pointer off the entity before the delay has passed cancels the event.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+pub struct HoverDelay(pub Duration);
+
+/// Sends [`HoverStart`] events for entities with a [`HoverDelay`] that have been hovered for long
+/// enough.
+pub fn send_hover_start_events(
+    // Input
+    hover_map: Res<HoverMap>,
+    delays: Query<&HoverDelay>,
+    pointer_map: Res<PointerMap>,
+    pointers: Query<&PointerLocation>,
+    time: Res<Time<Real>>,
+    // Local
+    // When each pointer started hovering each delayed entity, or `None` once the event was sent.
+    mut hover_starts: Local<HashMap<(PointerId, Entity), Option<Duration>>>,
+    // Output
+    mut pointer_hover_start: PointerEventWriter<HoverStart>,
+) {
+    let now = time.elapsed();
+    hover_starts.retain(|(pointer_id, entity), _| {
+        hover_map
+            .get(pointer_id)
+            .is_some_and(|hovered| hovered.contains_key(entity))
+    });
+
+    for (pointer_id, hovered) in hover_map.iter() {
+        for (entity, hit) in hovered {
+            let Ok(HoverDelay(delay)) = delays.get(*entity) else {
+                continue;
+            };
+            let start = hover_starts
+                .entry((*pointer_id, *entity))
+                .or_insert(Some(now));
+            let Some(start_time) = *start else {
+                continue; // already sent for this hover
+            };
+            if now.saturating_sub(start_time) < *delay {
+                continue;
+            }
+            let Some(location) = pointer_map
+                .get_entity(*pointer_id)
+                .and_then(|pointer| pointers.get(pointer).ok())
+                .and_then(|pointer| pointer.location.clone())
+            else {
+                debug!(
+                    "Unable to get location for pointer {:?} during hover start",
+                    pointer_id
+                );
+                continue;
+            };
+            *start = None;
+            pointer_hover_start.send(Pointer::new(
+                *pointer_id,
+                location,
+                *entity,
+                HoverStart { hit: hit.clone() },
+            ));
+        }
+    }
+}
+
+/// Controls when [`Idle`] events are sent.
+#[derive(Debug, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct IdleSettings {
+    /// How long a pointer can go without an [`InputMove`] before it becomes [`Idle`]. Defaults to
+    /// 3 seconds.
+    pub timeout: Duration,
+}
+
+impl Default for IdleSettings {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(3),
+        }
+    }
+}
+
+/// Sends an [`Idle`] event when a pointer has not moved for the [`IdleSettings::timeout`], and a
+/// [`Wake`] event on its next move. A pointer's timeout starts the first time it has a location,
+/// and restarts with each move.
+pub fn send_idle_events(
+    // Input
+    settings: Res<IdleSettings>,
+    time: Res<Time<Real>>,
+    mut input_moves: EventReader<InputMove>,
+    pointer_map: Res<PointerMap>,
+    pointers: Query<(Entity, &PointerId, &PointerLocation)>,
+    (disabled, mapper): (DisabledPointers, Res<PointerLocationMapper>),
+    // Local
+    // When each pointer last moved, and whether it is idle.
+    mut last_moves: Local<HashMap<PointerId, (Duration, bool)>>,
+    // Output
+    mut pointer_idle: PointerEventWriter<Idle>,
+    mut pointer_wake: PointerEventWriter<Wake>,
+) {
+    let now = time.elapsed();
+    for InputMove {
+        pointer_id,
+        location,
+        ..
+    } in input_moves
+        .read()
+        .filter(|input| !disabled.contains(input.pointer_id))
+        .map(|input| mapper.map_move(input))
+    {
+        let Some(pointer) = pointer_map.get_entity(pointer_id) else {
+            continue;
+        };
+        if let Some((last_move, true)) = last_moves.insert(pointer_id, (now, false)) {
+            pointer_wake.send(Pointer::new(
                 pointer_id,
                 location,
+                pointer,
+                Wake {
+                    idle_for: now.saturating_sub(last_move),
+                },
+            ));
+        }
+    }
+
+    last_moves.retain(|pointer_id, _| pointer_map.get_entity(*pointer_id).is_some());
+    for (pointer, pointer_id, pointer_location) in &pointers {
+        if disabled.contains(*pointer_id) {
+            continue;
+        }
+        let Some(location) = pointer_location.location.clone() else {
+            continue;
+        };
+        let (last_move, is_idle) = last_moves.entry(*pointer_id).or_insert((now, false));
+        if *is_idle || now.saturating_sub(*last_move) < settings.timeout {
+            continue;
+        }
+        *is_idle = true;
+        pointer_idle.send(Pointer::new(*pointer_id, location, pointer, Idle));
+    }
+}
+
+/// Sends [`WindowEnter`] and [`WindowLeave`] events when the [`PointerLocation`] of a pointer
+/// crosses the bounds of a window.
+///
+/// A pointer is in a window while its location is on that window, and within its logical size.
+/// Because the mouse pointer keeps its last location when the cursor leaves a window, it is also
+/// only in a window while the window has a [`Window::cursor_position`].
+pub fn send_window_events(
+    // Input
+    pointers: Query<(Entity, &PointerId, &PointerLocation)>,
+    windows: Query<&Window>,
+    // Local
+    // The window each pointer is in, and the pointer's last location in that window.
+    mut pointer_windows: Local<HashMap<PointerId, (Entity, Location)>>,
+    // Output
+    mut pointer_window_enter: PointerEventWriter<WindowEnter>,
+    mut pointer_window_leave: PointerEventWriter<WindowLeave>,
+) {
+    for (pointer, pointer_id, pointer_location) in &pointers {
+        let current = pointer_location.location.as_ref().and_then(|location| {
+            let window_entity = location.window()?;
+            let window = windows.get(window_entity).ok()?;
+            let bounds = Rect::new(0.0, 0.0, window.width(), window.height());
+            let has_cursor = !pointer_id.is_mouse() || window.cursor_position().is_some();
+            (has_cursor && bounds.contains(location.position))
+                .then(|| (window_entity, location.clone()))
+        });
+        let previous = match &current {
+            Some(current) => pointer_windows.insert(*pointer_id, current.clone()),
+            None => pointer_windows.remove(pointer_id),
+        };
+        let previous_window = previous.as_ref().map(|(window, _)| *window);
+        let current_window = current.as_ref().map(|(window, _)| *window);
+        if previous_window == current_window {
+            continue;
+        }
+        if let Some((window, last_location)) = previous {
+            let location = pointer_location.location.clone().unwrap_or(last_location);
+            pointer_window_leave.send(Pointer::new(
+                *pointer_id,
+                location,
+                pointer,
+                WindowLeave { window },
+            ));
+        }
+        if let Some((window, location)) = current {
+            pointer_window_enter.send(Pointer::new(
+                *pointer_id,
+                location,
+                pointer,
+                WindowEnter { window },
+            ));
+        }
+    }
+    pointer_windows.retain(|pointer_id, _| pointers.iter().any(|(_, id, _)| id == pointer_id));
+}
+
+/// Maps pointers to the entities they are dragging, keyed by the button driving each drag, so a
+/// pointer dragging with several buttons at once has an entry for each of them.
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct DragMap(pub HashMap<(PointerId, PointerButton), HashMap<Entity, DragEntry>>);
+
+impl DragMap {
+    /// Returns the entities that the pointer is dragging with `button`.
+    pub fn drags(
+        &self,
+        pointer_id: PointerId,
+        button: PointerButton,
+    ) -> impl Iterator<Item = (&Entity, &DragEntry)> {
+        self.get(&(pointer_id, button))
+            .into_iter()
+            .flat_map(|drags| drags.iter())
+    }
+
+    /// Returns the buttons that the pointer is currently dragging with.
+    pub fn dragging_buttons(
+        &self,
+        pointer_id: PointerId,
+    ) -> impl Iterator<Item = PointerButton> + '_ {
+        PointerButton::iter().filter(move |button| self.drags(pointer_id, *button).next().is_some())
+    }
+
+    /// Returns `true` if the pointer is dragging any entity, with any button.
+    pub fn is_dragging(&self, pointer_id: PointerId) -> bool {
+        self.dragging_buttons(pointer_id).next().is_some()
+    }
+}
+
+/// Maps pointers to the entities they are dragging other entities over, keyed by the button driving
+/// each drag, along with the latest hit of the pointer on each of them. Updated by
+/// [`send_drag_over_events`]: entities are added on [`DragEnter`], and removed on [`DragLeave`]
+/// and [`Drop`].
+///
+/// This can be queried to style valid drop targets differently from hovered entities, without
+/// tracking the drag events. The entities being dragged are in the [`DragMap`].
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct DragOverMap(pub HashMap<(PointerId, PointerButton), EntityHashMap<HitData>>);
+
+impl DragOverMap {
+    /// Returns the entities that the pointer is dragging over with `button`.
+    pub fn dragged_over(
+        &self,
+        pointer_id: PointerId,
+        button: PointerButton,
+    ) -> impl Iterator<Item = (&Entity, &HitData)> {
+        self.get(&(pointer_id, button))
+            .into_iter()
+            .flat_map(|dragged_over| dragged_over.iter())
+    }
+
+    /// Returns the pointers and buttons dragging over `entity`, and the hit of each pointer.
+    pub fn dragging_over(
+        &self,
+        entity: Entity,
+    ) -> impl Iterator<Item = (PointerId, PointerButton, &HitData)> {
+        self.iter()
+            .filter_map(move |((pointer_id, button), dragged_over)| {
+                Some((*pointer_id, *button, dragged_over.get(&entity)?))
+            })
+    }
+
+    /// Returns `true` if any pointer is dragging an entity over `entity`.
+    pub fn is_dragged_over(&self, entity: Entity) -> bool {
+        self.dragging_over(entity).next().is_some()
+    }
+}
+
+/// An entry in the [`DragMap`].
+#[derive(Debug, Clone)]
+pub struct DragEntry {
+    /// The position of the pointer at drag start.
+    pub start_pos: Vec2,
+    /// The latest position of the pointer during this drag, used to compute deltas.
+    pub latest_pos: Vec2,
+    /// The [`distance`](Drag::distance) of the latest [`Drag`] event.
+    pub distance: Vec2,
+    /// The distance of this drag scaled by the [`DragModifier`] of the dragged entity, before it
+    /// is snapped, so movement smaller than the snap is accumulated instead of lost.
+    pub scaled_distance: Vec2,
+    /// The [`DragPayload`] of the dragged entity at drag start.
+    pub payload: Option<DragPayload>,
+    /// The world space state of this drag, if the dragged entity had a [`DragPlane`] at drag start.
+    pub world: Option<WorldDrag>,
+}
+
+/// The world space state of a drag on a [`DragPlane`], in a [`DragEntry`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorldDrag {
+    /// The camera the drag started from, whose ray from the pointer is projected onto the plane.
+    pub camera: Entity,
+    /// The plane the drag is projected onto.
+    pub plane: Plane3d,
+    /// Where the drag started, which is on the plane.
+    pub start: Vec3,
+    /// The latest position of the pointer on the plane, used to compute deltas.
+    pub latest: Vec3,
+}
+
+impl WorldDrag {
+    /// Projects the pointer's ray from the [`RayMap`] onto the plane, returning the total distance of
+    /// the drag, and the change since the last projection.
+    fn update(&mut self, pointer_id: PointerId, ray_map: &RayMap) -> Option<(Vec3, Vec3)> {
+        let ray = ray_map.get(RayId::new(self.camera, pointer_id))?;
+        let position = ray.get_point(ray.intersect_plane(self.start, self.plane)?);
+        let delta = position - self.latest;
+        self.latest = position;
+        Some((position - self.start, delta))
+    }
+}
+
+/// Data carried by drags of this entity, such as the id and type of an inventory item, which can
+/// be any `Send + Sync` type.
+///
+/// The payload is snapshotted when a drag of the entity starts, so replacing or removing the
+/// component during the drag does not affect it. It is delivered to the drop target in the
+/// [`Drop`] event, and to the dragged entity in the [`DragEnd`] event, after which the drag no
+/// longer holds it.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_eventlistener::prelude::*;
+/// # use bevy_picking_core::events::*;
+/// struct Item {
+///     id: u32,
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(DragPayload::new(Item { id: 7 }));
+///     commands.spawn(On::<Pointer<Drop>>::run(|event: Listener<Pointer<Drop>>| {
+///         if let Some(item) = event.payload.as_ref().and_then(DragPayload::get::<Item>) {
+///             println!("Dropped item {}", item.id);
+///         }
+///     }));
+/// }
+/// ```
+#[derive(Component, Clone)]
+pub struct DragPayload(Arc<dyn Any + Send + Sync>);
+
+impl DragPayload {
+    /// Creates a payload holding `payload`.
+    pub fn new(payload: impl Any + Send + Sync) -> Self {
+        Self(Arc::new(payload))
+    }
+
+    /// Returns the payload, if it is a `T`.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+
+    /// Returns `true` if the payload is a `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+}
+
+impl Debug for DragPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DragPayload(..)")
+    }
+}
+
+/// Payloads are equal if they are the same snapshot.
+impl PartialEq for DragPayload {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Configures when pointers start dragging.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct DragSettings {
+    /// How far, in logical pixels, a pointer must move from where it was pressed before a
+    /// [`DragStart`] is sent. Smaller movements are ignored, so an unsteady press still resolves to a
+    /// [`Click`] on release. Once the threshold is crossed, the drag starts from where the pointer
+    /// was pressed, so the first [`Drag`] includes the movement below the threshold. Defaults to
+    /// `0.0`, which starts a drag on any movement. A few pixels is a good value for touchscreens.
+    pub threshold: f32,
+}
+
+/// Constrains the `distance` and `delta` of the [`Drag`] events sent to this entity, for example to
+/// keep a slider on a single axis. The unconstrained values are still available in the
+/// `raw_distance` and `raw_delta` fields.
+#[derive(Debug, Clone, Copy, Component)]
+pub enum DragConstraint {
+    /// Only keep horizontal movement.
+    Horizontal,
+    /// Only keep vertical movement.
+    Vertical,
+    /// Apply a custom function. It is applied to the distance and the delta separately, so it
+    /// should be linear, like a projection onto an axis.
+    Custom(fn(Vec2) -> Vec2),
+}
+
+impl DragConstraint {
+    /// Applies this constraint to a drag vector.
+    pub fn apply(&self, vector: Vec2) -> Vec2 {
+        match self {
+            DragConstraint::Horizontal => Vec2::new(vector.x, 0.0),
+            DragConstraint::Vertical => Vec2::new(0.0, vector.y),
+            DragConstraint::Custom(constrain) => constrain(vector),
+        }
+    }
+}
+
+/// Scales and snaps the `distance` and `delta` of the [`Drag`] events sent to this entity, for
+/// precise editing, or for moving entities on a grid without snapping in every drag listener. The
+/// movement is first constrained by the entity's [`DragConstraint`], if any. The unmodified values
+/// are still available in the `raw_distance` and `raw_delta` fields.
+///
+/// The movement of the pointer is scaled by the [`sensitivity`](Self::sensitivity) as it happens,
+/// so changing the sensitivity during a drag, for example while a key is held for finer control,
+/// only affects later movement. The accumulated distance is then rounded to the nearest multiple of
+/// the [`snap`](Self::snap), and the `delta` is the change in the snapped distance, so it is zero
+/// until the pointer has moved far enough to reach the next multiple.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::Vec2;
+/// # use bevy_picking_core::events::*;
+/// fn setup(mut commands: Commands) {
+///     // Moves half as fast as the pointer, in steps of 10 logical pixels.
+///     commands.spawn(DragModifier::new(0.5).with_snap(Vec2::splat(10.0)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Component, Reflect, PartialEq)]
+#[reflect(Component, Default)]
+pub struct DragModifier {
+    /// How much the movement of the pointer is scaled by. Values below `1.0` slow down dragging,
+    /// for finer control. Defaults to `1.0`.
+    pub sensitivity: f32,
+    /// Rounds the distance of the drag to the nearest multiple of this size on each axis. Axes
+    /// with a size of zero are not snapped. Defaults to `None`, which does not snap.
+    pub snap: Option<Vec2>,
+}
+
+impl Default for DragModifier {
+    fn default() -> Self {
+        Self {
+            sensitivity: 1.0,
+            snap: None,
+        }
+    }
+}
+
+impl DragModifier {
+    /// Scales drags by `sensitivity`, without snapping.
+    pub fn new(sensitivity: f32) -> Self {
+        Self {
+            sensitivity,
+            snap: None,
+        }
+    }
+
+    /// Snaps drags to multiples of `snap`.
+    pub fn with_snap(mut self, snap: Vec2) -> Self {
+        self.snap = Some(snap);
+        self
+    }
+
+    /// Rounds a drag distance to the nearest multiple of the [`snap`](Self::snap).
+    pub fn snap(&self, distance: Vec2) -> Vec2 {
+        let Some(snap) = self.snap else {
+            return distance;
+        };
+        let snap_axis = |distance: f32, snap: f32| {
+            if snap > 0.0 {
+                (distance / snap).round() * snap
+            } else {
+                distance
+            }
+        };
+        Vec2::new(snap_axis(distance.x, snap.x), snap_axis(distance.y, snap.y))
+    }
+}
+
+/// Projects the drags of this entity onto a plane in world space, so the [`Drag`] events sent to it
+/// also have a [`world_distance`](Drag::world_distance) and [`world_delta`](Drag::world_delta),
+/// for example to move a 3D object along the ground without intersecting rays with planes by hand.
+///
+/// The plane goes through the position of the [`DragStart`] hit, or through the entity's
+/// [`GlobalTransform`] translation if the backend
+/// doesn't report hit positions. The pointer's ray is the [`RayMap`] ray of the camera the drag
+/// started from, so only world space backends that report the hit camera are supported. The plane
+/// is fixed when the drag starts, so changing this component only affects later drags. World space
+/// values are not affected by the entity's [`DragConstraint`].
+#[derive(Debug, Clone, Copy, Component, Reflect, PartialEq)]
+#[reflect(Component, Default)]
+pub struct DragPlane {
+    /// The normal of the plane, in world space. Defaults to [`Vec3::Y`], for dragging along the
+    /// ground.
+    pub normal: Vec3,
+}
+
+impl Default for DragPlane {
+    fn default() -> Self {
+        Self { normal: Vec3::Y }
+    }
+}
+
+impl DragPlane {
+    /// A plane with this normal, in world space.
+    pub fn new(normal: Vec3) -> Self {
+        Self { normal }
+    }
+}
+
+/// Configures which pointer gestures exclude each other. While a pointer is performing one gesture,
+/// the gestures it excludes are suppressed for that pointer until it ends.
+#[derive(Debug, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct GestureExclusions {
+    /// While a pointer is dragging, it does not send [`Scroll`] events. Enabled by default.
+    pub drag_blocks_scroll: bool,
+    /// While a pointer is scrolling, it cannot start a drag. A drag that was already started is not
+    /// affected. Disabled by default.
+    pub scroll_blocks_drag: bool,
+    /// How long after its last [`Scroll`] event a pointer is still considered to be scrolling.
+    pub scroll_gesture_timeout: Duration,
+}
+
+impl Default for GestureExclusions {
+    fn default() -> Self {
+        Self {
+            drag_blocks_scroll: true,
+            scroll_blocks_drag: false,
+            scroll_gesture_timeout: Duration::from_millis(150),
+        }
+    }
+}
+
+/// Tracks when each pointer last sent a [`Scroll`] event, used to apply [`GestureExclusions`].
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct ScrollMap(pub HashMap<PointerId, Duration>);
+
+impl ScrollMap {
+    /// Returns `true` if the pointer has scrolled within the
+    /// [`GestureExclusions::scroll_gesture_timeout`], measured in real time since startup.
+    pub fn is_scrolling(&self, pointer_id: PointerId, now: Duration, timeout: Duration) -> bool {
+        self.get(&pointer_id)
+            .is_some_and(|last| now.saturating_sub(*last) <= timeout)
+    }
+}
+
+/// Global settings for scrolling.
+#[derive(Debug, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct ScrollSettings {
+    /// How many logical pixels a scroll of one line is worth, used to normalize scroll deltas from
+    /// different input devices.
+    pub lines_to_pixels: f32,
+    /// Inverts horizontal scrolling, for the scroll direction the user prefers. This applies to
+    /// every [`Scroll`] event, including the ones sent by [`ScrollMomentum`]. Disabled by default.
+    pub invert_x: bool,
+    /// Inverts vertical scrolling, such as to switch between "natural" and traditional scrolling.
+    /// This applies to every [`Scroll`] event, including the ones sent by [`ScrollMomentum`].
+    /// Disabled by default.
+    pub invert_y: bool,
+}
+
+impl Default for ScrollSettings {
+    fn default() -> Self {
+        Self {
+            lines_to_pixels: 20.0,
+            invert_x: false,
+            invert_y: false,
+        }
+    }
+}
+
+impl ScrollSettings {
+    /// Inverts a scroll `delta` on the axes set to be inverted.
+    pub fn invert(&self, delta: Vec2) -> Vec2 {
+        let sign = |invert: bool| if invert { -1.0 } else { 1.0 };
+        delta * Vec2::new(sign(self.invert_x), sign(self.invert_y))
+    }
+
+    /// Converts a scroll `delta` in `unit`s to logical pixels.
+    pub fn to_pixels(&self, delta: Vec2, unit: ScrollUnit) -> Vec2 {
+        match unit {
+            ScrollUnit::Line => delta * self.lines_to_pixels,
+            ScrollUnit::Pixel => delta,
+        }
+    }
+}
+
+/// Configures scroll momentum, which keeps scrolling after scroll input stops, slowing down over
+/// time. This makes touchpad and touch scrolling feel less abrupt. Disabled by default.
+///
+/// When a pointer stops sending [`InputScroll`]s in [`ScrollUnit::Pixel`]s, it keeps sending
+/// [`Scroll`] events every frame, with [`Scroll::momentum`] set, starting at the velocity of its
+/// last frame of scroll input. Scrolling by lines, like with a mouse wheel, has no momentum. The
+/// momentum of a pointer is cancelled by new scroll input, which replaces it, and by pressing any
+/// of its buttons. The current velocity of each pointer is stored in the [`ScrollMomentumMap`].
+#[derive(Debug, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct ScrollMomentum {
+    /// Whether scroll momentum is enabled.
+    pub enabled: bool,
+    /// How quickly momentum slows down. The velocity is multiplied by `exp(-friction)` every
+    /// second.
+    pub friction: f32,
+    /// The speed, in logical pixels per second, below which momentum stops.
+    pub cutoff: f32,
+}
+
+impl Default for ScrollMomentum {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            friction: 5.0,
+            cutoff: 10.0,
+        }
+    }
+}
+
+/// The current [`ScrollMomentum`] velocity of each pointer, in logical pixels per second. Removing a
+/// pointer's entry stops its momentum.
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct ScrollMomentumMap(pub HashMap<PointerId, Vec2>);
+
+/// Sends [`Scroll`] events to the entities hovered by scrolling pointers, unless the pointer is
+/// dragging and [`GestureExclusions::drag_blocks_scroll`] is enabled. Also sends the scrolls of
+/// [`ScrollMomentum`]. Scroll deltas are inverted as configured by the [`ScrollSettings`].
+pub fn send_scroll_events(
+    // Input
+    mut input_scrolls: EventReader<InputScroll>,
+    mut input_presses: DebouncedPresses,
+    pointer_map: Res<PointerMap>,
+    pointers: Query<&PointerLocation>,
+    event_hover_maps: Res<EventHoverMaps>,
+    drag_map: Res<DragMap>,
+    exclusions: Res<GestureExclusions>,
+    (settings, momentum): (Res<ScrollSettings>, Res<ScrollMomentum>),
+    time: Res<Time<Real>>,
+    // Output
+    mut scroll_map: ResMut<ScrollMap>,
+    mut momentum_map: ResMut<ScrollMomentumMap>,
+    mut pointer_scroll: PointerEventWriter<Scroll>,
+) {
+    let pointer_location = |pointer_id: PointerId| {
+        pointer_map
+            .get_entity(pointer_id)
+            .and_then(|entity| pointers.get(entity).ok())
+            .and_then(|pointer| pointer.location.clone())
+    };
+    let is_dragging = |pointer_id: PointerId| drag_map.is_dragging(pointer_id);
+
+    let mut send_scroll = |pointer_id: PointerId, delta: Vec2, unit: ScrollUnit, momentum: bool| {
+        let Some(location) = pointer_location(pointer_id) else {
+            debug!(
+                "Unable to get location for pointer {:?} during pointer scroll",
+                pointer_id
+            );
+            return;
+        };
+        scroll_map.insert(pointer_id, time.elapsed());
+        for (hovered_entity, hit) in event_hover_maps
+            .scroll
+            .get(&pointer_id)
+            .iter()
+            .flat_map(|h| h.iter().map(|(entity, data)| (*entity, data.to_owned())))
+        {
+            pointer_scroll.send(Pointer::new(
+                pointer_id,
+                location.clone(),
                 hovered_entity,
-                Out { hit },
+                Scroll {
+                    delta,
+                    unit,
+                    hit,
+                    momentum,
+                },
+            ));
+        }
+    };
+
+    for press in input_presses.read() {
+        if press.direction == pointer::PressDirection::Down {
+            momentum_map.remove(&press.pointer_id);
+        }
+    }
+
+    // The total pixel delta of each pointer that scrolled this frame, or `None` if it scrolled by
+    // lines, which has no momentum.
+    let mut scrolled = HashMap::<PointerId, Option<Vec2>>::new();
+    for InputScroll {
+        pointer_id,
+        delta,
+        unit,
+    } in input_scrolls.read().copied()
+    {
+        if exclusions.drag_blocks_scroll && is_dragging(pointer_id) {
+            continue;
+        }
+        // Momentum continues the inverted deltas, so it is inverted too.
+        let delta = settings.invert(delta);
+        let total = scrolled.entry(pointer_id).or_insert(Some(Vec2::ZERO));
+        *total = total
+            .filter(|_| unit == ScrollUnit::Pixel)
+            .map(|total| total + delta);
+        send_scroll(pointer_id, delta, unit, false);
+    }
+
+    if !momentum.enabled {
+        momentum_map.clear();
+        return;
+    }
+    let delta_seconds = time.delta_seconds();
+    if delta_seconds <= 0.0 {
+        return;
+    }
+    for (pointer_id, delta) in scrolled.iter() {
+        match delta {
+            Some(delta) => momentum_map.insert(*pointer_id, *delta / delta_seconds),
+            None => momentum_map.remove(pointer_id),
+        };
+    }
+    momentum_map.retain(|pointer_id, velocity| {
+        if scrolled.contains_key(pointer_id) {
+            return true; // this pointer is still receiving scroll input
+        }
+        *velocity *= (-momentum.friction * delta_seconds).exp();
+        if velocity.length() < momentum.cutoff {
+            return false;
+        }
+        send_scroll(
+            *pointer_id,
+            *velocity * delta_seconds,
+            ScrollUnit::Pixel,
+            true,
+        );
+        true
+    });
+}
+
+/// Global settings for detecting and limiting clicks.
+///
+/// ### Clicks after drags
+///
+/// A press that starts a drag does not send a [`Click`] when it is released, even if the pointer
+/// is released over the entity it was pressed on, such as after dragging away and back. Enable
+/// [`click_after_drag`](Self::click_after_drag) to send these clicks.
+#[derive(Debug, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct ClickSettings {
+    /// The maximum time between two clicks on the same entity for them to be considered a
+    /// [`DoubleClick`], or to continue the [`Click::click_count`]. Setting this to
+    /// [`Duration::ZERO`] disables multi-click detection.
+    pub multi_click_window: Duration,
+    /// The maximum distance, in logical pixels, between the positions of two clicks for them to be
+    /// counted as a multi-click.
+    pub multi_click_tolerance: f32,
+    /// The default minimum time between two clicks on the same entity with the same button. Can be
+    /// overridden per entity with [`ClickCooldown`]. Defaults to [`Duration::ZERO`], which disables
+    /// the cooldown.
+    pub click_cooldown: Duration,
+    /// Whether a press that started a drag sends a [`Click`] when it is released over an entity it
+    /// was pressed on. Disabled by default. See [the struct docs](Self#clicks-after-drags).
+    pub click_after_drag: bool,
+}
+
+impl Default for ClickSettings {
+    fn default() -> Self {
+        Self {
+            multi_click_window: Duration::from_millis(500),
+            multi_click_tolerance: 4.0,
+            click_cooldown: Duration::ZERO,
+            click_after_drag: false,
+        }
+    }
+}
+
+/// Overrides [`ClickSettings::click_cooldown`] for an entity.
+///
+/// After a click on this entity, further clicks on it with the same button are swallowed until the
+/// cooldown has passed, regardless of which pointer clicked. Swallowed clicks do not send any
+/// event, and are ignored by double click detection, so a cooldown longer than the
+/// [`ClickSettings::multi_click_window`] prevents [`DoubleClick`]s on the entity. This is useful
+/// for rate limiting actions that should not be triggered twice by accident.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+pub struct ClickCooldown(pub Duration);
+
+/// Controls when [`Click`] events are sent for an entity. Entities without this component use
+/// [`ClickEmission::Immediate`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default)]
+pub enum ClickEmission {
+    /// Every click sends a [`Click`] as soon as the pointer is released. If the click completes a
+    /// double click, a [`DoubleClick`] is sent as well.
+    #[default]
+    Immediate,
+    /// Holds each [`Click`] until the [`ClickSettings::multi_click_window`] has passed, so that
+    /// either a [`Click`] or a [`DoubleClick`] is sent, but never both. This is useful when single
+    /// and double clicks trigger conflicting actions. Clicks after the second in a multi-click,
+    /// with a `click_count` of 3 or more, are sent immediately.
+    ///
+    /// This adds up to `multi_click_window` of latency to every single click on the entity, which
+    /// is why it is opt-in. If double click detection is disabled, this behaves like
+    /// [`ClickEmission::Immediate`].
+    DeferIfDoubleCapable,
+}
+
+/// The most recent click on an entity, used to detect double clicks.
+#[derive(Debug, Clone)]
+struct LastClick {
+    /// The real time since startup when the click happened.
+    time: Duration,
+    /// The position of the pointer when the click happened.
+    position: Vec2,
+    /// The click count of the click.
+    count: u16,
+    /// The click, if it is being held back because of [`ClickEmission::DeferIfDoubleCapable`].
+    deferred: Option<Pointer<Click>>,
+}
+
+/// Sends [`Click`] and [`DoubleClick`] events, respecting each entity's [`ClickEmission`] and
+/// [`ClickCooldown`].
+#[derive(SystemParam)]
+pub struct ClickEmitter<'w, 's> {
+    time: Res<'w, Time<Real>>,
+    settings: Res<'w, ClickSettings>,
+    entities: Query<
+        'w,
+        's,
+        (
+            Option<&'static ClickEmission>,
+            Option<&'static ClickCooldown>,
+        ),
+    >,
+    last_clicks: Local<'s, HashMap<(PointerId, PointerButton, Entity), LastClick>>,
+    /// The real time since startup of the latest accepted click on each entity and button, used
+    /// for [`ClickCooldown`].
+    cooldowns: Local<'s, HashMap<(Entity, PointerButton), Duration>>,
+    pointer_click: PointerEventWriter<'w, Click>,
+    pointer_double_click: PointerEventWriter<'w, DoubleClick>,
+}
+
+impl ClickEmitter<'_, '_> {
+    /// Returns the click cooldown of an entity.
+    fn cooldown(&self, entity: Entity) -> Duration {
+        match self.entities.get(entity) {
+            Ok((_, Some(ClickCooldown(cooldown)))) => *cooldown,
+            _ => self.settings.click_cooldown,
+        }
+    }
+
+    /// Sends any deferred clicks whose double click window has passed, and forgets clicks that can
+    /// no longer become double clicks or are no longer in cooldown.
+    fn flush(&mut self) {
+        let now = self.time.elapsed();
+        let mut cooldowns = std::mem::take(&mut *self.cooldowns);
+        cooldowns.retain(|(entity, _), time| now.saturating_sub(*time) < self.cooldown(*entity));
+        *self.cooldowns = cooldowns;
+
+        let window = self.settings.multi_click_window;
+        let pointer_click = &mut self.pointer_click;
+        self.last_clicks.retain(|_, last| {
+            if now.saturating_sub(last.time) <= window {
+                return true;
+            }
+            if let Some(click) = last.deferred.take() {
+                pointer_click.send(click);
+            }
+            false
+        });
+    }
+
+    /// Handles a click, sending or deferring the [`Click`], and sending a [`DoubleClick`] if this
+    /// click completes one. Clicks on entities in cooldown are swallowed.
+    fn click(&mut self, click: Pointer<Click>) {
+        let now = self.time.elapsed();
+        let cooldown_key = (click.target, click.button);
+        if let Some(last) = self.cooldowns.get(&cooldown_key) {
+            if now.saturating_sub(*last) < self.cooldown(click.target) {
+                return;
+            }
+        }
+        if self.cooldown(click.target) > Duration::ZERO {
+            self.cooldowns.insert(cooldown_key, now);
+        }
+
+        let window = self.settings.multi_click_window;
+        let deferred = window > Duration::ZERO
+            && matches!(
+                self.entities.get(click.target),
+                Ok((Some(ClickEmission::DeferIfDoubleCapable), _))
+            );
+        let key = (click.pointer_id, click.button, click.target);
+        let position = click.pointer_location.position;
+
+        let mut click = click;
+        click.event.click_count = match self.last_clicks.remove(&key) {
+            Some(last)
+                if now.saturating_sub(last.time) <= window
+                    && last.position.distance(position) <= self.settings.multi_click_tolerance =>
+            {
+                // A deferred first click is replaced by the double click
+                last.count.saturating_add(1)
+            }
+            Some(LastClick {
+                deferred: Some(previous),
+                ..
+            }) => {
+                self.pointer_click.send(previous);
+                1
+            }
+            _ => 1,
+        };
+        let count = click.event.click_count;
+
+        let deferred_click = if count == 2 {
+            let double_click = Pointer::new(
+                click.pointer_id,
+                click.pointer_location.clone(),
+                click.target,
+                DoubleClick {
+                    button: click.event.button,
+                    hit: click.event.hit.clone(),
+                },
+            );
+            if !deferred {
+                self.pointer_click.send(click);
+            }
+            self.pointer_double_click.send(double_click);
+            None
+        } else if deferred && count == 1 {
+            Some(click)
+        } else {
+            self.pointer_click.send(click);
+            None
+        };
+        if window > Duration::ZERO {
+            self.last_clicks.insert(
+                key,
+                LastClick {
+                    time: now,
+                    position,
+                    count,
+                    deferred: deferred_click,
+                },
+            );
+        }
+    }
+}
+
+/// Configures when [`LongPress`] events are sent.
+#[derive(Debug, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct LongPressSettings {
+    /// How long a button must be held on an entity to send a [`LongPress`].
+    pub threshold: Duration,
+    /// The maximum distance, in logical pixels, the pointer can move from where it was pressed
+    /// before the long press is cancelled.
+    pub tolerance: f32,
+}
+
+impl Default for LongPressSettings {
+    fn default() -> Self {
+        Self {
+            threshold: Duration::from_millis(500),
+            tolerance: 8.0,
+        }
+    }
+}
+
+/// A press that can still become a [`LongPress`].
+#[derive(Debug, Clone)]
+pub struct PendingLongPress {
+    /// The real time since startup when the button was pressed.
+    pub start_time: Duration,
+    /// The position of the pointer when the button was pressed.
+    pub start_position: Vec2,
+    /// Information about the picking intersection when the button was pressed.
+    pub hit: HitData,
+    /// Whether the [`LongPress`] has been sent, so it is only sent once per press.
+    pub sent: bool,
+}
+
+/// Uses pointer events, [`PointerPress`] state, and hover state to determine when long press
+/// events occur.
+pub fn send_long_press_events(
+    // Input
+    mut pointer_down: EventReader<Pointer<Down>>,
+    pointer_map: Res<PointerMap>,
+    pointers: Query<(&PointerLocation, &PointerPress)>,
+    event_hover_maps: Res<EventHoverMaps>,
+    settings: Res<LongPressSettings>,
+    time: Res<Time<Real>>,
+    // Local
+    mut pending: Local<HashMap<(PointerId, PointerButton, Entity), PendingLongPress>>,
+    // Output
+    mut pointer_long_press: PointerEventWriter<LongPress>,
+) {
+    let now = time.elapsed();
+    for down in pointer_down.read() {
+        pending.insert(
+            (down.pointer_id, down.button, down.target),
+            PendingLongPress {
+                start_time: now,
+                start_position: down.pointer_location.position,
+                hit: down.hit.clone(),
+                sent: false,
+            },
+        );
+    }
+
+    pending.retain(|(pointer_id, button, target), press| {
+        let Some((location, pointer_press)) = pointer_map
+            .get_entity(*pointer_id)
+            .and_then(|entity| pointers.get(entity).ok())
+        else {
+            return false;
+        };
+        let Some(location) = location.location() else {
+            return false;
+        };
+        let is_held = pointer_press.is_pressed(*button)
+            && location.position.distance(press.start_position) <= settings.tolerance
+            && event_hover_maps
+                .pressable(*pointer_id)
+                .any(|(entity, _)| entity == *target);
+        if !is_held {
+            return false;
+        }
+        let duration = now.saturating_sub(press.start_time);
+        if !press.sent && duration >= settings.threshold {
+            press.sent = true;
+            pointer_long_press.send(Pointer::new(
+                *pointer_id,
+                location.clone(),
+                *target,
+                LongPress {
+                    button: *button,
+                    duration,
+                    hit: press.hit.clone(),
+                },
             ));
         }
+        true
+    });
+}
+
+/// Two touch pointers paired into a [`Pinch`] and [`Rotate`] gesture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchGesture {
+    /// The paired touch pointers.
+    pub pointers: [PointerId; 2],
+    /// The distance between the touches at the last update.
+    pub distance: f32,
+    /// The angle of the line from the first touch to the second at the last update.
+    pub angle: f32,
+}
+
+/// The order in which the currently pressed touch pointers were pressed, used to pair the touches
+/// that have been active longest in [`send_touch_gesture_events`].
+#[derive(Debug, Default)]
+pub struct TouchPressOrder {
+    /// The press number of each pressed touch, counting up from the first press.
+    pressed: HashMap<PointerId, u64>,
+    presses: u64,
+}
+
+impl TouchPressOrder {
+    /// Forgets the touches that are no longer pressed, and numbers the newly pressed ones, in the
+    /// order they are given in.
+    fn update(&mut self, pressed: impl Iterator<Item = PointerId> + Clone) {
+        self.pressed
+            .retain(|id, _| pressed.clone().any(|pressed| pressed == *id));
+        for id in pressed {
+            self.pressed.entry(id).or_insert_with(|| {
+                self.presses += 1;
+                self.presses
+            });
+        }
     }
 }
 
-/// Maps pointers to the entities they are dragging.
-#[derive(Debug, Deref, DerefMut, Default, Resource)]
-pub struct DragMap(pub HashMap<(PointerId, PointerButton), HashMap<Entity, DragEntry>>);
+/// Pairs two pressed touch pointers and sends [`Pinch`] and [`Rotate`] events to the nearest entity
+/// hovered by both.
+///
+/// The two touches that have been active longest are paired. A third touch is ignored for as long
+/// as the pair lasts, and when either touch of the pair lifts, the remaining touches are paired
+/// again starting from their current positions, so the gesture continues without a jump.
+pub fn send_touch_gesture_events(
+    // Input
+    pointers: Query<(&PointerId, &PointerLocation, &PointerPress)>,
+    hover_map: Res<HoverMap>,
+    // Local
+    mut gesture: Local<Option<TouchGesture>>,
+    mut press_order: Local<TouchPressOrder>,
+    // Output
+    mut pointer_pinch: PointerEventWriter<Pinch>,
+    mut pointer_rotate: PointerEventWriter<Rotate>,
+) {
+    let mut touches: Vec<(PointerId, &Location)> = pointers
+        .iter()
+        .filter(|(id, _, press)| id.is_touch() && press.is_primary_pressed())
+        .filter_map(|(id, location, _)| Some((*id, location.location()?)))
+        .collect();
+    // Touches pressed in the same update are ordered by their id.
+    touches.sort_by_key(|(id, _)| id.get_touch_id());
+    press_order.update(touches.iter().map(|(id, _)| *id));
+    touches.sort_by_key(|(id, _)| press_order.pressed[id]);
+    let touch = |pointer_id: PointerId| {
+        touches
+            .iter()
+            .find(|(id, _)| *id == pointer_id)
+            .map(|(_, location)| *location)
+    };
+    let measure = |a: &Location, b: &Location| {
+        let offset = b.position - a.position;
+        (offset.length(), offset.y.atan2(offset.x))
+    };
 
-/// An entry in the [`DragMap`].
+    let pair = gesture.and_then(|gesture| {
+        let [a, b] = gesture.pointers;
+        Some((gesture, touch(a)?, touch(b)?)).filter(|(_, a, b)| a.target == b.target)
+    });
+    let Some((previous, a, b)) = pair else {
+        // Start a new gesture from the touches that have been active longest, if any.
+        *gesture = touches
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| touches[i + 1..].iter().map(move |b| (a, b)))
+            .find(|((_, a), (_, b))| a.target == b.target)
+            .map(|((a_id, a), (b_id, b))| {
+                let (distance, angle) = measure(a, b);
+                TouchGesture {
+                    pointers: [*a_id, *b_id],
+                    distance,
+                    angle,
+                }
+            });
+        return;
+    };
+
+    let (distance, angle) = measure(a, b);
+    *gesture = Some(TouchGesture {
+        distance,
+        angle,
+        ..previous
+    });
+
+    let [a_id, b_id] = previous.pointers;
+    let (Some(a_hits), Some(b_hits)) = (hover_map.get(&a_id), hover_map.get(&b_id)) else {
+        return;
+    };
+    let Some((target, hit)) = a_hits
+        .iter()
+        .filter(|(entity, _)| b_hits.contains_key(*entity))
+        .min_by(|(_, a), (_, b)| a.depth.total_cmp(&b.depth))
+    else {
+        return;
+    };
+    let location = Location {
+        position: a.position.lerp(b.position, 0.5),
+        ..a.clone()
+    };
+
+    if distance > 0.0 && previous.distance > 0.0 && distance != previous.distance {
+        pointer_pinch.send(Pointer::new(
+            a_id,
+            location.clone(),
+            *target,
+            Pinch {
+                pointers: previous.pointers,
+                scale_delta: distance / previous.distance,
+                distance,
+                hit: hit.clone(),
+            },
+        ));
+    }
+    let angle_delta = (angle - previous.angle + std::f32::consts::PI)
+        .rem_euclid(std::f32::consts::TAU)
+        - std::f32::consts::PI;
+    if angle_delta != 0.0 {
+        pointer_rotate.send(Pointer::new(
+            a_id,
+            location,
+            *target,
+            Rotate {
+                pointers: previous.pointers,
+                angle_delta,
+                hit: hit.clone(),
+            },
+        ));
+    }
+}
+
+/// A [`Down`] event on an entity, and whether the entity can be clicked or dragged by that press.
 #[derive(Debug, Clone)]
-pub struct DragEntry {
-    /// The position of the pointer at drag start.
-    pub start_pos: Vec2,
-    /// The latest position of the pointer during this drag, used to compute deltas.
-    pub latest_pos: Vec2,
+pub struct PressedEntity {
+    /// The event that pressed the entity.
+    pub down: Pointer<Down>,
+    /// Whether the entity can be clicked, see [`PickableEvents::click`](crate::PickableEvents::click).
+    pub can_click: bool,
+    /// Whether the entity can be dragged, see [`PickableEvents::drag`](crate::PickableEvents::drag).
+    pub can_drag: bool,
 }
 
-/// Uses pointer events to determine when click and drag events occur.
+/// Uses pointer events to determine when click and drag events occur. Drags only start once the
+/// pointer has moved further than the [`DragSettings::threshold`].
 pub fn send_click_and_drag_events(
     // Input
     mut pointer_down: EventReader<Pointer<Down>>,
     mut pointer_up: EventReader<Pointer<Up>>,
     mut input_move: EventReader<InputMove>,
-    mut input_presses: EventReader<InputPress>,
+    mut input_presses: DebouncedPresses,
     pointer_map: Res<PointerMap>,
-    pointers: Query<&PointerLocation>,
+    (pointers, velocities, (drag_constraints, drag_modifiers), payloads, disabled, mapper): (
+        Query<&PointerLocation>,
+        Query<&PointerVelocity>,
+        (Query<&DragConstraint>, Query<&DragModifier>),
+        Query<&DragPayload>,
+        DisabledPointers,
+        Res<PointerLocationMapper>,
+    ),
+    (drag_planes, ray_map): (Query<(&DragPlane, Option<&GlobalTransform>)>, Res<RayMap>),
+    (scroll_map, time): (Res<ScrollMap>, Res<Time<Real>>),
+    (exclusions, drag_settings, entities, pointer_presses): (
+        Res<GestureExclusions>,
+        Res<DragSettings>,
+        &Entities,
+        Query<&PointerPress>,
+    ),
+    (event_hover_maps, mut withheld_downs, mut withheld_ups): (
+        Res<EventHoverMaps>,
+        ResMut<WithheldPointerEvents<Down>>,
+        ResMut<WithheldPointerEvents<Up>>,
+    ),
     // Locals
-    mut down_map: Local<HashMap<(PointerId, PointerButton), HashMap<Entity, Pointer<Down>>>>,
+    mut down_map: Local<HashMap<(PointerId, PointerButton), HashMap<Entity, PressedEntity>>>,
     // Output
     mut drag_map: ResMut<DragMap>,
-    mut pointer_click: EventWriter<Pointer<Click>>,
-    mut pointer_drag_start: EventWriter<Pointer<DragStart>>,
-    mut pointer_drag_end: EventWriter<Pointer<DragEnd>>,
-    mut pointer_drag: EventWriter<Pointer<Drag>>,
+    mut click_emitter: ClickEmitter,
+    mut pointer_drag_start: PointerEventWriter<DragStart>,
+    mut pointer_drag_end: PointerEventWriter<DragEnd>,
+    mut pointer_drag: PointerEventWriter<Drag>,
 ) {
     let pointer_location = |pointer_id: PointerId| {
         pointer_map
@@ -378,29 +2695,86 @@ pub fn send_click_and_drag_events(
             .and_then(|entity| pointers.get(entity).ok())
             .and_then(|pointer| pointer.location.clone())
     };
+    let dragged_hit = |pointer_id: PointerId, dragged: Entity| {
+        event_hover_maps
+            .drag
+            .get(&pointer_id)
+            .and_then(|hovered| hovered.get(&dragged))
+            .cloned()
+    };
+
+    // Send any deferred clicks that can no longer become double clicks
+    click_emitter.flush();
+
+    // Entities despawned while pressed can't be dragged
+    for down_list in down_map.values_mut() {
+        down_list.retain(|entity, _| entities.contains(*entity));
+    }
 
     // Triggers during movement even if not over an entity
     for InputMove {
         pointer_id,
         location,
-        delta: _,
-    } in input_move.read().cloned()
+        ..
+    } in input_move
+        .read()
+        .filter(|input| !disabled.contains(input.pointer_id))
+        .map(|input| mapper.map_move(input))
     {
+        let drag_start_blocked = exclusions.scroll_blocks_drag
+            && scroll_map.is_scrolling(
+                pointer_id,
+                time.elapsed(),
+                exclusions.scroll_gesture_timeout,
+            );
         for button in PointerButton::iter() {
             let Some(down_list) = down_map.get(&(pointer_id, button)) else {
                 continue;
             };
             let drag_list = drag_map.entry((pointer_id, button)).or_default();
 
-            for down in down_list.values() {
+            for PressedEntity { down, can_drag, .. } in down_list.values() {
+                if !can_drag {
+                    continue; // this entity does not accept drag events
+                }
                 if drag_list.contains_key(&down.target) {
                     continue; // this entity is already logged as being dragged
                 }
+                if drag_start_blocked {
+                    continue; // this pointer is scrolling, which excludes dragging
+                }
+                if location.position.distance(down.pointer_location.position)
+                    <= drag_settings.threshold
+                {
+                    continue; // the pointer has not moved far enough to start dragging
+                }
+                let world =
+                    drag_planes
+                        .get(down.target)
+                        .ok()
+                        .and_then(|(drag_plane, transform)| {
+                            let start = down
+                                .hit
+                                .position
+                                .or_else(|| Some(transform?.translation()))?;
+                            Some(WorldDrag {
+                                camera: down.hit.camera,
+                                plane: Plane3d {
+                                    normal: Direction3d::new(drag_plane.normal).ok()?,
+                                },
+                                start,
+                                latest: start,
+                            })
+                        });
                 drag_list.insert(
                     down.target,
                     DragEntry {
                         start_pos: down.pointer_location.position,
                         latest_pos: down.pointer_location.position,
+                        distance: Vec2::ZERO,
+                        scaled_distance: Vec2::ZERO,
+                        payload: payloads.get(down.target).ok().cloned(),
+                        world,
                     },
                 );
                 pointer_drag_start.send(Pointer::new(
@@ -410,15 +2784,41 @@ pub fn send_click_and_drag_events(
                     DragStart {
                         button,
                         hit: down.hit.clone(),
+                        modifiers: down.modifiers,
                     },
                 ));
             }
 
             for (dragged_entity, drag) in drag_list.iter_mut() {
+                let raw_distance = location.position - drag.start_pos;
+                let raw_delta = location.position - drag.latest_pos;
+                let constraint = drag_constraints.get(*dragged_entity).ok();
+                let constrain = |vector| constraint.map_or(vector, |c| c.apply(vector));
+                let (distance, delta) = match drag_modifiers.get(*dragged_entity) {
+                    Ok(modifier) => {
+                        drag.scaled_distance += constrain(raw_delta) * modifier.sensitivity;
+                        let distance = modifier.snap(drag.scaled_distance);
+                        (distance, distance - drag.distance)
+                    }
+                    Err(_) => {
+                        drag.scaled_distance = constrain(raw_distance);
+                        (constrain(raw_distance), constrain(raw_delta))
+                    }
+                };
+                drag.distance = distance;
+                let world = drag
+                    .world
+                    .as_mut()
+                    .and_then(|world| world.update(pointer_id, &ray_map));
                 let drag_event = Drag {
                     button,
-                    distance: location.position - drag.start_pos,
-                    delta: location.position - drag.latest_pos,
+                    distance,
+                    delta,
+                    raw_distance,
+                    raw_delta,
+                    world_distance: world.map(|(distance, _)| distance),
+                    world_delta: world.map(|(_, delta)| delta),
+                    hit: dragged_hit(pointer_id, *dragged_entity),
                 };
                 drag.latest_pos = location.position;
                 pointer_drag.send(Pointer::new(
@@ -436,36 +2836,100 @@ pub fn send_click_and_drag_events(
         pointer_id,
         pointer_location,
         target,
-        event: Up { button, hit },
-    } in pointer_up.read().cloned()
+        event:
+            Up {
+                button,
+                hit,
+                held,
+                modifiers,
+            },
+    } in pointer_up.read().cloned().chain(withheld_ups.drain())
     {
+        let dragged = drag_map
+            .get(&(pointer_id, button))
+            .is_some_and(|drags| !drags.is_empty());
+        if dragged && !click_emitter.settings.click_after_drag {
+            continue; // this press started a drag, so it is not a click
+        }
         // Can't have a click without the button being pressed down first
         if down_map
             .get(&(pointer_id, button))
             .and_then(|down| down.get(&target))
-            .is_some()
+            .is_some_and(|pressed| pressed.can_click)
         {
-            pointer_click.send(Pointer::new(
+            click_emitter.click(Pointer::new(
                 pointer_id,
                 pointer_location,
                 target,
-                Click { button, hit },
+                Click {
+                    button,
+                    hit,
+                    click_count: 1,
+                    held,
+                    modifiers,
+                },
             ));
         }
     }
 
     // Triggers when button is pressed over an entity
-    for event in pointer_down.read() {
+    for event in pointer_down.read().cloned().chain(withheld_downs.drain()) {
         let button = event.button;
+        let can = |map: &HashMap<PointerId, EntityHashMap<HitData>>| {
+            map.get(&event.pointer_id)
+                .is_some_and(|hovered| hovered.contains_key(&event.target))
+        };
+        let pressed = PressedEntity {
+            down: event.clone(),
+            can_click: can(&event_hover_maps.click),
+            can_drag: can(&event_hover_maps.drag),
+        };
         let down_button_entity_map = down_map.entry((event.pointer_id, button)).or_default();
-        down_button_entity_map.insert(event.target, event.clone());
+        down_button_entity_map.insert(event.target, pressed);
     }
 
-    // Triggered for all button presses
-    for press in input_presses.read() {
-        if press.direction != pointer::PressDirection::Up {
-            continue; // We are only interested in button releases
+    // Triggered for all button releases. Disabled pointers release every button they were
+    // pressing, ending their drags. Buttons whose press was cancelled without a release, such as
+    // when the window lost focus, are released too, cancelling their drags.
+    let is_pressing = |pointer_id: PointerId, button: PointerButton| {
+        let key = (pointer_id, button);
+        down_map.get(&key).is_some_and(|down| !down.is_empty())
+            || drag_map.get(&key).is_some_and(|drags| !drags.is_empty())
+    };
+    let mut releases = input_presses
+        .read()
+        .filter(|press| press.direction == pointer::PressDirection::Up)
+        .filter(|press| !disabled.contains(press.pointer_id))
+        .copied()
+        .chain(disabled.iter().flat_map(|pointer_id| {
+            PointerButton::iter()
+                .filter(move |button| is_pressing(pointer_id, *button))
+                .map(move |button| InputPress::new_up(pointer_id, button))
+        }))
+        .map(|press| (press, false))
+        .collect::<Vec<_>>();
+    let is_cancelled = |&(pointer_id, button): &(PointerId, PointerButton)| {
+        is_pressing(pointer_id, button)
+            && pointer_map
+                .get_entity(pointer_id)
+                .and_then(|entity| pointer_presses.get(entity).ok())
+                .is_some_and(|press| !press.is_pressed(button))
+    };
+    let cancelled_presses: HashSet<_> = down_map
+        .keys()
+        .chain(drag_map.keys())
+        .filter(|key| is_cancelled(key))
+        .copied()
+        .collect();
+    for (pointer_id, button) in cancelled_presses {
+        let is_released = releases
+            .iter()
+            .any(|(press, _)| press.pointer_id == pointer_id && press.button == button);
+        if !is_released {
+            releases.push((InputPress::new_up(pointer_id, button), true));
         }
+    }
+    for (press, cancelled) in &releases {
         down_map.insert((press.pointer_id, press.button), HashMap::new());
         let Some(drag_list) = drag_map.insert((press.pointer_id, press.button), HashMap::new())
         else {
@@ -479,10 +2943,18 @@ pub fn send_click_and_drag_events(
             continue;
         };
 
+        let velocity = pointer_map
+            .get_entity(press.pointer_id)
+            .and_then(|entity| velocities.get(entity).ok())
+            .map_or(Vec2::ZERO, |velocity| velocity.smoothed);
         for (drag_target, drag) in drag_list {
             let drag_end = DragEnd {
                 button: press.button,
                 distance: drag.latest_pos - drag.start_pos,
+                velocity,
+                hit: dragged_hit(press.pointer_id, drag_target),
+                payload: drag.payload,
+                cancelled: *cancelled,
             };
             pointer_drag_end.send(Pointer::new(
                 press.pointer_id,
@@ -494,30 +2966,176 @@ pub fn send_click_and_drag_events(
     }
 }
 
+/// Cancels the interactions of entities that were despawned while they were hovered, dragged, or
+/// dragged over, so no state or events are left for dead entities.
+///
+/// Despawned entities are removed from the [`HoverMap`], [`PreviousHoverMap`], [`EventHoverMaps`],
+/// [`PreviousEventHoverMaps`], [`DragMap`], and [`DragOverMap`], before [`update_focus`] runs.
+/// [`Out`], [`DragEnd`], and [`DragLeave`] events are sent for them as usual, so systems reading
+/// these events can unwind their own state, but no [`Drop`] is sent for despawned entities.
+///
+/// [`update_focus`]: crate::focus::update_focus
+pub fn cancel_despawned_interactions(
+    // Input
+    entities: &Entities,
+    pointer_map: Res<PointerMap>,
+    pointers: Query<&PointerLocation>,
+    // Output
+    (mut hover_map, mut previous_hover_map): (ResMut<HoverMap>, ResMut<PreviousHoverMap>),
+    (mut event_hover_maps, mut previous_event_hover_maps): (
+        ResMut<EventHoverMaps>,
+        ResMut<PreviousEventHoverMaps>,
+    ),
+    mut drag_map: ResMut<DragMap>,
+    mut drag_over_map: ResMut<DragOverMap>,
+    mut pointer_out: PointerEventWriter<Out>,
+    mut pointer_drag_end: PointerEventWriter<DragEnd>,
+    mut pointer_drag_leave: PointerEventWriter<DragLeave>,
+) {
+    let pointer_location = |pointer_id: PointerId| {
+        pointer_map
+            .get_entity(pointer_id)
+            .and_then(|entity| pointers.get(entity).ok())
+            .and_then(|pointer| pointer.location.clone())
+    };
+    let is_alive = |entity: &Entity| entities.contains(*entity);
+
+    for (pointer_id, hovered) in hover_map.iter_mut() {
+        if hovered.keys().all(is_alive) {
+            continue;
+        }
+        let location = pointer_location(*pointer_id);
+        for (entity, hit) in hovered.extract_if(|entity, _| !is_alive(entity)) {
+            if let Some(location) = location.clone() {
+                pointer_out.send(Pointer::new(*pointer_id, location, entity, Out { hit }));
+            }
+        }
+    }
+    let EventHoverMaps {
+        click,
+        drag,
+        scroll,
+    } = &mut *event_hover_maps;
+    let PreviousEventHoverMaps(previous_event_hover_maps) = &mut *previous_event_hover_maps;
+    let EventHoverMaps {
+        click: previous_click,
+        drag: previous_drag,
+        scroll: previous_scroll,
+    } = previous_event_hover_maps;
+    for map in [
+        &mut previous_hover_map.0,
+        click,
+        drag,
+        scroll,
+        previous_click,
+        previous_drag,
+        previous_scroll,
+    ] {
+        for hovered in map.values_mut() {
+            hovered.retain(|entity, _| is_alive(entity));
+        }
+    }
+
+    for (&(pointer_id, button), drags) in drag_map.iter_mut() {
+        if drags.keys().all(is_alive) {
+            continue;
+        }
+        let location = pointer_location(pointer_id);
+        let despawned: Vec<_> = drags.extract_if(|entity, _| !is_alive(entity)).collect();
+        let dragged_over = drag_over_map.entry((pointer_id, button)).or_default();
+        let despawned_over: Vec<_> = dragged_over
+            .extract_if(|entity, _| !is_alive(entity))
+            .collect();
+        let leaves = despawned_over
+            .iter()
+            .flat_map(|(over, hit)| {
+                let dragged = despawned
+                    .iter()
+                    .map(|(entity, _)| entity)
+                    .chain(drags.keys());
+                dragged.map(move |dragged| (*over, *dragged, hit))
+            })
+            .chain(dragged_over.iter().flat_map(|(over, hit)| {
+                // Despawned entities leave without being dropped.
+                despawned
+                    .iter()
+                    .map(move |(dragged, _)| (*over, *dragged, hit))
+            }));
+        if let Some(location) = &location {
+            for (dragged_over, dragged, hit) in leaves {
+                pointer_drag_leave.send(Pointer::new(
+                    pointer_id,
+                    location.clone(),
+                    dragged_over,
+                    DragLeave {
+                        button,
+                        dragged,
+                        hit: hit.clone(),
+                    },
+                ));
+            }
+        }
+        if drags.is_empty() {
+            dragged_over.clear();
+        }
+        let Some(location) = location else {
+            continue;
+        };
+        for (dragged, drag) in despawned {
+            pointer_drag_end.send(Pointer::new(
+                pointer_id,
+                location.clone(),
+                dragged,
+                DragEnd {
+                    button,
+                    distance: drag.latest_pos - drag.start_pos,
+                    velocity: Vec2::ZERO,
+                    hit: None,
+                    payload: drag.payload,
+                    cancelled: true,
+                },
+            ));
+        }
+    }
+    for dragged_over in drag_over_map.values_mut() {
+        dragged_over.retain(|entity, _| is_alive(entity));
+    }
+}
+
 /// Uses pointer events to determine when drag-over events occur
 pub fn send_drag_over_events(
     // Input
+    entities: &Entities,
     drag_map: Res<DragMap>,
+    transforms: Query<&GlobalTransform>,
     mut pointer_over: EventReader<Pointer<Over>>,
     mut pointer_move: EventReader<Pointer<Move>>,
     mut pointer_out: EventReader<Pointer<Out>>,
     mut pointer_drag_end: EventReader<Pointer<DragEnd>>,
-    // Local
-    mut drag_over_map: Local<HashMap<(PointerId, PointerButton), HashMap<Entity, HitData>>>,
-
+    (mut withheld_overs, mut withheld_moves, mut withheld_outs): (
+        ResMut<WithheldPointerEvents<Over>>,
+        ResMut<WithheldPointerEvents<Move>>,
+        ResMut<WithheldPointerEvents<Out>>,
+    ),
     // Output
-    mut pointer_drag_enter: EventWriter<Pointer<DragEnter>>,
-    mut pointer_drag_over: EventWriter<Pointer<DragOver>>,
-    mut pointer_drag_leave: EventWriter<Pointer<DragLeave>>,
-    mut pointer_drop: EventWriter<Pointer<Drop>>,
+    mut drag_over_map: ResMut<DragOverMap>,
+    mut pointer_drag_enter: PointerEventWriter<DragEnter>,
+    mut pointer_drag_over: PointerEventWriter<DragOver>,
+    mut pointer_drag_leave: PointerEventWriter<DragLeave>,
+    mut pointer_drop: PointerEventWriter<Drop>,
 ) {
+    let local_position = |target: Entity, hit: &HitData| {
+        let transform = transforms.get(target).ok()?;
+        Some(transform.affine().inverse().transform_point3(hit.position?))
+    };
+
     // Fire PointerDragEnter events.
     for Pointer {
         pointer_id,
         pointer_location,
         target,
         event: Over { hit },
-    } in pointer_over.read().cloned()
+    } in pointer_over.read().cloned().chain(withheld_overs.drain())
     {
         for button in PointerButton::iter() {
             for drag_target in drag_map
@@ -534,6 +3152,7 @@ pub fn send_drag_over_events(
                     button,
                     dragged: *drag_target,
                     hit: hit.clone(),
+                    local_position: local_position(target, &hit),
                 };
                 pointer_drag_enter.send(Pointer::new(
                     pointer_id,
@@ -550,8 +3169,8 @@ pub fn send_drag_over_events(
         pointer_id,
         pointer_location,
         target,
-        event: Move { hit, delta: _ },
-    } in pointer_move.read().cloned()
+        event: Move { hit, .. },
+    } in pointer_move.read().cloned().chain(withheld_moves.drain())
     {
         for button in PointerButton::iter() {
             for drag_target in drag_map
@@ -570,6 +3189,7 @@ pub fn send_drag_over_events(
                         button,
                         dragged: *drag_target,
                         hit: hit.clone(),
+                        local_position: local_position(target, &hit),
                     },
                 ));
             }
@@ -581,12 +3201,19 @@ pub fn send_drag_over_events(
         pointer_id,
         pointer_location,
         target,
-        event: DragEnd {
-            button,
-            distance: _,
-        },
+        event:
+            DragEnd {
+                button,
+                payload,
+                cancelled,
+                ..
+            },
     } in pointer_drag_end.read().cloned()
     {
+        // Drags of despawned entities are cancelled by `cancel_despawned_interactions`.
+        if !entities.contains(target) {
+            continue;
+        }
         let Some(drag_over_set) = drag_over_map.get_mut(&(pointer_id, button)) else {
             continue;
         };
@@ -601,6 +3228,9 @@ pub fn send_drag_over_events(
                     hit: hit.clone(),
                 },
             ));
+            if cancelled {
+                continue;
+            }
             pointer_drop.send(Pointer::new(
                 pointer_id,
                 pointer_location.clone(),
@@ -609,6 +3239,7 @@ pub fn send_drag_over_events(
                     button,
                     dropped: target,
                     hit: hit.clone(),
+                    payload: payload.clone(),
                 },
             ));
         }
@@ -620,7 +3251,7 @@ pub fn send_drag_over_events(
         pointer_location,
         target,
         event: Out { hit },
-    } in pointer_out.read().cloned()
+    } in pointer_out.read().cloned().chain(withheld_outs.drain())
     {
         for button in PointerButton::iter() {
             let Some(dragged_over) = drag_over_map.get_mut(&(pointer_id, button)) else {
@@ -647,3 +3278,578 @@ pub fn send_drag_over_events(
         }
     }
 }
+
+/// Uses drag events to determine when a dragging pointer leaves or re-enters the viewport the drag
+/// started in, sending [`DragViewportExit`] and [`DragViewportEnter`] events on each transition.
+///
+/// The viewport is that of the highest-order active camera containing the pointer at drag start.
+/// Drags that start outside of any camera's viewport are not tracked.
+pub fn send_drag_viewport_events(
+    // Input
+    mut pointer_drag_start: EventReader<Pointer<DragStart>>,
+    mut pointer_drag: EventReader<Pointer<Drag>>,
+    mut pointer_drag_end: EventReader<Pointer<DragEnd>>,
+    cameras: Query<(Entity, &Camera)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    // Local
+    mut viewport_map: Local<HashMap<(PointerId, PointerButton, Entity), (Entity, ViewportEdges)>>,
+    // Output
+    mut pointer_viewport_exit: PointerEventWriter<DragViewportExit>,
+    mut pointer_viewport_enter: PointerEventWriter<DragViewportEnter>,
+) {
+    for Pointer {
+        pointer_id,
+        pointer_location,
+        target,
+        event: DragStart { button, .. },
+    } in pointer_drag_start.read()
+    {
+        let Some((camera_entity, _)) = cameras
+            .iter()
+            .filter(|(_, camera)| camera.is_active)
+            .filter(|(_, camera)| pointer_location.is_in_viewport(camera, &primary_window))
+            .max_by_key(|(_, camera)| camera.order)
+        else {
+            continue;
+        };
+        viewport_map.insert(
+            (*pointer_id, *button, *target),
+            (camera_entity, ViewportEdges::default()),
+        );
+    }
+
+    for Pointer {
+        pointer_id,
+        pointer_location,
+        target,
+        event: Drag { button, .. },
+    } in pointer_drag.read().cloned()
+    {
+        let Some((camera_entity, outside)) = viewport_map.get_mut(&(pointer_id, button, target))
+        else {
+            continue;
+        };
+        let Some(viewport) = cameras
+            .get(*camera_entity)
+            .ok()
+            .and_then(|(_, camera)| camera.logical_viewport_rect())
+        else {
+            continue;
+        };
+        let edges = ViewportEdges::outside(viewport, pointer_location.position);
+        if outside.is_empty() && !edges.is_empty() {
+            pointer_viewport_exit.send(Pointer::new(
+                pointer_id,
+                pointer_location,
+                target,
+                DragViewportExit { button, edges },
+            ));
+        } else if !outside.is_empty() && edges.is_empty() {
+            pointer_viewport_enter.send(Pointer::new(
+                pointer_id,
+                pointer_location,
+                target,
+                DragViewportEnter {
+                    button,
+                    edges: *outside,
+                },
+            ));
+        }
+        *outside = edges;
+    }
+
+    for Pointer {
+        pointer_id,
+        target,
+        event: DragEnd { button, .. },
+        ..
+    } in pointer_drag_end.read()
+    {
+        viewport_map.remove(&(*pointer_id, *button, *target));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::prelude::*;
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_render::{
+        camera::{camera_system, ManualTextureViews, NormalizedRenderTarget, Projection},
+        texture::Image,
+    };
+    use bevy_window::{WindowCreated, WindowRef, WindowResolution};
+
+    use super::*;
+    use crate::{
+        backend::PointerHits, testing::*, CorePlugin, InteractionPlugin, PickSet, Pickable,
+        PointerCoreBundle,
+    };
+
+    #[test]
+    fn normalized_move_deltas_ignore_scale_factor_changes() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(MoveDeltaSettings { normalize: true });
+        let resolution = WindowResolution::default().with_scale_factor_override(2.0);
+        let window = app
+            .world
+            .spawn(Window {
+                resolution,
+                ..Default::default()
+            })
+            .id();
+        app.world.spawn(PointerCoreBundle::new(PointerId::Mouse));
+        let item = app.world.spawn_empty().id();
+        let move_to = |app: &mut App, x: f32, delta: f32| {
+            let window = WindowRef::Entity(window).normalize(None).unwrap();
+            let location = Location {
+                target: NormalizedRenderTarget::Window(window),
+                position: Vec2::new(x, 0.0),
+            };
+            let delta = Vec2::new(delta, 0.0);
+            let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+            app.world
+                .send_event(InputMove::new(PointerId::Mouse, location, delta));
+            app.world
+                .send_event(PointerHits::new(PointerId::Mouse, vec![(item, hit)], 0.0));
+            app.update();
+            sent_pointer_events::<Move>(app)[0].event.clone()
+        };
+
+        let moved = move_to(&mut app, 10.0, 10.0);
+        assert_eq!(moved.physical_delta, Some(Vec2::new(20.0, 0.0)));
+
+        // The scale factor halves, so the cursor's logical position doubles without it moving.
+        app.world
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set_scale_factor_override(Some(1.0));
+        let moved = move_to(&mut app, 25.0, 15.0);
+        assert_eq!(moved.physical_delta, Some(Vec2::new(5.0, 0.0)));
+        assert_eq!(moved.delta, Vec2::new(5.0, 0.0));
+    }
+
+    #[test]
+    fn drags_do_not_click_by_default() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        let button = app.world.spawn_empty().id();
+
+        simulate_press(&mut app, PointerId::Mouse, PointerButton::Primary, button);
+        let away = simulated_location(Vec2::new(50.0, 0.0));
+        simulate_pointer_move(&mut app, PointerId::Mouse, away);
+        simulate_hover(&mut app, PointerId::Mouse, button);
+        simulate_release(&mut app, PointerId::Mouse, PointerButton::Primary, button);
+
+        assert!(sent_pointer_events::<Click>(&app).is_empty());
+        assert_eq!(sent_pointer_events::<DragEnd>(&app).len(), 1);
+    }
+
+    #[test]
+    fn click_cooldowns_swallow_clicks_until_they_pass() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        let button = app
+            .world
+            .spawn(ClickCooldown(Duration::from_millis(300)))
+            .id();
+        let advance = |app: &mut App, millis| {
+            let mut time = app.world.resource_mut::<Time<Real>>();
+            time.advance_by(Duration::from_millis(millis));
+        };
+
+        simulate_click(&mut app, PointerId::Mouse, button);
+        assert_eq!(sent_pointer_events::<Click>(&app).len(), 1);
+
+        // A click within the cooldown is swallowed, even from another pointer.
+        advance(&mut app, 100);
+        simulate_click(&mut app, PointerId::Touch(0), button);
+        assert!(sent_pointer_events::<Click>(&app).is_empty());
+
+        advance(&mut app, 300);
+        simulate_click(&mut app, PointerId::Mouse, button);
+        assert_eq!(sent_pointer_events::<Click>(&app).len(), 1);
+    }
+
+    #[test]
+    fn disabled_clicks_still_start_drags() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(PickingEventSettings {
+                click: false,
+                ..Default::default()
+            });
+        let item = app.world.spawn_empty().id();
+
+        simulate_press(&mut app, PointerId::Mouse, PointerButton::Primary, item);
+        assert!(sent_pointer_events::<Down>(&app).is_empty());
+        let away = simulated_location(Vec2::new(50.0, 0.0));
+        simulate_pointer_move(&mut app, PointerId::Mouse, away);
+        assert_eq!(sent_pointer_events::<DragStart>(&app).len(), 1);
+
+        simulate_release(&mut app, PointerId::Mouse, PointerButton::Primary, item);
+        assert!(sent_pointer_events::<Up>(&app).is_empty());
+        assert_eq!(sent_pointer_events::<DragEnd>(&app).len(), 1);
+    }
+
+    #[test]
+    fn pointer_moved_is_sent_over_empty_space() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        let moved = |app: &App| -> Vec<PointerMoved> {
+            let events = app.world.resource::<Events<PointerMoved>>();
+            events.iter_current_update_events().cloned().collect()
+        };
+
+        // Disabled by default.
+        simulate_pointer_move(&mut app, PointerId::Mouse, simulated_location(Vec2::ZERO));
+        assert!(moved(&app).is_empty());
+
+        app.insert_resource(PointerMovedSettings { is_enabled: true });
+        let location = simulated_location(Vec2::new(10.0, 5.0));
+        simulate_pointer_move(&mut app, PointerId::Mouse, location.clone());
+        assert!(sent_pointer_events::<Move>(&app).is_empty());
+        assert_eq!(
+            moved(&app),
+            [PointerMoved {
+                pointer_id: PointerId::Mouse,
+                location,
+                delta: Vec2::new(10.0, 5.0),
+            }]
+        );
+    }
+
+    /// Scrolls the mouse over `entity`, and returns the number of [`Scroll`] events sent.
+    fn scroll(app: &mut App, entity: Entity) -> usize {
+        let scroll = InputScroll::new(PointerId::Mouse, Vec2::Y, ScrollUnit::Line);
+        app.world.send_event(scroll);
+        simulate_hover(app, PointerId::Mouse, entity);
+        sent_pointer_events::<Scroll>(app).len()
+    }
+
+    #[test]
+    fn dragging_blocks_scrolling_on_the_same_pointer() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        let item = app.world.spawn_empty().id();
+
+        simulate_press(&mut app, PointerId::Mouse, PointerButton::Primary, item);
+        let away = simulated_location(Vec2::new(50.0, 0.0));
+        simulate_pointer_move(&mut app, PointerId::Mouse, away);
+        assert_eq!(sent_pointer_events::<DragStart>(&app).len(), 1);
+        assert_eq!(scroll(&mut app, item), 0);
+
+        simulate_release(&mut app, PointerId::Mouse, PointerButton::Primary, item);
+        assert_eq!(scroll(&mut app, item), 1);
+    }
+
+    #[test]
+    fn scrolling_blocks_drag_starts_until_the_gesture_times_out() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(GestureExclusions {
+                scroll_blocks_drag: true,
+                ..Default::default()
+            });
+        let item = app.world.spawn_empty().id();
+
+        assert_eq!(scroll(&mut app, item), 1);
+        simulate_press(&mut app, PointerId::Mouse, PointerButton::Primary, item);
+        let away = simulated_location(Vec2::new(50.0, 0.0));
+        simulate_pointer_move(&mut app, PointerId::Mouse, away);
+        assert!(sent_pointer_events::<DragStart>(&app).is_empty());
+
+        app.world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(200));
+        simulate_pointer_move(&mut app, PointerId::Mouse, simulated_location(Vec2::ONE));
+        assert_eq!(sent_pointer_events::<DragStart>(&app).len(), 1);
+    }
+
+    #[test]
+    fn drags_report_crossing_the_viewport_boundary() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<AssetEvent<Image>>()
+            .add_event::<WindowCreated>()
+            .add_systems(First, camera_system::<Projection>);
+        let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+        app.world.spawn((Camera::default(), Projection::default()));
+        let window = WindowRef::Entity(window).normalize(None).unwrap();
+        let location = |x| Location {
+            target: NormalizedRenderTarget::Window(window),
+            position: Vec2::new(x, 360.0),
+        };
+        app.world
+            .spawn(PointerCoreBundle::new(PointerId::Mouse).with_location(location(640.0)));
+        let item = app.world.spawn_empty().id();
+
+        simulate_press(&mut app, PointerId::Mouse, PointerButton::Primary, item);
+        simulate_pointer_move(&mut app, PointerId::Mouse, location(600.0));
+        assert_eq!(sent_pointer_events::<DragStart>(&app).len(), 1);
+        assert!(sent_pointer_events::<DragViewportExit>(&app).is_empty());
+
+        let left = ViewportEdges {
+            left: true,
+            ..Default::default()
+        };
+        simulate_pointer_move(&mut app, PointerId::Mouse, location(-10.0));
+        let exits = sent_pointer_events::<DragViewportExit>(&app);
+        assert_eq!(exits.len(), 1);
+        assert_eq!((exits[0].target, exits[0].edges), (item, left));
+
+        // Moving further outside does not exit again.
+        simulate_pointer_move(&mut app, PointerId::Mouse, location(-20.0));
+        assert!(sent_pointer_events::<DragViewportExit>(&app).is_empty());
+
+        simulate_pointer_move(&mut app, PointerId::Mouse, location(10.0));
+        let enters = sent_pointer_events::<DragViewportEnter>(&app);
+        assert_eq!(enters.len(), 1);
+        assert_eq!((enters[0].target, enters[0].edges), (item, left));
+    }
+
+    #[test]
+    fn global_presses_target_the_nearest_hovered_entity() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        app.world.spawn(
+            PointerCoreBundle::new(PointerId::Mouse).with_location(simulated_location(Vec2::ZERO)),
+        );
+        let far = app.world.spawn_empty().id();
+        let near = app
+            .world
+            .spawn(Pickable {
+                should_block_lower: false,
+                is_hoverable: true,
+            })
+            .id();
+
+        let hit = |depth| HitData::new(Entity::PLACEHOLDER, depth, None, None);
+        let hits = vec![(far, hit(5.0)), (near, hit(1.0))];
+        app.world
+            .send_event(PointerHits::new(PointerId::Mouse, hits, 0.0));
+        app.world.send_event(InputPress::new_down(
+            PointerId::Mouse,
+            PointerButton::Primary,
+        ));
+        app.update();
+
+        let presses: Vec<_> = app
+            .world
+            .resource::<Events<PointerDownGlobal>>()
+            .iter_current_update_events()
+            .map(|press| press.target)
+            .collect();
+        assert_eq!(presses, [Some(near)]);
+    }
+
+    #[test]
+    fn gestures_pair_the_longest_pressed_touches() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        let item = app.world.spawn_empty().id();
+        let update = |app: &mut App, touches: &[u64]| {
+            for id in touches {
+                let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+                let hits = vec![(item, hit)];
+                app.world
+                    .send_event(PointerHits::new(PointerId::Touch(*id), hits, 0.0));
+            }
+            app.update();
+        };
+        let press = |app: &mut App, id: u64, x: f32| {
+            let location = simulated_location(Vec2::new(x, 0.0));
+            app.world
+                .spawn(PointerCoreBundle::new(PointerId::Touch(id)).with_location(location));
+            app.world.send_event(InputPress::new_down(
+                PointerId::Touch(id),
+                PointerButton::Primary,
+            ));
+        };
+
+        press(&mut app, 5, 0.0);
+        press(&mut app, 3, 10.0);
+        update(&mut app, &[5, 3]);
+        press(&mut app, 2, 20.0);
+        update(&mut app, &[5, 3, 2]);
+        press(&mut app, 1, 30.0);
+        update(&mut app, &[5, 3, 2, 1]);
+        // Lifting a touch of the pair pairs the two remaining touches that were pressed first.
+        app.world.send_event(InputPress::new_up(
+            PointerId::Touch(5),
+            PointerButton::Primary,
+        ));
+        update(&mut app, &[3, 2, 1]);
+        let location = simulated_location(Vec2::new(40.0, 0.0));
+        app.world.send_event(InputMove::new(
+            PointerId::Touch(2),
+            location,
+            Vec2::new(20.0, 0.0),
+        ));
+        update(&mut app, &[3, 2, 1]);
+
+        let pinches = sent_pointer_events::<Pinch>(&app);
+        assert_eq!(pinches.len(), 1);
+        assert_eq!(
+            pinches[0].pointers,
+            [PointerId::Touch(3), PointerId::Touch(2)]
+        );
+    }
+
+    /// The entities whose [`On<Pointer<Over>>`](On) listeners ran, in order.
+    #[derive(Resource, Default)]
+    struct Listened(Vec<Entity>);
+
+    fn listen_over(listener: Listener<Pointer<Over>>, mut listened: ResMut<Listened>) {
+        listened.0.push(listener.listener());
+    }
+
+    #[test]
+    fn disabled_event_types_do_not_bubble() {
+        let mut bubbling = PointerBubbling::default();
+        bubbling.disable::<Over>();
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(bubbling)
+            .init_resource::<Listened>();
+        let parent = app.world.spawn(On::<Pointer<Over>>::run(listen_over)).id();
+        let item = app
+            .world
+            .spawn(On::<Pointer<Over>>::run(listen_over))
+            .set_parent(parent)
+            .id();
+
+        simulate_hover(&mut app, PointerId::Mouse, item);
+        assert_eq!(app.world.resource::<Listened>().0, [item]);
+        assert_eq!(app.world.get::<Parent>(item).map(Parent::get), Some(parent));
+    }
+
+    #[test]
+    fn events_bubble_through_shared_ancestors_every_frame() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .init_resource::<Listened>();
+        let root = app.world.spawn(On::<Pointer<Over>>::run(listen_over)).id();
+        let middle = app.world.spawn_empty().set_parent(root).id();
+        let first = app
+            .world
+            .spawn(On::<Pointer<Over>>::run(listen_over))
+            .set_parent(middle)
+            .id();
+        let second = app.world.spawn_empty().set_parent(middle).id();
+
+        simulate_hover(&mut app, PointerId::Mouse, first);
+        simulate_hover(&mut app, PointerId::Mouse, second);
+        assert_eq!(app.world.resource::<Listened>().0, [first, root, root]);
+    }
+
+    #[test]
+    fn bubbling_stops_at_boundaries() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .init_resource::<Listened>();
+        let root = app.world.spawn(On::<Pointer<Over>>::run(listen_over)).id();
+        let boundary = app
+            .world
+            .spawn((On::<Pointer<Over>>::run(listen_over), BubbleBoundary))
+            .set_parent(root)
+            .id();
+        let item = app
+            .world
+            .spawn(On::<Pointer<Over>>::run(listen_over))
+            .set_parent(boundary)
+            .id();
+
+        simulate_hover(&mut app, PointerId::Mouse, item);
+        assert_eq!(app.world.resource::<Listened>().0, [item, boundary]);
+        assert_eq!(
+            app.world.get::<Parent>(boundary).map(Parent::get),
+            Some(root)
+        );
+    }
+
+    #[test]
+    fn dispatching_never_changes_the_hierarchy() {
+        let mut bubbling = PointerBubbling::default();
+        bubbling.disable::<Out>();
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(bubbling)
+            .init_resource::<Listened>();
+        let root = app.world.spawn(On::<Pointer<Over>>::run(listen_over)).id();
+        let boundary = app
+            .world
+            .spawn((On::<Pointer<Over>>::run(listen_over), BubbleBoundary))
+            .set_parent(root)
+            .id();
+        let item = app
+            .world
+            .spawn((
+                On::<Pointer<Over>>::run(listen_over),
+                On::<Pointer<Out>>::run(|| {}),
+            ))
+            .set_parent(boundary)
+            .id();
+        let changed = |app: &App| {
+            [boundary, item].map(|entity| {
+                let parent = app.world.entity(entity).get_ref::<Parent>().unwrap();
+                (parent.get(), parent.last_changed())
+            })
+        };
+        let before = changed(&app);
+
+        simulate_hover(&mut app, PointerId::Mouse, item);
+        simulate_hover(&mut app, PointerId::Mouse, root);
+        assert_eq!(sent_pointer_events::<Out>(&app).len(), 1);
+        assert_eq!(app.world.resource::<Listened>().0, [item, boundary, root]);
+        assert_eq!(changed(&app), before);
+    }
+
+    /// A tilemap entity with one tile on either side of `x = 0`, for a stub backend.
+    #[derive(Resource)]
+    struct Tilemap(Entity);
+
+    fn pick_tiles(
+        tilemap: Res<Tilemap>,
+        pointers: Query<(&PointerId, &PointerLocation)>,
+        mut output: EventWriter<PointerHits>,
+    ) {
+        for (pointer_id, pointer) in &pointers {
+            let Some(location) = pointer.location() else {
+                continue;
+            };
+            let tile = u64::from(location.position.x >= 0.0);
+            let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None).with_part(Some(tile));
+            output.send(PointerHits::new(*pointer_id, vec![(tilemap.0, hit)], 0.0));
+        }
+    }
+
+    #[test]
+    fn moving_between_parts_only_sends_moves() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .add_systems(PreUpdate, pick_tiles.in_set(PickSet::Backend));
+        let tilemap = app.world.spawn_empty().id();
+        app.world.insert_resource(Tilemap(tilemap));
+        let move_to = |app: &mut App, x| {
+            simulate_pointer_move(app, PointerId::Mouse, simulated_location(Vec2::new(x, 0.0)));
+            let moves = sent_pointer_events::<Move>(app);
+            assert_eq!(moves.len(), 1);
+            assert_eq!(moves[0].target, tilemap);
+            moves[0].hit.part
+        };
+
+        assert_eq!(move_to(&mut app, -5.0), Some(0));
+        assert_eq!(sent_pointer_events::<Over>(&app).len(), 1);
+
+        assert_eq!(move_to(&mut app, 5.0), Some(1));
+        assert!(sent_pointer_events::<Over>(&app).is_empty());
+        assert!(sent_pointer_events::<Out>(&app).is_empty());
+        let hovered = &app.world.resource::<HoverMap>()[&PointerId::Mouse];
+        assert_eq!(hovered[&tilemap].part, Some(1));
+    }
+}
@@ -1,31 +1,256 @@
 //! Determines which entities are being hovered by which pointers.
 
-use std::{collections::BTreeMap, fmt::Debug};
+use std::{cmp::Reverse, fmt::Debug};
 
 use crate::{
-    backend::{self, HitData},
+    backend::{
+        self, ray::RayMap, BackendDepthBias, BackendId, BackendPriority, HitData, LateHitSettings,
+        PointerHits,
+    },
     events::PointerCancel,
-    pointer::{PointerId, PointerInteraction, PointerPress},
-    Pickable,
+    pointer::{
+        Location, PointerCapture, PointerDisabled, PointerGrab, PointerId, PointerInteraction,
+        PointerLocation, PointerLocationHistory, PointerPress, PointerRadius, RebaselinedPointers,
+        SmoothedHitTestPosition,
+    },
+    PickLayers, PickTarget, Pickable, PickableEvents, PickingShape, PointerCoreBundle,
 };
 
+use bevy_core::FrameCount;
 use bevy_derive::{Deref, DerefMut};
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    entity::{EntityHashMap, EntityHashSet},
+    prelude::*,
+    system::SystemParam,
+};
 use bevy_reflect::prelude::*;
+use bevy_render::camera::{OrthographicProjection, PerspectiveProjection, Projection};
+use bevy_transform::components::GlobalTransform;
 use bevy_utils::{FloatOrd, HashMap};
 
-type DepthSortedHits = Vec<(Entity, HitData)>;
-
+/// Sorts hits from the highest [`BackendPriority`] to the lowest, then from the highest order to
+/// the lowest, then from the nearest depth to the farthest, including the [`BackendDepthBias`], then
+/// in the order they were reported in. The last element is the index of the hit.
+///
 /// Events returned from backends can be grouped with an order field. This allows picking to work
 /// with multiple layers of rendered output to the same render target.
-type PickLayer = FloatOrd;
+type SortKey = (Reverse<i32>, Reverse<FloatOrd>, FloatOrd, usize);
+
+/// The hits under a single pointer. Hits are sorted by a separate buffer of [`SortKey`]s, so the
+/// hits themselves are never moved.
+#[derive(Debug, Default, Clone)]
+struct SortedHits {
+    /// The hits, in the order they were reported in.
+    hits: Vec<(Entity, HitData)>,
+    /// The key of each hit, sorted by [`Self::sort`].
+    keys: Vec<SortKey>,
+    /// The [`BackendDepthBias`] of each hit, in the order they were reported in.
+    biases: Vec<f32>,
+}
+
+impl SortedHits {
+    fn clear(&mut self) {
+        self.hits.clear();
+        self.keys.clear();
+        self.biases.clear();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.hits.is_empty()
+    }
+
+    fn push(&mut self, priority: i32, order: f32, bias: f32, entity: Entity, hit: HitData) {
+        let index = self.hits.len();
+        self.keys.push((
+            Reverse(priority),
+            Reverse(FloatOrd(order)),
+            FloatOrd(hit.depth),
+            index,
+        ));
+        self.hits.push((entity, hit));
+        self.biases.push(bias);
+    }
+
+    /// Sort hits by order, then by depth, as computed by `depth` and biased by the backend. This
+    /// sorts in place, without allocating.
+    fn sort(&mut self, depth: impl Fn(&HitData) -> f32) {
+        for (_, _, hit_depth, index) in self.keys.iter_mut() {
+            *hit_depth = FloatOrd(depth(&self.hits[*index].1) + self.biases[*index]);
+        }
+        // Every key is unique, because of the index, so an unstable sort gives the same result as
+        // a stable one.
+        self.keys.sort_unstable();
+    }
+
+    /// Moves a hit that `was_hovered` to the top, if the top hit was not hovered, and the other
+    /// hit has the same priority and order, and is at most `epsilon` farther away. Must be called
+    /// after [`Self::sort`].
+    fn keep_hovered(&mut self, epsilon: f32, was_hovered: impl Fn(Entity) -> bool) {
+        let Some(&(priority, order, FloatOrd(depth), index)) = self.keys.first() else {
+            return;
+        };
+        if epsilon <= 0.0 || was_hovered(self.hits[index].0) {
+            return;
+        }
+        let kept = self
+            .keys
+            .iter()
+            .skip(1)
+            .take_while(|(p, o, FloatOrd(d), _)| {
+                *p == priority && *o == order && *d - depth <= epsilon
+            })
+            .position(|(.., index)| was_hovered(self.hits[*index].0));
+        if let Some(kept) = kept {
+            self.keys[..=kept + 1].rotate_right(1);
+        }
+    }
+
+    /// Iterates over the hits in sorted order.
+    fn iter(&self) -> impl Iterator<Item = &(Entity, HitData)> {
+        self.keys.iter().map(|(.., index)| &self.hits[*index])
+    }
+}
+
+/// Maps Pointers to their [`SortedHits`]. Note this is much more complex than the [`HoverMap`]
+/// because this data structure is used to sort entities by layer then depth for every pointer.
+type OverMap = HashMap<PointerId, SortedHits>;
+
+/// Scratch buffers used by [`update_focus`]. They are cleared and reused every frame, instead of
+/// being reallocated, so the cost of computing focus does not grow with allocations in scenes with
+/// many cameras, pointers, and hits.
+#[derive(Debug, Default, Resource)]
+pub struct FocusBuffers {
+    over_map: OverMap,
+    active_pointers: Vec<PointerId>,
+    cancelled_pointers: Vec<PointerId>,
+    pointer_layers: HashMap<PointerId, PickLayers>,
+    claimed_groups: Vec<HoverGroup>,
+    listed_entities: EntityHashSet,
+}
 
-/// Maps [`PickLayer`]s to the map of entities within that pick layer, sorted by depth.
-type LayerMap = BTreeMap<PickLayer, DepthSortedHits>;
+/// The last valid late [`PointerHits`] of each backend for each pointer, used in place of stale
+/// hits. See [`LateHitSettings`].
+#[derive(Debug, Default, Resource)]
+pub struct LateHits {
+    valid: HashMap<(PointerId, Option<BackendId>), PointerHits>,
+}
+
+impl LateHits {
+    /// Returns the hits to use for `hits`: the hits themselves if they are valid, or the last valid
+    /// hits of the same backend and pointer if they are stale.
+    fn resolve<'a>(
+        &'a mut self,
+        hits: &'a PointerHits,
+        is_valid: impl Fn(&PointerHits) -> bool,
+    ) -> Option<&'a PointerHits> {
+        if hits.frame.is_none() {
+            return Some(hits);
+        }
+        let key = (hits.pointer, hits.backend);
+        if is_valid(hits) {
+            self.valid.insert(key, hits.clone());
+            Some(hits)
+        } else {
+            self.valid.get(&key)
+        }
+    }
+}
 
-/// Maps Pointers to a [`LayerMap`]. Note this is much more complex than the [`HoverMap`] because
-/// this data structure is used to sort entities by layer then depth for every pointer.
-type OverMap = HashMap<PointerId, LayerMap>;
+/// Keeps the entity that a pointer is hovering on top of entities that are hit at nearly the same
+/// depth, so hover does not flicker between coplanar entities from frame to frame, for example a
+/// sprite drawn exactly on a mesh.
+///
+/// When the nearest hit under a pointer was not hovered on the previous frame, but a hit with the
+/// same [`BackendPriority`] and order that is at most `depth_epsilon` farther away was, the
+/// previously hovered entity stays on top. Depths are compared after the [`DepthComparison`] and
+/// [`BackendDepthBias`] are applied.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct FocusHysteresis {
+    /// How much farther than the nearest hit a hovered entity can be and stay on top. Defaults to
+    /// `0.0`, which disables hysteresis.
+    pub depth_epsilon: f32,
+}
+
+/// Debugging aids for focus, which override the settings of every entity.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct FocusDebugSettings {
+    /// Treats every entity as if its [`Pickable::should_block_lower`] was `false`, whatever its
+    /// [`Pickable`] or [`FocusPolicy`](crate::FocusPolicy), so every entity hit under a pointer is
+    /// hovered, down to the farthest. Entities that are not
+    /// [`is_hoverable`](Pickable::is_hoverable) are still not hovered. The included backends report
+    /// every hit while this is set, instead of exiting early at blocking entities. Defaults to
+    /// `false`.
+    pub ignore_blocking: bool,
+}
+
+impl FocusDebugSettings {
+    /// Returns the `pickable` settings with the overrides of these settings applied.
+    pub fn apply(&self, pickable: Pickable) -> Pickable {
+        Pickable {
+            should_block_lower: pickable.should_block_lower && !self.ignore_blocking,
+            ..pickable
+        }
+    }
+}
+
+/// Controls how the depths of hits that share a pick layer are compared. Hits are always grouped
+/// by the order reported by the backend, which is the camera order for the included backends, so
+/// hits from a higher order camera are always above hits from a lower order camera, unless their
+/// [`BackendPriority`] differs. Depth is only used to sort hits within the same order.
+///
+/// This only matters when multiple cameras with the same order, but different near and far planes,
+/// render to the same target. Their raw depths are measured in different spaces, so comparing them
+/// directly can pick the wrong entity as the nearest.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub enum DepthComparison {
+    /// Compare the depths reported by backends directly.
+    #[default]
+    Raw,
+    /// Divide the depth of each hit by the distance between the near and far planes of the hit's
+    /// camera, before comparing. Hits from cameras without a [`Projection`],
+    /// [`PerspectiveProjection`], or [`OrthographicProjection`] use their raw depth.
+    Normalized,
+}
+
+impl DepthComparison {
+    /// Returns the depth used to sort `hit`, given the depth range of the hit's camera.
+    fn depth(self, hit: &HitData, depth_range: impl Fn(Entity) -> Option<f32>) -> f32 {
+        match self {
+            DepthComparison::Raw => hit.depth,
+            DepthComparison::Normalized => depth_range(hit.camera)
+                .filter(|range| *range > 0.0)
+                .map_or(hit.depth, |range| hit.depth / range),
+        }
+    }
+}
+
+/// Returns the distance between the near and far planes of a camera's projection, if it has one.
+fn depth_range(
+    projection: Option<&Projection>,
+    perspective: Option<&PerspectiveProjection>,
+    orthographic: Option<&OrthographicProjection>,
+) -> Option<f32> {
+    let (near, far) = match (projection, perspective, orthographic) {
+        (Some(Projection::Perspective(p)), ..) | (None, Some(p), _) => (p.near, p.far),
+        (Some(Projection::Orthographic(o)), ..) | (None, None, Some(o)) => (o.near, o.far),
+        (None, None, None) => return None,
+    };
+    Some(far - near)
+}
+
+/// The projections of cameras, used to apply [`DepthComparison::Normalized`].
+type CameraProjections<'w, 's> = Query<
+    'w,
+    's,
+    AnyOf<(
+        &'static Projection,
+        &'static PerspectiveProjection,
+        &'static OrthographicProjection,
+    )>,
+>;
 
 /// The source of truth for all hover state. This is used to determine what events to send, and what
 /// state components should be in.
@@ -39,7 +264,7 @@ type OverMap = HashMap<PointerId, LayerMap>;
 ///
 /// For example, if a pointer is hitting a UI button and a 3d mesh, but the button is in front of
 /// the mesh, and [`Pickable::should_block_lower`], the UI button will be hovered, but the mesh will
-/// not.
+/// not. Both are listed in the [`AllHitsMap`].
 ///
 /// # Advanced Users
 ///
@@ -49,34 +274,540 @@ type OverMap = HashMap<PointerId, LayerMap>;
 /// [`PreviousHoverMap`] as a robust way of determining changes in hover state from the previous
 /// update.
 #[derive(Debug, Deref, DerefMut, Default, Resource)]
-pub struct HoverMap(pub HashMap<PointerId, HashMap<Entity, HitData>>);
+pub struct HoverMap(pub HashMap<PointerId, EntityHashMap<HitData>>);
+
+impl HoverMap {
+    /// Returns the pointers hovering `entity`, with the hit data of each pointer on it.
+    pub fn pointers_over(&self, entity: Entity) -> impl Iterator<Item = (PointerId, &HitData)> {
+        self.iter()
+            .filter_map(move |(pointer_id, hovered)| Some((*pointer_id, hovered.get(&entity)?)))
+    }
+
+    /// Returns the entities hovered by `pointer_id`, with the hit data of the pointer on each.
+    pub fn entities_under(
+        &self,
+        pointer_id: PointerId,
+    ) -> impl Iterator<Item = (Entity, &HitData)> {
+        self.get(&pointer_id)
+            .into_iter()
+            .flatten()
+            .map(|(entity, hit)| (*entity, hit))
+    }
+
+    /// Returns `true` if any pointer is hovering `entity`.
+    pub fn is_hovered(&self, entity: Entity) -> bool {
+        self.pointers_over(entity).next().is_some()
+    }
+}
+
+/// Every entity under each pointer, sorted from the nearest to the farthest, regardless of whether
+/// an entity nearer to the pointer blocks it, or whether it is hoverable. Unlike the [`HoverMap`],
+/// this ignores [`Pickable`], so tools that need to see through entities, such as an "x-ray"
+/// picker, don't need every entity to disable [`Pickable::should_block_lower`].
+///
+/// Hits are still filtered by [`PickLayers`], and reported on the entities they are redirected to
+/// by a [`PickTarget`]. An entity hit more than once is only listed once, with its nearest hit. This
+/// is updated by [`update_focus`], before blocking is applied, so it is neither captured with a
+/// [`PointerCapture`] nor cleared for pointers that are grabbed, disabled, or suppressed by the
+/// [`ExclusivePointer`].
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct AllHitsMap(pub HashMap<PointerId, Vec<(Entity, HitData)>>);
+
+impl AllHitsMap {
+    /// Returns every entity under `pointer_id`, from the nearest to the farthest.
+    pub fn hits_under(&self, pointer_id: PointerId) -> &[(Entity, HitData)] {
+        self.get(&pointer_id).map_or(&[], Vec::as_slice)
+    }
+}
 
 /// The previous state of the hover map, used to track changes to hover state.
 #[derive(Debug, Deref, DerefMut, Default, Resource)]
-pub struct PreviousHoverMap(pub HashMap<PointerId, HashMap<Entity, HitData>>);
+pub struct PreviousHoverMap(pub HashMap<PointerId, EntityHashMap<HitData>>);
+
+/// Returns the entities `pointer_id` started hovering this update, with the hit data of the pointer
+/// on each. These are the entities that receive an [`Over`](crate::events::Over) event.
+pub fn hover_entered<'a>(
+    hover_map: &'a HoverMap,
+    previous_hover_map: &'a PreviousHoverMap,
+    pointer_id: PointerId,
+) -> impl Iterator<Item = (Entity, &'a HitData)> + 'a {
+    hover_difference(&hover_map.0, &previous_hover_map.0, pointer_id)
+}
+
+/// Returns the entities `pointer_id` stopped hovering this update, with the hit data of the pointer
+/// on each from the previous update. These are the entities that receive an
+/// [`Out`](crate::events::Out) event.
+pub fn hover_left<'a>(
+    hover_map: &'a HoverMap,
+    previous_hover_map: &'a PreviousHoverMap,
+    pointer_id: PointerId,
+) -> impl Iterator<Item = (Entity, &'a HitData)> + 'a {
+    hover_difference(&previous_hover_map.0, &hover_map.0, pointer_id)
+}
+
+/// Returns the entities `pointer_id` started and stopped hovering this update, as
+/// `(entered, left)`. See [`hover_entered`] and [`hover_left`] to iterate over them without
+/// allocating.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_picking_core::{focus::*, pointer::PointerId};
+/// # use bevy_utils::tracing::info;
+/// fn log_hover_changes(hover_map: Res<HoverMap>, previous_hover_map: Res<PreviousHoverMap>) {
+///     let (entered, left) = hover_changes(&hover_map, &previous_hover_map, PointerId::Mouse);
+///     for entity in entered {
+///         info!("The mouse entered {entity:?}");
+///     }
+///     for entity in left {
+///         info!("The mouse left {entity:?}");
+///     }
+/// }
+/// ```
+pub fn hover_changes(
+    hover_map: &HoverMap,
+    previous_hover_map: &PreviousHoverMap,
+    pointer_id: PointerId,
+) -> (Vec<Entity>, Vec<Entity>) {
+    let entered = hover_entered(hover_map, previous_hover_map, pointer_id)
+        .map(|(entity, _)| entity)
+        .collect();
+    let left = hover_left(hover_map, previous_hover_map, pointer_id)
+        .map(|(entity, _)| entity)
+        .collect();
+    (entered, left)
+}
+
+/// Returns the entities hovered by `pointer_id` in `hovered`, but not in `other`.
+fn hover_difference<'a>(
+    hovered: &'a HashMap<PointerId, EntityHashMap<HitData>>,
+    other: &'a HashMap<PointerId, EntityHashMap<HitData>>,
+    pointer_id: PointerId,
+) -> impl Iterator<Item = (Entity, &'a HitData)> + 'a {
+    let other = other.get(&pointer_id);
+    hovered
+        .get(&pointer_id)
+        .into_iter()
+        .flatten()
+        .filter(move |(entity, _)| other.map_or(true, |other| !other.contains_key(*entity)))
+        .map(|(entity, hit)| (*entity, hit))
+}
+
+/// Hover state resolved separately for each kind of event that can be disabled with
+/// [`PickableEvents`]. An entity that opts out of a kind is neither hovered nor blocks lower
+/// entities for that kind, so for example, scrolling can fall through a clickable button to the
+/// container behind it.
+///
+/// When no entity has a [`PickableEvents`] component, every map matches the [`HoverMap`].
+#[derive(Debug, Default, Clone, Resource)]
+pub struct EventHoverMaps {
+    /// The entities that can be clicked by each pointer.
+    pub click: HashMap<PointerId, EntityHashMap<HitData>>,
+    /// The entities that can be dragged by each pointer.
+    pub drag: HashMap<PointerId, EntityHashMap<HitData>>,
+    /// The entities that can be scrolled by each pointer.
+    pub scroll: HashMap<PointerId, EntityHashMap<HitData>>,
+}
+
+impl EventHoverMaps {
+    /// Returns the entities that receive `Down` and `Up` events from `pointer_id`, which are those
+    /// that can be either clicked or dragged.
+    pub fn pressable(&self, pointer_id: PointerId) -> impl Iterator<Item = (Entity, &HitData)> {
+        let click = self.click.get(&pointer_id);
+        let drag = self.drag.get(&pointer_id);
+        click.into_iter().flatten().map(|(e, hit)| (*e, hit)).chain(
+            drag.into_iter()
+                .flatten()
+                .filter(move |(e, _)| !click.is_some_and(|click| click.contains_key(*e)))
+                .map(|(e, hit)| (*e, hit)),
+        )
+    }
+}
+
+/// The previous state of the [`EventHoverMaps`].
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct PreviousEventHoverMaps(pub EventHoverMaps);
+
+/// Makes an entity part of a hover-exclusive group.
+///
+/// Within a group, a pointer can only hover a single member at a time: the topmost member, as
+/// determined by the same layer and depth ordering used for blocking. All other members of the
+/// group under that pointer are left out of the [`HoverMap`], and will receive `Out` events if they
+/// were previously hovered. This is useful for radio buttons or tab bars whose geometry overlaps,
+/// without restricting the rest of the scene to a single hovered entity.
+///
+/// Groups are scoped per pointer, so two pointers can hover two different members of the same
+/// group. Membership does not change blocking; a suppressed member still blocks lower entities
+/// according to its [`Pickable`] settings.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct HoverGroup(pub u32);
+
+/// Gives a single pointer exclusive focus, like a modal for pointers. While set, all other pointers
+/// are ignored by focus until it is cleared, at which point they resume hovering normally.
+///
+/// Suppressed pointers hover nothing. When the exclusive pointer is set, the entities they were
+/// hovering receive `Out` events and have their [`PickingInteraction`] updated, and suppressed
+/// pointers cannot send `Down`, `Up`, `Click`, or `Move` events until it is cleared. Drags that a suppressed pointer had already started are not
+/// cancelled, and end as usual when its button is released.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct ExclusivePointer {
+    /// The pointer with exclusive focus, or `None` if all pointers can interact.
+    pub pointer_id: Option<PointerId>,
+}
+
+impl ExclusivePointer {
+    /// Gives `pointer_id` exclusive focus, suppressing all other pointers.
+    pub fn set(&mut self, pointer_id: PointerId) {
+        self.pointer_id = Some(pointer_id);
+    }
+
+    /// Clears exclusive focus, so all pointers can interact again.
+    pub fn clear(&mut self) {
+        self.pointer_id = None;
+    }
+
+    /// Returns `true` if `pointer_id` is allowed to interact.
+    pub fn allows(&self, pointer_id: PointerId) -> bool {
+        self.pointer_id
+            .map_or(true, |exclusive| exclusive == pointer_id)
+    }
+}
+
+/// The components and resources that decide which hits and pointers [`update_focus`] uses.
+#[derive(SystemParam)]
+pub struct FocusFilters<'w, 's> {
+    pickable: Query<'w, 's, &'static Pickable>,
+    pickable_events: Query<'w, 's, &'static PickableEvents>,
+    hover_groups: Query<'w, 's, &'static HoverGroup>,
+    entity_layers: Query<'w, 's, &'static PickLayers>,
+    pointer_layers: Query<'w, 's, (&'static PointerId, &'static PickLayers)>,
+    pick_targets: Query<'w, 's, &'static PickTarget>,
+    shapes: Query<'w, 's, (&'static PickingShape, Option<&'static GlobalTransform>)>,
+    focus_debug: Res<'w, FocusDebugSettings>,
+    exclusive_pointer: Res<'w, ExclusivePointer>,
+    /// The pointers that hover nothing, because they are grabbed or disabled.
+    suppressed: Query<'w, 's, &'static PointerId, Or<(With<PointerGrab>, With<PointerDisabled>)>>,
+}
+
+/// The state used by [`update_focus`] to accept hits that backends report late. See
+/// [`LateHitSettings`].
+#[derive(SystemParam)]
+pub struct LateHitTracking<'w> {
+    settings: Res<'w, LateHitSettings>,
+    location_history: Res<'w, PointerLocationHistory>,
+    frame_count: Option<Res<'w, FrameCount>>,
+    late_hits: ResMut<'w, LateHits>,
+}
+
+/// The settings used by [`update_focus`] to sort the hits under each pointer.
+#[derive(SystemParam)]
+pub struct FocusSorting<'w, 's> {
+    depth_comparison: Res<'w, DepthComparison>,
+    hysteresis: Res<'w, FocusHysteresis>,
+    backend_priority: Res<'w, BackendPriority>,
+    depth_bias: Res<'w, BackendDepthBias>,
+    projections: CameraProjections<'w, 's>,
+}
+
+/// The hover maps written by [`update_focus`].
+#[derive(SystemParam)]
+pub struct FocusMaps<'w> {
+    hover_map: ResMut<'w, HoverMap>,
+    all_hits: ResMut<'w, AllHitsMap>,
+    event_hover_maps: ResMut<'w, EventHoverMaps>,
+    previous_hover_map: ResMut<'w, PreviousHoverMap>,
+    previous_event_hover_maps: ResMut<'w, PreviousEventHoverMaps>,
+}
 
 /// Coalesces all data from inputs and backends to generate a map of the currently hovered entities.
 /// This is the final focusing step to determine which entity the pointer is hovering over.
+///
+/// To keep hover from flickering between overlapping entities at nearly the same depth, such as in
+/// dense UI, set a [`FocusHysteresis`] margin.
+///
+/// In large scenes, most frames have no hits under any pointer. When no pointer had hits on the
+/// last frames either, rebuilding the maps would leave them unchanged, so this returns early
+/// without touching them, and they are not marked as changed.
 pub fn update_focus(
     // Inputs
-    pickable: Query<&Pickable>,
-    pointers: Query<&PointerId>,
+    pointers: Query<(&PointerId, Option<&PointerRadius>)>,
     mut under_pointer: EventReader<backend::PointerHits>,
     mut cancellations: EventReader<PointerCancel>,
-    // Local
-    mut over_map: Local<OverMap>,
+    rebaselined: Res<RebaselinedPointers>,
+    filters: FocusFilters,
+    late_hits: LateHitTracking,
+    sorting: FocusSorting,
     // Output
-    mut hover_map: ResMut<HoverMap>,
-    mut previous_hover_map: ResMut<PreviousHoverMap>,
+    mut buffers: ResMut<FocusBuffers>,
+    maps: FocusMaps,
 ) {
-    reset_maps(
-        &mut hover_map,
-        &mut previous_hover_map,
-        &mut over_map,
+    let FocusFilters {
+        pickable,
+        pickable_events,
+        hover_groups,
+        entity_layers,
+        pointer_layers,
+        pick_targets,
+        shapes,
+        focus_debug,
+        exclusive_pointer,
+        suppressed,
+    } = filters;
+    let LateHitTracking {
+        settings: late_hit_settings,
+        location_history,
+        frame_count,
+        mut late_hits,
+    } = late_hits;
+    let FocusSorting {
+        depth_comparison,
+        hysteresis,
+        backend_priority,
+        depth_bias,
+        projections,
+    } = sorting;
+    let FocusMaps {
+        mut hover_map,
+        mut all_hits,
+        mut event_hover_maps,
+        mut previous_hover_map,
+        mut previous_event_hover_maps,
+    } = maps;
+    let FocusBuffers {
+        over_map,
+        active_pointers,
+        cancelled_pointers,
+        pointer_layers: layers_of_pointers,
+        claimed_groups,
+        listed_entities,
+    } = &mut *buffers;
+    active_pointers.clear();
+    active_pointers.extend(pointers.iter().map(|(pointer_id, _)| *pointer_id));
+    // When no pointer had hits on the last two frames, the maps are already in the state they
+    // would be reset to, so they are left untouched.
+    let was_idle = rebaselined.iter().next().is_none()
+        && is_idle(
+            active_pointers,
+            (&hover_map, &previous_hover_map),
+            (&event_hover_maps, &previous_event_hover_maps),
+            &all_hits,
+        );
+    if was_idle {
+        over_map.retain(|pointer, _| active_pointers.contains(pointer));
+        over_map.values_mut().for_each(SortedHits::clear);
+    } else {
+        core::mem::swap(&mut previous_event_hover_maps.0, &mut event_hover_maps);
+        reset_maps(
+            &mut hover_map,
+            &mut previous_hover_map,
+            over_map,
+            active_pointers,
+        );
+    }
+    layers_of_pointers.clear();
+    layers_of_pointers.extend(
+        pointer_layers
+            .iter()
+            .map(|(pointer_id, layers)| (*pointer_id, *layers)),
+    );
+    late_hits
+        .valid
+        .retain(|(pointer_id, _), _| active_pointers.contains(pointer_id));
+    let frame = frame_count.map(|frame_count| frame_count.0);
+    build_over_map(
+        &mut under_pointer,
+        over_map,
+        &mut cancellations,
+        cancelled_pointers,
+        (&mut late_hits, |hits: &PointerHits| {
+            late_hit_settings.is_valid(hits, &location_history, frame)
+        }),
+        (&backend_priority, &depth_bias),
+        |pointer_id, entity, hit| {
+            let (shape, transform) = shapes.get(entity).ok().unzip();
+            if !PickingShape::accepts(shape, transform.flatten(), hit) {
+                return None;
+            }
+            let entity = PickTarget::retarget(
+                entity,
+                pick_targets.get(entity).ok(),
+                pickable.get(entity).ok(),
+            )?;
+            let pointer_layers = layers_of_pointers
+                .get(&pointer_id)
+                .copied()
+                .unwrap_or_default();
+            let entity_layers = entity_layers.get(entity).copied().unwrap_or_default();
+            pointer_layers.intersects(&entity_layers).then_some(entity)
+        },
+    );
+    if was_idle && over_map.values().all(SortedHits::is_empty) {
+        return; // the maps would be rebuilt with the same empty state
+    }
+    for (pointer_id, hits) in over_map.iter_mut() {
+        hits.sort(|hit| {
+            depth_comparison.depth(hit, |camera| {
+                let (projection, perspective, orthographic) = projections.get(camera).ok()?;
+                depth_range(projection, perspective, orthographic)
+            })
+        });
+        if let Some(previous) = previous_hover_map.get(pointer_id) {
+            hits.keep_hovered(hysteresis.depth_epsilon, |entity| {
+                previous.contains_key(&entity)
+            });
+        }
+    }
+    update_all_hits(over_map, active_pointers, listed_entities, &mut all_hits);
+    let pickable_for = |enabled: fn(&PickableEvents) -> bool| {
+        let (pickable, pickable_events, focus_debug) = (&pickable, &pickable_events, &focus_debug);
+        move |entity| match pickable_events.get(entity) {
+            Ok(events) if !enabled(events) => Pickable::IGNORE,
+            _ => focus_debug.apply(pickable.get(entity).cloned().unwrap_or_default()),
+        }
+    };
+    build_hover_map(
         &pointers,
+        pickable_for(|events| events.hover),
+        &hover_groups,
+        over_map,
+        claimed_groups,
+        &mut hover_map,
     );
-    build_over_map(&mut under_pointer, &mut over_map, &mut cancellations);
-    build_hover_map(&pointers, pickable, &over_map, &mut hover_map);
+    if pickable_events.is_empty() {
+        copy_hover_map(&hover_map, &mut event_hover_maps.click);
+        copy_hover_map(&hover_map, &mut event_hover_maps.drag);
+        copy_hover_map(&hover_map, &mut event_hover_maps.scroll);
+    } else {
+        let EventHoverMaps {
+            click,
+            drag,
+            scroll,
+        } = &mut *event_hover_maps;
+        let kinds: [(fn(&PickableEvents) -> bool, _); 3] = [
+            (|events| events.click, click),
+            (|events| events.drag, drag),
+            (|events| events.scroll, scroll),
+        ];
+        for (enabled, map) in kinds {
+            map.retain(|pointer, _| active_pointers.contains(pointer));
+            for hovered in map.values_mut() {
+                hovered.clear();
+            }
+            build_hover_map(
+                &pointers,
+                pickable_for(enabled),
+                &hover_groups,
+                over_map,
+                claimed_groups,
+                map,
+            );
+        }
+    }
+    hold_rebaselined_hovers(&rebaselined, &previous_hover_map, &mut hover_map);
+    let EventHoverMaps {
+        click,
+        drag,
+        scroll,
+    } = &mut *event_hover_maps;
+    for map in [&mut hover_map.0, click, drag, scroll] {
+        for (pointer_id, hovered) in map.iter_mut() {
+            if !exclusive_pointer.allows(*pointer_id)
+                || suppressed.iter().any(|id| id == pointer_id)
+            {
+                hovered.clear();
+            }
+        }
+    }
+}
+
+/// Returns `true` if every map holds an empty entry for each of the `active_pointers`, and no other
+/// entries. This is the state [`update_focus`] leaves the maps in when no pointer has hits for two
+/// frames, so it can skip resetting and rebuilding them while they are in that state.
+fn is_idle(
+    active_pointers: &[PointerId],
+    (hover_map, previous_hover_map): (&HoverMap, &PreviousHoverMap),
+    (event_hover_maps, previous_event_hover_maps): (&EventHoverMaps, &PreviousEventHoverMaps),
+    all_hits: &AllHitsMap,
+) -> bool {
+    fn is_empty<T>(
+        map: &HashMap<PointerId, T>,
+        active_pointers: &[PointerId],
+        is_empty: impl Fn(&T) -> bool,
+    ) -> bool {
+        map.len() == active_pointers.len()
+            && active_pointers
+                .iter()
+                .all(|pointer_id| map.get(pointer_id).is_some_and(&is_empty))
+    }
+    let hover_maps = [
+        &hover_map.0,
+        &previous_hover_map.0,
+        &event_hover_maps.click,
+        &event_hover_maps.drag,
+        &event_hover_maps.scroll,
+        &previous_event_hover_maps.click,
+        &previous_event_hover_maps.drag,
+        &previous_event_hover_maps.scroll,
+    ];
+    hover_maps
+        .into_iter()
+        .all(|map| is_empty(map, active_pointers, EntityHashMap::is_empty))
+        && is_empty(all_hits, active_pointers, Vec::is_empty)
+}
+
+/// Copies the sorted hits of every pointer to the [`AllHitsMap`], reusing the memory allocated for
+/// each pointer. Hits are already sorted, so only the first, nearest, hit of each entity is kept,
+/// using `listed_entities` to remember the entities that have been copied.
+fn update_all_hits(
+    over_map: &OverMap,
+    active_pointers: &[PointerId],
+    listed_entities: &mut EntityHashSet,
+    all_hits: &mut AllHitsMap,
+) {
+    all_hits.retain(|pointer, _| active_pointers.contains(pointer));
+    for pointer_id in active_pointers {
+        let hits_under = all_hits.entry(*pointer_id).or_default();
+        hits_under.clear();
+        listed_entities.clear();
+        for (entity, hit) in over_map
+            .get(pointer_id)
+            .into_iter()
+            .flat_map(SortedHits::iter)
+        {
+            if listed_entities.insert(*entity) {
+                hits_under.push((*entity, hit.clone()));
+            }
+        }
+    }
+}
+
+/// Keeps the previous hover state of pointers that were re-baselined this frame, so a change in
+/// window scale factor or resolution does not cause spurious hover changes.
+fn hold_rebaselined_hovers(
+    rebaselined: &RebaselinedPointers,
+    previous_hover_map: &PreviousHoverMap,
+    hover_map: &mut HoverMap,
+) {
+    for pointer_id in rebaselined.iter() {
+        if let Some(previous) = previous_hover_map.get(pointer_id) {
+            hover_map.insert(*pointer_id, previous.clone());
+        }
+    }
+}
+
+/// Copies the hover state in `source` to `destination`, reusing the memory allocated for each
+/// pointer in `destination`.
+fn copy_hover_map(
+    source: &HashMap<PointerId, EntityHashMap<HitData>>,
+    destination: &mut HashMap<PointerId, EntityHashMap<HitData>>,
+) {
+    destination.retain(|pointer, _| source.contains_key(pointer));
+    for (pointer, hovered) in source {
+        let copy = destination.entry(*pointer).or_default();
+        copy.clear();
+        copy.extend(hovered.iter().map(|(entity, hit)| (*entity, hit.clone())));
+    }
 }
 
 /// Clear non-empty local maps, reusing allocated memory.
@@ -84,7 +815,7 @@ fn reset_maps(
     hover_map: &mut HoverMap,
     previous_hover_map: &mut PreviousHoverMap,
     over_map: &mut OverMap,
-    pointers: &Query<&PointerId>,
+    active_pointers: &[PointerId],
 ) {
     // Swap the previous and current hover maps. This results in the previous values being stored in
     // `PreviousHoverMap`. Swapping is okay because we clear the `HoverMap` which now holds stale
@@ -94,12 +825,11 @@ fn reset_maps(
     for entity_set in hover_map.values_mut() {
         entity_set.clear()
     }
-    for layer_map in over_map.values_mut() {
-        layer_map.clear()
+    for hits in over_map.values_mut() {
+        hits.clear()
     }
 
     // Clear pointers from the maps if they have been removed.
-    let active_pointers: Vec<PointerId> = pointers.iter().copied().collect();
     hover_map.retain(|pointer, _| active_pointers.contains(pointer));
     over_map.retain(|pointer, _| active_pointers.contains(pointer));
 }
@@ -107,30 +837,123 @@ fn reset_maps(
 /// Build an ordered map of entities that are under each pointer
 fn build_over_map(
     backend_events: &mut EventReader<backend::PointerHits>,
-    pointer_over_map: &mut Local<OverMap>,
+    pointer_over_map: &mut OverMap,
     pointer_cancel: &mut EventReader<PointerCancel>,
+    cancelled_pointers: &mut Vec<PointerId>,
+    (late_hits, is_valid): (&mut LateHits, impl Fn(&PointerHits) -> bool),
+    backend_settings: (&BackendPriority, &BackendDepthBias),
+    hit_target: impl Fn(PointerId, Entity, &HitData) -> Option<Entity>,
 ) {
-    let cancelled_pointers: Vec<PointerId> = pointer_cancel.read().map(|p| p.pointer_id).collect();
+    cancelled_pointers.clear();
+    cancelled_pointers.extend(pointer_cancel.read().map(|p| p.pointer_id));
+    late_hits
+        .valid
+        .retain(|(pointer_id, _), _| !cancelled_pointers.contains(pointer_id));
 
     for entities_under_pointer in backend_events
         .read()
         .filter(|e| !cancelled_pointers.contains(&e.pointer))
     {
+        let Some(entities_under_pointer) = late_hits.resolve(entities_under_pointer, &is_valid)
+        else {
+            continue;
+        };
         let pointer = entities_under_pointer.pointer;
-        let layer_map = pointer_over_map
-            .entry(pointer)
-            .or_insert_with(BTreeMap::new);
-        for (entity, pick_data) in entities_under_pointer.picks.iter() {
-            let layer = entities_under_pointer.order;
-            let hits = layer_map.entry(FloatOrd(layer)).or_insert_with(Vec::new);
-            hits.push((*entity, pick_data.clone()));
-        }
+        let hits = pointer_over_map.entry(pointer).or_default();
+        add_hits(
+            hits,
+            entities_under_pointer,
+            backend_settings,
+            |entity, hit| hit_target(pointer, entity, hit),
+        );
+    }
+}
+
+/// Add the hits reported by a backend to the hits under their pointer. They are sorted later, by
+/// [`SortedHits::sort`]. Each hit is reported on the entity returned by `hit_target`, or dropped
+/// if it returns `None`.
+fn add_hits(
+    hits: &mut SortedHits,
+    entities_under_pointer: &PointerHits,
+    (backend_priority, depth_bias): (&BackendPriority, &BackendDepthBias),
+    hit_target: impl Fn(Entity, &HitData) -> Option<Entity>,
+) {
+    let priority = backend_priority.get_priority(entities_under_pointer.backend);
+    let bias = depth_bias.get_bias(entities_under_pointer.backend);
+    for (entity, pick_data) in entities_under_pointer
+        .picks
+        .iter()
+        .filter_map(|(entity, pick_data)| Some((hit_target(*entity, pick_data)?, pick_data)))
+    {
+        hits.push(
+            priority,
+            entities_under_pointer.order,
+            bias,
+            entity,
+            pick_data.clone(),
+        );
     }
+}
 
-    for layers in pointer_over_map.values_mut() {
-        for hits in layers.values_mut() {
-            hits.sort_by_key(|(_, hit)| FloatOrd(hit.depth));
+/// Overrides the hover state of pointers with a [`PointerCapture`], so they only hover the
+/// captured entity. This runs after [`update_focus`], and does not apply to pointers suppressed by
+/// the [`ExclusivePointer`], grabbed with a [`PointerGrab`], or disabled with a [`PointerDisabled`].
+pub fn apply_pointer_captures(
+    // Inputs
+    captures: Query<
+        (&PointerId, &PointerCapture),
+        (Without<PointerGrab>, Without<PointerDisabled>),
+    >,
+    pickable_events: Query<&PickableEvents>,
+    pick_targets: Query<(&PickTarget, Option<&Pickable>)>,
+    mut under_pointer: EventReader<backend::PointerHits>,
+    exclusive_pointer: Res<ExclusivePointer>,
+    previous_hover_map: Res<PreviousHoverMap>,
+    // Output
+    mut hover_map: ResMut<HoverMap>,
+    mut event_hover_maps: ResMut<EventHoverMaps>,
+) {
+    let hits: Vec<_> = under_pointer.read().collect();
+    for (pointer_id, PointerCapture(captured)) in &captures {
+        if !exclusive_pointer.allows(*pointer_id) {
+            continue;
+        }
+        let Some(hit) = hits
+            .iter()
+            .rev()
+            .filter(|hits| hits.pointer == *pointer_id)
+            .find_map(|hits| {
+                hits.picks.iter().find(|(entity, _)| {
+                    let (pick_target, pickable) = pick_targets.get(*entity).ok().unzip();
+                    PickTarget::retarget(*entity, pick_target, pickable.flatten())
+                        == Some(*captured)
+                })
+            })
+            .map(|(_, hit)| hit)
+            .or_else(|| previous_hover_map.get(pointer_id)?.get(captured))
+        else {
+            continue;
+        };
+        let captured_map = EntityHashMap::from_iter([(*captured, hit.clone())]);
+        let events = pickable_events.get(*captured).ok();
+        let EventHoverMaps {
+            click,
+            drag,
+            scroll,
+        } = &mut *event_hover_maps;
+        let kinds: [(fn(&PickableEvents) -> bool, _); 3] = [
+            (|events| events.click, click),
+            (|events| events.drag, drag),
+            (|events| events.scroll, scroll),
+        ];
+        for (enabled, map) in kinds {
+            if events.map_or(true, enabled) {
+                map.insert(*pointer_id, captured_map.clone());
+            } else {
+                map.insert(*pointer_id, EntityHashMap::default());
+            }
         }
+        hover_map.insert(*pointer_id, captured_map);
     }
 }
 
@@ -138,31 +961,220 @@ fn build_over_map(
 /// that unlike the pointer map, this uses [`Pickable`] to determine if lower entities receive hover
 /// focus. Often, only a single entity per pointer will be hovered.
 fn build_hover_map(
-    pointers: &Query<&PointerId>,
-    pickable: Query<&Pickable>,
-    over_map: &Local<OverMap>,
+    pointers: &Query<(&PointerId, Option<&PointerRadius>)>,
+    pickable: impl Fn(Entity) -> Pickable,
+    hover_groups: &Query<&HoverGroup>,
+    over_map: &OverMap,
+    claimed_groups: &mut Vec<HoverGroup>,
     // Output
-    hover_map: &mut HoverMap,
+    hover_map: &mut HashMap<PointerId, EntityHashMap<HitData>>,
+) {
+    for (pointer_id, radius) in pointers.iter() {
+        let is_area = radius.is_some_and(PointerRadius::is_area);
+        let pointer_entity_set = hover_map.entry(*pointer_id).or_default();
+        if let Some(hits) = over_map.get(pointer_id) {
+            resolve_hovered(
+                hits,
+                is_area,
+                &pickable,
+                |entity| hover_groups.get(entity).ok().copied(),
+                claimed_groups,
+                |entity, pick_data| {
+                    // Keep the nearest hit of entities hit more than once through a `PickTarget`.
+                    pointer_entity_set
+                        .entry(entity)
+                        .or_insert_with(|| pick_data.clone());
+                },
+            );
+        }
+    }
+}
+
+/// Walks the sorted hits of a single pointer from the highest layer and nearest depth, calling
+/// `hover` for every entity that should be hovered, and stopping at the first entity that blocks
+/// lower entities. Hits of area pointers, with a [`PointerRadius`], are never blocked.
+///
+/// Entities without a [`Pickable`] component should be reported as [`Pickable::default`], which
+/// is hoverable and blocks lower entities.
+fn resolve_hovered<'a>(
+    hits: &'a SortedHits,
+    is_area: bool,
+    pickable: impl Fn(Entity) -> Pickable,
+    hover_group: impl Fn(Entity) -> Option<HoverGroup>,
+    claimed_groups: &mut Vec<HoverGroup>,
+    mut hover: impl FnMut(Entity, &'a HitData),
 ) {
-    for pointer_id in pointers.iter() {
-        let pointer_entity_set = hover_map.entry(*pointer_id).or_insert_with(HashMap::new);
-        if let Some(layer_map) = over_map.get(pointer_id) {
-            // Note we reverse here to start from the highest layer first.
-            for (entity, pick_data) in layer_map.values().rev().flatten() {
-                if let Ok(pickable) = pickable.get(*entity) {
-                    if pickable.is_hoverable {
-                        pointer_entity_set.insert(*entity, pick_data.clone());
-                    }
-                    if pickable.should_block_lower {
-                        break;
-                    }
-                } else {
-                    pointer_entity_set.insert(*entity, pick_data.clone()); // Emit events by default
-                    break; // Entities block by default so we break out of the loop
+    claimed_groups.clear();
+    for (entity, hit) in hits.iter() {
+        let pickable = pickable(*entity);
+        if pickable.is_hoverable && claim_hover_group(hover_group(*entity), claimed_groups) {
+            hover(*entity, hit);
+        }
+        if pickable.should_block_lower && !is_area {
+            break;
+        }
+    }
+}
+
+/// Returns `true` if the entity is not part of a [`HoverGroup`], or if it is the first member of its
+/// group to be hovered by the current pointer, in which case the group is claimed.
+fn claim_hover_group(group: Option<HoverGroup>, claimed_groups: &mut Vec<HoverGroup>) -> bool {
+    let Some(group) = group else {
+        return true;
+    };
+    if claimed_groups.contains(&group) {
+        return false;
+    }
+    claimed_groups.push(group);
+    true
+}
+
+/// Computes the entities `pointer` would hover if it were at `location` right now, sorted from
+/// nearest to farthest, without changing any picking state.
+///
+/// This runs the [`RayMap`] and every backend registered in the
+/// [`PickingProbe`](backend::PickingProbe) schedule against a temporary pointer location, then
+/// resolves the reported hits with the same blocking rules as [`update_focus`], including
+/// [`Pickable`] and [`HoverGroup`]. No events are emitted, and the [`HoverMap`], the [`RayMap`],
+/// and the pointer's [`PointerLocation`] are left exactly as they were. If no pointer with this id
+/// exists, a temporary one is spawned for the duration of the probe.
+///
+/// This is useful for hover previews and editor pickers. Note that backends hit test every pointer
+/// when they run, so the cost of a probe is roughly the cost of a frame of hit testing.
+pub fn probe_hover(
+    world: &mut World,
+    pointer: PointerId,
+    location: Location,
+) -> Vec<(Entity, HitData)> {
+    let existing_pointer = world
+        .query::<(Entity, &PointerId)>()
+        .iter(world)
+        .find_map(|(entity, id)| (*id == pointer).then_some(entity));
+
+    let probe_location = PointerLocation::new(location.clone());
+    let previous_location = match existing_pointer {
+        Some(entity) => match world.get_mut::<PointerLocation>(entity) {
+            Some(mut pointer_location) => Some(std::mem::replace(
+                pointer_location.bypass_change_detection(),
+                probe_location,
+            )),
+            None => return Vec::new(),
+        },
+        None => None,
+    };
+    // Hit test the probed location itself, rather than the pointer's smoothed position.
+    let previous_smoothed = existing_pointer
+        .and_then(|entity| world.get_mut::<SmoothedHitTestPosition>(entity))
+        .map(|mut smoothed| std::mem::take(smoothed.bypass_change_detection()));
+    let pointer_entity = existing_pointer.unwrap_or_else(|| {
+        world
+            .spawn(PointerCoreBundle::new(pointer).with_location(location))
+            .id()
+    });
+    let pointer_layers = world
+        .get::<PickLayers>(pointer_entity)
+        .copied()
+        .unwrap_or_default();
+    let is_area = world
+        .get::<PointerRadius>(pointer_entity)
+        .is_some_and(PointerRadius::is_area);
+
+    // Run the backends against a scratch event queue, so the hits are never seen by the focus
+    // systems, and restore the ray map and the queue's change tick afterwards.
+    let ray_map = world.get_resource::<RayMap>().cloned();
+    let hits_changed = world
+        .get_resource_ref::<Events<PointerHits>>()
+        .map(|hits| hits.last_changed());
+    world.init_resource::<Events<PointerHits>>();
+    let pointer_hits = std::mem::take(
+        world
+            .resource_mut::<Events<PointerHits>>()
+            .bypass_change_detection(),
+    );
+    let _ = world.try_run_schedule(backend::PickingProbe);
+    let mut hits = world.resource_mut::<Events<PointerHits>>();
+    let mut probe_hits = std::mem::replace(hits.bypass_change_detection(), pointer_hits);
+    if let Some(hits_changed) = hits_changed {
+        hits.set_last_changed(hits_changed);
+    } else {
+        world.remove_resource::<Events<PointerHits>>();
+    }
+    if let Some(ray_map) = ray_map {
+        world.insert_resource(ray_map);
+    }
+    match previous_location {
+        Some(previous_location) => {
+            if let Some(mut pointer_location) = world.get_mut::<PointerLocation>(pointer_entity) {
+                *pointer_location.bypass_change_detection() = previous_location;
+            }
+            if let Some(previous_smoothed) = previous_smoothed {
+                if let Some(mut smoothed) = world.get_mut::<SmoothedHitTestPosition>(pointer_entity)
+                {
+                    *smoothed.bypass_change_detection() = previous_smoothed;
                 }
             }
         }
+        None => {
+            world.despawn(pointer_entity);
+        }
     }
+
+    let backend_priority = world
+        .get_resource::<BackendPriority>()
+        .cloned()
+        .unwrap_or_default();
+    let depth_bias = world
+        .get_resource::<BackendDepthBias>()
+        .cloned()
+        .unwrap_or_default();
+    let mut sorted_hits = SortedHits::default();
+    for hits in probe_hits.drain().filter(|hits| hits.pointer == pointer) {
+        let backend_settings = (&backend_priority, &depth_bias);
+        add_hits(&mut sorted_hits, &hits, backend_settings, |entity, hit| {
+            if !PickingShape::accepts(world.get(entity), world.get(entity), hit) {
+                return None;
+            }
+            let entity =
+                PickTarget::retarget(entity, world.get::<PickTarget>(entity), world.get(entity))?;
+            let entity_layers = world.get::<PickLayers>(entity).copied().unwrap_or_default();
+            pointer_layers.intersects(&entity_layers).then_some(entity)
+        });
+    }
+    let depth_comparison = world
+        .get_resource::<DepthComparison>()
+        .copied()
+        .unwrap_or_default();
+    sorted_hits.sort(|hit| {
+        depth_comparison.depth(hit, |camera| {
+            depth_range(
+                world.get::<Projection>(camera),
+                world.get::<PerspectiveProjection>(camera),
+                world.get::<OrthographicProjection>(camera),
+            )
+        })
+    });
+
+    let focus_debug = world
+        .get_resource::<FocusDebugSettings>()
+        .copied()
+        .unwrap_or_default();
+    let mut hovered = Vec::new();
+    resolve_hovered(
+        &sorted_hits,
+        is_area,
+        |entity| match world.get::<PickableEvents>(entity) {
+            Some(events) if !events.hover => Pickable::IGNORE,
+            _ => focus_debug.apply(world.get::<Pickable>(entity).cloned().unwrap_or_default()),
+        },
+        |entity| world.get::<HoverGroup>(entity).copied(),
+        &mut Vec::new(),
+        |entity, pick_data| {
+            if hovered.iter().all(|(hovered, _)| *hovered != entity) {
+                hovered.push((entity, pick_data.clone()));
+            }
+        },
+    );
+    hovered
 }
 
 /// A component that aggregates picking interaction state of this entity across all pointers.
@@ -174,7 +1186,11 @@ fn build_hover_map(
 /// For example, if we have an entity that is being hovered by one pointer, and pressed by another,
 /// the entity will be considered pressed. If that entity is instead being hovered by both pointers,
 /// it will be considered hovered.
-#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+///
+/// The precedence is the order of the variants, from [`PickingInteraction::None`] to
+/// [`PickingInteraction::Pressed`]. The pointer whose interaction won is stored in the entity's
+/// [`PickingInteractionSource`]. See [`update_interactions`] for how ties are resolved.
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, PartialOrd, Ord, Hash, Debug, Reflect)]
 #[reflect(Component, Default)]
 pub enum PickingInteraction {
     /// The entity is being pressed down by a pointer.
@@ -186,7 +1202,23 @@ pub enum PickingInteraction {
     None = 0,
 }
 
-/// Uses pointer events to update [`PointerInteraction`] and [`PickingInteraction`] components.
+/// The pointer that caused the [`PickingInteraction`] of this entity, or `None` if no pointer is
+/// interacting with it.
+///
+/// This is inserted and updated alongside the [`PickingInteraction`] by [`update_interactions`].
+#[derive(Component, Copy, Clone, Default, Eq, PartialEq, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct PickingInteractionSource(pub Option<PointerId>);
+
+/// Uses pointer events to update [`PointerInteraction`], [`PickingInteraction`], and
+/// [`PickingInteractionSource`] components. Returns early if no pointer hovers anything, and none
+/// did on the last frame.
+///
+/// When several pointers hover the same entity, the merged interaction does not depend on the order
+/// pointers are iterated in. The interaction with the highest precedence wins, so
+/// [`PickingInteraction::Pressed`] wins over [`PickingInteraction::Hovered`], which wins over
+/// [`PickingInteraction::None`]. Among pointers with the same interaction, the lowest [`PointerId`]
+/// wins: the mouse, then touches by increasing id, then custom pointers.
 pub fn update_interactions(
     // Input
     hover_map: Res<HoverMap>,
@@ -195,7 +1227,22 @@ pub fn update_interactions(
     mut commands: Commands,
     mut pointers: Query<(&PointerId, &PointerPress, &mut PointerInteraction)>,
     mut interact: Query<&mut PickingInteraction>,
+    mut sources: Query<&mut PickingInteractionSource>,
 ) {
+    // Nothing needs to be cleared or updated if no pointer hovers anything, and none did on the
+    // last frame.
+    let is_empty = |map: &HashMap<PointerId, EntityHashMap<HitData>>| {
+        map.values().all(EntityHashMap::is_empty)
+    };
+    if is_empty(&hover_map)
+        && is_empty(&previous_hover_map)
+        && pointers
+            .iter()
+            .all(|(.., interaction)| interaction.sorted_entities.is_empty())
+    {
+        return;
+    }
+
     // Clear all previous hover data from pointers and entities
     for (pointer, _, mut pointer_interaction) in &mut pointers {
         pointer_interaction.sorted_entities.clear();
@@ -204,6 +1251,9 @@ pub fn update_interactions(
                 if let Ok(mut interaction) = interact.get_mut(*entity) {
                     *interaction = PickingInteraction::None;
                 }
+                if let Ok(mut source) = sources.get_mut(*entity) {
+                    source.0 = None;
+                }
             }
         }
     }
@@ -212,7 +1262,7 @@ pub fn update_interactions(
     // need to be able to insert the interaction component on entities if they do not exist. To do
     // so we need to know the final aggregated interaction state to avoid the scenario where we set
     // an entity to `Pressed`, then overwrite that with a lower precedent like `Hovered`.
-    let mut new_interaction_state = HashMap::<Entity, PickingInteraction>::new();
+    let mut new_interaction_state = HashMap::<Entity, (PickingInteraction, PointerId)>::new();
     for (pointer, pointer_press, mut pointer_interaction) in &mut pointers {
         if let Some(pointers_hovered_entities) = hover_map.get(pointer) {
             // Insert a sorted list of hit entities into the pointer's interaction component.
@@ -221,45 +1271,348 @@ pub fn update_interactions(
             pointer_interaction.sorted_entities = sorted_entities;
 
             for hovered_entity in pointers_hovered_entities.iter().map(|(entity, _)| entity) {
-                merge_interaction_states(pointer_press, hovered_entity, &mut new_interaction_state);
+                merge_interaction_states(
+                    pointer,
+                    pointer_press,
+                    hovered_entity,
+                    &mut new_interaction_state,
+                );
             }
         }
     }
 
     // Take the aggregated entity states and update or insert the component if missing.
-    for (hovered_entity, new_interaction) in new_interaction_state.drain() {
+    for (hovered_entity, (new_interaction, pointer)) in new_interaction_state.drain() {
+        let new_source = PickingInteractionSource(Some(pointer));
+        let Some(mut entity_commands) = commands.get_entity(hovered_entity) else {
+            continue;
+        };
         if let Ok(mut interaction) = interact.get_mut(hovered_entity) {
             *interaction = new_interaction;
-        } else if let Some(mut entity_commands) = commands.get_entity(hovered_entity) {
+        } else {
             entity_commands.try_insert(new_interaction);
         }
+        if let Ok(mut source) = sources.get_mut(hovered_entity) {
+            *source = new_source;
+        } else {
+            entity_commands.try_insert(new_source);
+        }
     }
 }
 
 /// Merge the interaction state of this entity into the aggregated map.
 fn merge_interaction_states(
+    pointer: &PointerId,
     pointer_press: &PointerPress,
     hovered_entity: &Entity,
-    new_interaction_state: &mut HashMap<Entity, PickingInteraction>,
+    new_interaction_state: &mut HashMap<Entity, (PickingInteraction, PointerId)>,
 ) {
     let new_interaction = match pointer_press.is_any_pressed() {
         true => PickingInteraction::Pressed,
         false => PickingInteraction::Hovered,
     };
 
-    if let Some(old_interaction) = new_interaction_state.get_mut(hovered_entity) {
-        // Only update if the new value has a higher precedence than the old value.
-        if *old_interaction != new_interaction
-            && matches!(
-                (*old_interaction, new_interaction),
-                (PickingInteraction::Hovered, PickingInteraction::Pressed)
-                    | (PickingInteraction::None, PickingInteraction::Pressed)
-                    | (PickingInteraction::None, PickingInteraction::Hovered)
-            )
-        {
+    if let Some((old_interaction, old_pointer)) = new_interaction_state.get_mut(hovered_entity) {
+        // Only update if the new value has a higher precedence than the old value, or the same
+        // precedence from a lower pointer id, so the result does not depend on iteration order.
+        if (new_interaction, Reverse(*pointer)) > (*old_interaction, Reverse(*old_pointer)) {
             *old_interaction = new_interaction;
+            *old_pointer = *pointer;
         }
     } else {
-        new_interaction_state.insert(*hovered_entity, new_interaction);
+        new_interaction_state.insert(*hovered_entity, (new_interaction, *pointer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::prelude::*;
+    use bevy_math::Vec2;
+
+    use super::*;
+    use crate::{events::*, pointer::*, testing::*, CorePlugin, InteractionPlugin, PickSet};
+
+    fn hit(depth: f32) -> HitData {
+        HitData::new(Entity::PLACEHOLDER, depth, None, None)
+    }
+
+    #[test]
+    fn ignore_blocking_hovers_every_hit() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(FocusDebugSettings {
+                ignore_blocking: true,
+            });
+        let top = app.world.spawn(Pickable::default()).id();
+        let bottom = app.world.spawn(Pickable::default()).id();
+        app.world.spawn(PointerCoreBundle::new(PointerId::Mouse));
+
+        let hits = vec![(top, hit(1.0)), (bottom, hit(2.0))];
+        app.world
+            .send_event(PointerHits::new(PointerId::Mouse, hits, 0.0));
+        app.update();
+
+        // Both entities are hovered, even though the top one blocks lower entities.
+        let hovered = &app.world.resource::<HoverMap>()[&PointerId::Mouse];
+        assert!(hovered.contains_key(&top) && hovered.contains_key(&bottom));
+    }
+
+    /// Reports overlapping hits from two cameras with the same order but different depth ranges,
+    /// and returns `true` if the hit from the camera with the shorter range ends up hovered.
+    fn short_range_hit_is_nearest(comparison: DepthComparison) -> bool {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(comparison);
+        let projection = |far| PerspectiveProjection {
+            near: 0.0,
+            far,
+            ..Default::default()
+        };
+        let short_camera = app.world.spawn(projection(10.0)).id();
+        let long_camera = app
+            .world
+            .spawn(Projection::Perspective(projection(1000.0)))
+            .id();
+        // Halfway through the short camera's range, but a tenth of the way through the long one's.
+        let short_entity = app.world.spawn_empty().id();
+        let long_entity = app.world.spawn_empty().id();
+        app.world.spawn(PointerCoreBundle::new(PointerId::Mouse));
+
+        for (camera, entity, depth) in [
+            (short_camera, short_entity, 5.0),
+            (long_camera, long_entity, 100.0),
+        ] {
+            let hit = HitData::new(camera, depth, None, None);
+            app.world
+                .send_event(PointerHits::new(PointerId::Mouse, vec![(entity, hit)], 0.0));
+        }
+        app.update();
+
+        let hovered = &app.world.resource::<HoverMap>()[&PointerId::Mouse];
+        assert_eq!(hovered.len(), 1);
+        hovered.contains_key(&short_entity)
+    }
+
+    #[test]
+    fn normalized_depths_compare_hits_across_camera_projections() {
+        assert!(short_range_hit_is_nearest(DepthComparison::Raw));
+        assert!(!short_range_hit_is_nearest(DepthComparison::Normalized));
+    }
+
+    #[test]
+    fn merged_interactions_prefer_pressed_then_lowest_pointer() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        let button = app.world.spawn_empty().id();
+        for pointer_id in [PointerId::Mouse, PointerId::Touch(0)] {
+            let location = simulated_location(Vec2::ZERO);
+            app.world
+                .spawn(PointerCoreBundle::new(pointer_id).with_location(location));
+        }
+        let hover_both = |app: &mut App| {
+            for pointer_id in [PointerId::Touch(0), PointerId::Mouse] {
+                app.world
+                    .send_event(PointerHits::new(pointer_id, vec![(button, hit(0.0))], 0.0));
+            }
+        };
+
+        // The touch presses the button while the mouse hovers it: pressed wins over hovered.
+        hover_both(&mut app);
+        let press = InputPress::new_down(PointerId::Touch(0), PointerButton::Primary);
+        app.world.send_event(press);
+        app.update();
+        let entity = app.world.entity(button);
+        assert_eq!(entity.get(), Some(&PickingInteraction::Pressed));
+        assert_eq!(
+            entity.get(),
+            Some(&PickingInteractionSource(Some(PointerId::Touch(0))))
+        );
+
+        // Once released, both pointers hover the button, and the mouse wins the tie.
+        hover_both(&mut app);
+        let release = InputPress::new_up(PointerId::Touch(0), PointerButton::Primary);
+        app.world.send_event(release);
+        app.update();
+        let entity = app.world.entity(button);
+        assert_eq!(entity.get(), Some(&PickingInteraction::Hovered));
+        assert_eq!(
+            entity.get(),
+            Some(&PickingInteractionSource(Some(PointerId::Mouse)))
+        );
+
+        // Once no pointer hovers the button, it has no interaction and no source.
+        app.update();
+        let entity = app.world.entity(button);
+        assert_eq!(entity.get(), Some(&PickingInteraction::None));
+        assert_eq!(entity.get(), Some(&PickingInteractionSource(None)));
+    }
+
+    #[test]
+    fn exclusive_pointers_suppress_other_pointers_until_cleared() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        let item = app.world.spawn_empty().id();
+        let touch = PointerId::Touch(0);
+        let hover_both = |app: &mut App| {
+            app.world.send_event(PointerHits::new(
+                PointerId::Mouse,
+                vec![(item, hit(0.0))],
+                0.0,
+            ));
+            simulate_hover(app, touch, item);
+        };
+        let overs = |app: &App| -> Vec<PointerId> {
+            let overs = sent_pointer_events::<Over>(app);
+            overs.iter().map(|over| over.pointer_id).collect()
+        };
+        simulate_hover(&mut app, PointerId::Mouse, item);
+        hover_both(&mut app);
+        assert_eq!(overs(&app), [touch]);
+
+        // The mouse leaves the item as soon as the touch has exclusive focus, and can't click it.
+        app.world.resource_mut::<ExclusivePointer>().set(touch);
+        hover_both(&mut app);
+        let outs = sent_pointer_events::<Out>(&app);
+        assert_eq!(outs.len(), 1);
+        assert_eq!(outs[0].pointer_id, PointerId::Mouse);
+        simulate_click(&mut app, PointerId::Mouse, item);
+        assert!(sent_pointer_events::<Click>(&app).is_empty());
+        simulate_click(&mut app, touch, item);
+        assert_eq!(sent_pointer_events::<Click>(&app).len(), 1);
+
+        app.world.resource_mut::<ExclusivePointer>().clear();
+        hover_both(&mut app);
+        assert_eq!(overs(&app), [PointerId::Mouse]);
+    }
+
+    /// The entities a stub backend reports under every pointer, from top to bottom.
+    #[derive(Resource)]
+    struct Stack(Vec<Entity>);
+
+    fn pick_stack(
+        stack: Res<Stack>,
+        pointers: Query<(&PointerId, &PointerLocation)>,
+        mut output: EventWriter<PointerHits>,
+    ) {
+        for (pointer_id, pointer) in &pointers {
+            if pointer.location.is_none() {
+                continue;
+            }
+            let hits = stack
+                .0
+                .iter()
+                .enumerate()
+                .map(|(depth, entity)| (*entity, hit(depth as f32)))
+                .collect();
+            output.send(PointerHits::new(*pointer_id, hits, 0.0));
+        }
+    }
+
+    #[test]
+    fn hover_groups_hover_only_the_topmost_member() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .add_systems(PreUpdate, pick_stack.in_set(PickSet::Backend));
+        let transparent = Pickable {
+            should_block_lower: false,
+            ..Default::default()
+        };
+        let top = app.world.spawn((transparent.clone(), HoverGroup(0))).id();
+        let bottom = app.world.spawn((transparent.clone(), HoverGroup(0))).id();
+        let background = app.world.spawn(transparent).id();
+        app.world
+            .insert_resource(Stack(vec![top, bottom, background]));
+
+        simulate_pointer_move(&mut app, PointerId::Mouse, simulated_location(Vec2::ZERO));
+
+        // Only the top member of the group is hovered, while the rest of the scene is unaffected.
+        let hovered = &app.world.resource::<HoverMap>()[&PointerId::Mouse];
+        assert!(hovered.contains_key(&top) && hovered.contains_key(&background));
+        assert!(!hovered.contains_key(&bottom));
+        let overs = sent_pointer_events::<Over>(&app);
+        assert!(overs.iter().all(|over| over.target != bottom));
+
+        // When the members swap places, hover moves from one to the other.
+        app.world
+            .insert_resource(Stack(vec![bottom, top, background]));
+        simulate_pointer_move(&mut app, PointerId::Mouse, simulated_location(Vec2::ONE));
+        let hovered = &app.world.resource::<HoverMap>()[&PointerId::Mouse];
+        assert!(hovered.contains_key(&bottom) && !hovered.contains_key(&top));
+        let outs = sent_pointer_events::<Out>(&app);
+        assert_eq!(outs.len(), 1);
+        assert_eq!(outs[0].target, top);
+    }
+
+    #[test]
+    fn disabled_event_kinds_fall_through_to_lower_entities() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .add_systems(PreUpdate, pick_stack.in_set(PickSet::Backend));
+        let button = app
+            .world
+            .spawn(PickableEvents {
+                scroll: false,
+                ..PickableEvents::ALL
+            })
+            .id();
+        let container = app.world.spawn_empty().id();
+        app.world.insert_resource(Stack(vec![button, container]));
+        let location = simulated_location(Vec2::ZERO);
+
+        simulate_click(&mut app, PointerId::Mouse, location.clone());
+        let clicks = sent_pointer_events::<Click>(&app);
+        assert_eq!(clicks.len(), 1);
+        assert_eq!(clicks[0].target, button);
+
+        let scroll = InputScroll::new(PointerId::Mouse, Vec2::Y, ScrollUnit::Line);
+        app.world.send_event(scroll);
+        simulate_pointer_move(&mut app, PointerId::Mouse, location);
+        let scrolls = sent_pointer_events::<Scroll>(&app);
+        assert_eq!(scrolls.len(), 1);
+        assert_eq!(scrolls[0].target, container);
+    }
+
+    #[test]
+    fn probing_leaves_picking_state_unchanged() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .add_systems(PreUpdate, pick_stack.in_set(PickSet::Backend))
+            .add_systems(backend::PickingProbe, pick_stack.in_set(PickSet::Backend));
+        let item = app.world.spawn_empty().id();
+        let other = app.world.spawn_empty().id();
+        app.world.insert_resource(Stack(vec![item]));
+        simulate_pointer_move(&mut app, PointerId::Mouse, simulated_location(Vec2::ZERO));
+        let mouse = app
+            .world
+            .query::<(Entity, &PointerId)>()
+            .iter(&app.world)
+            .find_map(|(entity, id)| id.is_mouse().then_some(entity))
+            .unwrap();
+        let state = |world: &World| {
+            let location = world.entity(mouse).get_ref::<PointerLocation>().unwrap();
+            let hits = world.get_resource_ref::<Events<PointerHits>>().unwrap();
+            (
+                world.resource::<HoverMap>().0.clone(),
+                location.clone(),
+                (location.last_changed(), location.is_added()),
+                world
+                    .entity(mouse)
+                    .get_ref::<SmoothedHitTestPosition>()
+                    .map(|smoothed| (*smoothed, smoothed.last_changed())),
+                hits.len(),
+                hits.last_changed(),
+            )
+        };
+        let before = state(&app.world);
+
+        app.world.insert_resource(Stack(vec![other, item]));
+        let probed = probe_hover(
+            &mut app.world,
+            PointerId::Mouse,
+            simulated_location(Vec2::ONE),
+        );
+        assert_eq!(probed.len(), 1);
+        assert_eq!(probed[0].0, other);
+        assert_eq!(state(&app.world), before);
     }
 }
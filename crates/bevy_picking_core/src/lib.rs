@@ -5,15 +5,20 @@
 #![deny(missing_docs)]
 
 pub mod backend;
+pub mod diagnostics;
 pub mod events;
 pub mod focus;
 pub mod pointer;
+pub mod replay;
+pub mod report;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_reflect::prelude::*;
 
-use bevy_eventlistener::{prelude::*, EventListenerSet};
+use bevy_eventlistener::EventListenerSet;
 /// Used to globally toggle picking features at runtime.
 #[derive(Clone, Debug, Resource, Reflect)]
 #[reflect(Resource, Default)]
@@ -48,6 +53,92 @@ impl Default for PickingPluginsSettings {
     }
 }
 
+/// Used to toggle categories of [`Pointer`](events::Pointer) events at runtime, such as to suppress
+/// drag events while a modal is open, without affecting hover. Every category is enabled by
+/// default.
+///
+/// The interactions tracked by the events of a disabled category are still updated, so they stay
+/// consistent, but the systems that send pointer events check these settings, through their
+/// [`PointerEventWriter`](events::PointerEventWriter), and do not send the events of disabled
+/// categories. For example, a drag that starts while drag events are disabled sends
+/// [`Drag`](events::Drag) events once they are enabled again, without a
+/// [`DragStart`](events::DragStart). Touch gesture, [`Idle`](events::Idle),
+/// [`Wake`](events::Wake), [`WindowEnter`](events::WindowEnter), and
+/// [`WindowLeave`](events::WindowLeave) events are not affected by these settings.
+#[derive(Clone, Copy, Debug, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct PickingEventSettings {
+    /// Enables and disables [`Over`](events::Over), [`Out`](events::Out),
+    /// [`Move`](events::Move), and [`HoverStart`](events::HoverStart) events.
+    pub hover: bool,
+    /// Enables and disables [`Down`](events::Down), [`Up`](events::Up), [`Click`](events::Click),
+    /// [`DoubleClick`](events::DoubleClick), and [`LongPress`](events::LongPress) events.
+    pub click: bool,
+    /// Enables and disables [`DragStart`](events::DragStart), [`Drag`](events::Drag),
+    /// [`DragEnd`](events::DragEnd), [`DragEnter`](events::DragEnter),
+    /// [`DragOver`](events::DragOver), [`DragLeave`](events::DragLeave),
+    /// [`Drop`](events::Drop), [`DragViewportExit`](events::DragViewportExit), and
+    /// [`DragViewportEnter`](events::DragViewportEnter) events.
+    pub drag: bool,
+    /// Enables and disables [`Scroll`](events::Scroll) events.
+    pub scroll: bool,
+}
+
+impl PickingEventSettings {
+    /// Returns `true` if [`Pointer<E>`](events::Pointer) events are sent. Event types that do not
+    /// belong to a category are always sent.
+    pub fn allows<E: 'static>(&self) -> bool {
+        use events::*;
+        use std::any::TypeId;
+
+        let kind = TypeId::of::<E>();
+        let is_any = |kinds: &[TypeId]| kinds.contains(&kind);
+        if is_any(&[
+            TypeId::of::<Over>(),
+            TypeId::of::<Out>(),
+            TypeId::of::<Move>(),
+            TypeId::of::<HoverStart>(),
+        ]) {
+            self.hover
+        } else if is_any(&[
+            TypeId::of::<Down>(),
+            TypeId::of::<Up>(),
+            TypeId::of::<Click>(),
+            TypeId::of::<DoubleClick>(),
+            TypeId::of::<LongPress>(),
+        ]) {
+            self.click
+        } else if is_any(&[
+            TypeId::of::<DragStart>(),
+            TypeId::of::<Drag>(),
+            TypeId::of::<DragEnd>(),
+            TypeId::of::<DragEnter>(),
+            TypeId::of::<DragOver>(),
+            TypeId::of::<DragLeave>(),
+            TypeId::of::<Drop>(),
+            TypeId::of::<DragViewportExit>(),
+            TypeId::of::<DragViewportEnter>(),
+        ]) {
+            self.drag
+        } else if kind == TypeId::of::<Scroll>() {
+            self.scroll
+        } else {
+            true
+        }
+    }
+}
+
+impl Default for PickingEventSettings {
+    fn default() -> Self {
+        Self {
+            hover: true,
+            click: true,
+            drag: true,
+            scroll: true,
+        }
+    }
+}
+
 /// An optional component that overrides default picking behavior for an entity, allowing you to
 /// make an entity non-hoverable, or allow items below it to be hovered. See the documentation on
 /// the fields for more details.
@@ -113,6 +204,315 @@ impl Default for Pickable {
     }
 }
 
+/// Redirects hits on this entity to another entity, usually an ancestor, such as the root of a
+/// glTF scene whose meshes are hit by backends.
+///
+/// Hits are redirected before focus is computed, so the target is hovered instead of this entity,
+/// and every [`Pointer`](events::Pointer) event is sent with the target as its `target`. This is
+/// different from bubbling, which only changes which listeners an event reaches on its way up the
+/// hierarchy, and keeps the original `target`. Events sent to the target still bubble up from it.
+///
+/// Redirected hits use the picking components of the target, such as its [`Pickable`],
+/// [`PickableEvents`], and [`PickLayers`], instead of those of this entity. The only exception is
+/// [`Pickable::IGNORE`]: hits on an entity that has both this component and [`Pickable::IGNORE`]
+/// are dropped, so parts of a scene can be excluded from picking without removing this component.
+/// When several entities are redirected to the same target, the nearest hit is used. Redirection is
+/// not followed further if the target has a [`PickTarget`] of its own.
+#[derive(Component, Debug, Clone, Copy, Reflect, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct PickTarget(pub Entity);
+
+impl PickTarget {
+    /// Returns the entity that a hit on `entity` should be reported on, or `None` if the hit should
+    /// be dropped, given the [`PickTarget`] and [`Pickable`] of `entity`.
+    pub fn retarget(
+        entity: Entity,
+        pick_target: Option<&PickTarget>,
+        pickable: Option<&Pickable>,
+    ) -> Option<Entity> {
+        match pick_target {
+            Some(_) if pickable == Some(&Pickable::IGNORE) => None,
+            Some(PickTarget(target)) => Some(*target),
+            None => Some(entity),
+        }
+    }
+}
+
+/// Restricts the hits on this entity to a region of it, such as the header of a card that can only
+/// be dragged by its header, without splitting the entity into invisible children.
+///
+/// The shape is in the local space of the entity's [`GlobalTransform`], on its XY plane. For UI
+/// nodes, this is in logical pixels from the center of the node, with Y pointing down, and for
+/// sprites, it is in the units of the sprite's transform from its translation. Hits whose position
+/// falls outside of the shape are dropped by [`update_focus`](focus::update_focus), so the entity
+/// is neither hovered nor blocks lower entities there. Hits are kept if the backend doesn't report
+/// their position, or if the entity has no [`GlobalTransform`]. Backends may have already applied
+/// blocking among their own hits, so entities of the same backend below a rejected hit are not hit
+/// either.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::{Rect, Vec2};
+/// # use bevy_picking_core::PickingShape;
+/// fn setup(mut commands: Commands) {
+///     // Only the top 40 pixels of a 200x300 UI card are pickable.
+///     let header = Rect::new(-100.0, -150.0, 100.0, -110.0);
+///     commands.spawn(PickingShape::Rect(header));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect, PartialEq)]
+#[reflect(Component)]
+pub enum PickingShape {
+    /// A rectangle.
+    Rect(bevy_math::Rect),
+    /// A circle.
+    Circle {
+        /// The center of the circle.
+        center: bevy_math::Vec2,
+        /// The radius of the circle.
+        radius: f32,
+    },
+    /// A polygon, from its vertices in order. Self-intersecting polygons use the even-odd rule.
+    Polygon(Vec<bevy_math::Vec2>),
+}
+
+impl PickingShape {
+    /// Returns `true` if `point`, in local space, is inside of the shape.
+    pub fn contains(&self, point: bevy_math::Vec2) -> bool {
+        match self {
+            PickingShape::Rect(rect) => rect.contains(point),
+            PickingShape::Circle { center, radius } => {
+                point.distance_squared(*center) <= radius * radius
+            }
+            PickingShape::Polygon(vertices) => {
+                let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+                edges
+                    .filter(|(a, b)| {
+                        (a.y > point.y) != (b.y > point.y)
+                            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+                    })
+                    .count()
+                    % 2
+                    == 1
+            }
+        }
+    }
+
+    /// Returns `true` if a hit on an entity with this shape and `transform` should be kept: if its
+    /// position is inside of the shape, or if its position or the transform is unknown.
+    pub fn accepts(
+        shape: Option<&PickingShape>,
+        transform: Option<&bevy_transform::components::GlobalTransform>,
+        hit: &backend::HitData,
+    ) -> bool {
+        let (Some(shape), Some(transform), Some(position)) = (shape, transform, hit.position)
+        else {
+            return true;
+        };
+        let local = transform.affine().inverse().transform_point3(position);
+        shape.contains(local.truncate())
+    }
+}
+
+/// An optional component that enables or disables individual kinds of pointer events for an
+/// entity, for finer control than [`Pickable`]. For example, a button can receive clicks while
+/// letting scroll events fall through to the container behind it.
+///
+/// Disabling a kind of event affects both emission and blocking for that kind: the entity is
+/// ignored, as if it had [`Pickable::IGNORE`], when resolving which entities receive those events,
+/// so entities below it can receive them instead. Other kinds are unaffected, and still follow the
+/// entity's [`Pickable`] settings. `Down` and `Up` events are sent to an entity if it can be either
+/// clicked or dragged.
+///
+/// Entities without this component have every kind of event enabled. See the
+/// [`EventHoverMaps`](focus::EventHoverMaps) for the resulting per-kind hover state.
+#[derive(Component, Debug, Clone, Copy, Reflect, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct PickableEvents {
+    /// Enables `Over`, `Out`, and `Move` events, and the
+    /// [`PickingInteraction`](focus::PickingInteraction). This also controls whether the entity can
+    /// be dragged over and dropped onto.
+    pub hover: bool,
+    /// Enables `Click` and `DoubleClick` events.
+    pub click: bool,
+    /// Enables `DragStart`, `Drag`, and `DragEnd` events.
+    pub drag: bool,
+    /// Enables `Scroll` events.
+    pub scroll: bool,
+}
+
+impl PickableEvents {
+    /// Every kind of event is enabled.
+    pub const ALL: Self = Self {
+        hover: true,
+        click: true,
+        drag: true,
+        scroll: true,
+    };
+}
+
+impl Default for PickableEvents {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// An optional component that describes how an entity takes part in focus with a single value,
+/// instead of combining [`Pickable`] and [`PickableEvents`].
+///
+/// The policy is applied by [`apply_focus_policies`], which inserts the [`Pickable`] and
+/// [`PickableEvents`] components that match it whenever the policy is added or changed, replacing
+/// any that were there. Backends and the focus systems only read those components, so they behave
+/// exactly as if the components had been added by hand. Removing the policy leaves the components
+/// it inserted. The existing components map onto policies as follows:
+///
+/// | Policy          | [`Pickable`]                | [`PickableEvents`]      |
+/// |-----------------|-----------------------------|-------------------------|
+/// | [`Block`]       | [`Pickable::default`]       | [`PickableEvents::ALL`] |
+/// | [`Pass`]        | `should_block_lower: false` | [`PickableEvents::ALL`] |
+/// | [`Transparent`] | `should_block_lower: false` | Only `hover`            |
+///
+/// ### Bubbling
+///
+/// Events bubble from the entity they target, so a policy only changes which entities are
+/// targeted. A [`Transparent`] entity is still hovered, so its `Over`, `Out`, and `Move` events
+/// bubble up its own hierarchy as usual. Clicks, drags, and scrolls pass through it to the first
+/// entity below it that accepts them, and bubble up *that* entity's hierarchy instead, which does
+/// not include the transparent entity unless it is an ancestor of the entity below.
+///
+/// [`Block`]: FocusPolicy::Block
+/// [`Pass`]: FocusPolicy::Pass
+/// [`Transparent`]: FocusPolicy::Transparent
+#[derive(Component, Debug, Default, Clone, Copy, Reflect, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub enum FocusPolicy {
+    /// The entity receives every event, and blocks lower entities from being picked. This is the
+    /// default for entities without a policy.
+    #[default]
+    Block,
+    /// The entity receives every event, and lower entities can be picked as well.
+    Pass,
+    /// The entity receives hover events, for example to change the cursor, but never blocks lower
+    /// entities and never receives click, drag, or scroll events, which pass through to the
+    /// entities below it. Useful for decorative overlays.
+    Transparent,
+}
+
+impl FocusPolicy {
+    /// The [`PickableEvents`] that match this policy.
+    pub fn events(self) -> PickableEvents {
+        match self {
+            FocusPolicy::Block | FocusPolicy::Pass => PickableEvents::ALL,
+            FocusPolicy::Transparent => PickableEvents {
+                hover: true,
+                click: false,
+                drag: false,
+                scroll: false,
+            },
+        }
+    }
+}
+
+impl From<FocusPolicy> for Pickable {
+    fn from(policy: FocusPolicy) -> Self {
+        Self {
+            should_block_lower: policy == FocusPolicy::Block,
+            is_hoverable: true,
+        }
+    }
+}
+
+/// Inserts the [`Pickable`] and [`PickableEvents`] that match each added or changed
+/// [`FocusPolicy`].
+pub fn apply_focus_policies(
+    mut commands: Commands,
+    policies: Query<(Entity, &FocusPolicy), Changed<FocusPolicy>>,
+) {
+    for (entity, policy) in &policies {
+        commands
+            .entity(entity)
+            .insert((Pickable::from(*policy), policy.events()));
+    }
+}
+
+/// A bitmask of picking layers. Add this component to an entity to place it on specific layers.
+///
+/// Add it to a pointer entity to limit which entities that pointer can pick: the focus systems
+/// ignore any hit on an entity whose layers do not intersect the pointer's layers, as if the
+/// backend had never reported it. This is useful for split-screen or tool-specific pointers.
+///
+/// Backends that support layer filtering (for example the raycasting backends) also carry their own
+/// `PickLayers` mask in their settings, and will only hit test entities whose layers intersect that
+/// mask. Entities and pointers without this component are on every layer, [`PickLayers::ALL`].
+///
+/// ### Combining backends
+///
+/// Backend layer filtering happens inside each backend, before any
+/// [`PointerHits`](backend::PointerHits) are reported. The focus systems simply merge whatever the
+/// backends report, so if two backends can see the same entity and only one of them filters it out,
+/// the entity can still be hovered through the other backend. An entity that is filtered out, by a
+/// backend or by a pointer's layers, can neither be hovered nor block lower entities.
+#[derive(Component, Debug, Clone, Copy, Reflect, PartialEq, Eq, Hash)]
+#[reflect(Component, Default)]
+pub struct PickLayers(pub u32);
+
+impl PickLayers {
+    /// Member of every layer.
+    pub const ALL: Self = Self(u32::MAX);
+    /// Member of no layers.
+    pub const NONE: Self = Self(0);
+
+    /// The number of layers a mask can contain. Layer indices must be less than this.
+    pub const TOTAL_LAYERS: u8 = 32;
+
+    /// Create a mask containing only the layer with index `layer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `layer` is not less than [`PickLayers::TOTAL_LAYERS`].
+    pub const fn layer(layer: u8) -> Self {
+        Self(Self::bit(layer))
+    }
+
+    /// Add the layer with index `layer` to this mask.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `layer` is not less than [`PickLayers::TOTAL_LAYERS`].
+    pub const fn with(self, layer: u8) -> Self {
+        Self(self.0 | Self::bit(layer))
+    }
+
+    /// Remove the layer with index `layer` from this mask.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `layer` is not less than [`PickLayers::TOTAL_LAYERS`].
+    pub const fn without(self, layer: u8) -> Self {
+        Self(self.0 & !Self::bit(layer))
+    }
+
+    const fn bit(layer: u8) -> u32 {
+        assert!(
+            layer < Self::TOTAL_LAYERS,
+            "`PickLayers` only supports layers 0 through 31"
+        );
+        1 << layer
+    }
+
+    /// Returns `true` if this mask shares at least one layer with `other`.
+    #[inline]
+    pub const fn intersects(&self, other: &Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl Default for PickLayers {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
 /// Components needed to build a pointer. Multiple pointers can be active at once, with each pointer
 /// being an entity.
 ///
@@ -129,6 +529,10 @@ pub struct PointerCoreBundle {
     pub click: pointer::PointerPress,
     /// The interaction state of any hovered entities.
     pub interaction: pointer::PointerInteraction,
+    /// Tracks the pointer's velocity.
+    pub velocity: pointer::PointerVelocity,
+    /// Tracks the pointer's pressure.
+    pub pressure: pointer::PointerPressure,
 }
 
 impl PointerCoreBundle {
@@ -147,6 +551,8 @@ impl PointerCoreBundle {
             location: pointer::PointerLocation::default(),
             click: pointer::PointerPress::default(),
             interaction: pointer::PointerInteraction::default(),
+            velocity: pointer::PointerVelocity::default(),
+            pressure: pointer::PointerPressure::default(),
         }
     }
 }
@@ -179,20 +585,64 @@ impl Plugin for CorePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PickingPluginsSettings>()
             .init_resource::<pointer::PointerMap>()
+            .init_resource::<pointer::InputDebounce>()
+            .init_resource::<pointer::ModifierCapture>()
+            .init_resource::<focus::FocusDebugSettings>()
+            .init_resource::<pointer::DebouncedPressState>()
             .init_resource::<backend::ray::RayMap>()
+            .init_resource::<pointer::HitTestSmoothing>()
+            .init_resource::<pointer::PointerLocationMapper>()
+            .init_resource::<pointer::ActivePointer>()
+            .init_resource::<pointer::RebaselineSettings>()
+            .init_resource::<pointer::RebaselinedPointers>()
+            .init_resource::<backend::LateHitSettings>()
+            .init_resource::<pointer::PointerLocationHistory>()
+            .init_resource::<pointer::StalePointerSettings>()
+            .init_resource::<replay::PointerInputRecorder>()
+            .init_resource::<replay::PointerInputPlayer>()
+            .init_resource::<bevy_time::Time<bevy_time::Real>>()
             .add_event::<pointer::InputPress>()
             .add_event::<pointer::InputMove>()
+            .add_event::<pointer::InputScroll>()
             .add_event::<backend::PointerHits>()
+            .add_event::<bevy_window::WindowScaleFactorChanged>()
+            .add_event::<bevy_window::WindowResized>()
             .add_systems(
                 PreUpdate,
                 (
+                    apply_focus_policies,
+                    pointer::despawn_stale_pointers.before(pointer::update_pointer_map),
                     pointer::update_pointer_map,
                     pointer::InputMove::receive,
                     pointer::InputPress::receive,
-                    backend::ray::RayMap::repopulate,
+                    pointer::update_active_pointer,
+                    pointer::rebaseline_pointers.after(pointer::InputMove::receive),
+                    pointer::smooth_hit_test_positions.after(pointer::rebaseline_pointers),
+                    pointer::update_pointer_velocities.after(pointer::rebaseline_pointers),
+                    pointer::record_pointer_location_history
+                        .after(pointer::smooth_hit_test_positions),
+                    backend::ray::RayMap::repopulate.after(pointer::smooth_hit_test_positions),
                 )
                     .in_set(PickSet::ProcessInput),
             )
+            .add_systems(
+                First,
+                (
+                    replay::replay_pointer_inputs.in_set(PickSet::Input),
+                    pointer::debounce_input_presses
+                        .after(bevy_time::TimeSystem)
+                        .in_set(PickSet::PostInput),
+                    replay::record_pointer_inputs.after(PickSet::PostInput),
+                ),
+            )
+            .add_systems(
+                backend::PickingProbe,
+                backend::ray::RayMap::repopulate.in_set(PickSet::ProcessInput),
+            )
+            .configure_sets(
+                backend::PickingProbe,
+                (PickSet::ProcessInput, PickSet::Backend).chain(),
+            )
             .configure_sets(First, (PickSet::Input, PickSet::PostInput).chain())
             .configure_sets(
                 PreUpdate,
@@ -207,52 +657,477 @@ impl Plugin for CorePlugin {
                     .chain(),
             )
             .register_type::<pointer::PointerId>()
+            .register_type::<pointer::PointerButton>()
+            .register_type::<pointer::ScrollUnit>()
+            .register_type::<pointer::InputDebounce>()
+            .register_type::<pointer::ModifierCapture>()
+            .register_type::<focus::FocusDebugSettings>()
+            .register_type::<pointer::Location>()
+            .register_type::<backend::HitData>()
+            .register_type::<backend::Face>()
+            .register_type::<Option<backend::Face>>()
             .register_type::<pointer::PointerLocation>()
+            .register_type::<pointer::PointerHitRadius>()
+            .register_type::<pointer::PointerRadius>()
+            .register_type::<pointer::PointerDisabled>()
+            .register_type::<pointer::PointerVelocity>()
+            .register_type::<pointer::PointerPressure>()
+            .register_type::<pointer::HitTestSmoothing>()
+            .register_type::<pointer::SmoothedHitTestPosition>()
+            .register_type::<pointer::ActivePointer>()
+            .register_type::<pointer::RebaselineSettings>()
+            .register_type::<backend::LateHitSettings>()
+            .register_type::<pointer::StalePointerSettings>()
             .register_type::<pointer::PointerPress>()
+            .register_type::<[std::time::Duration; 3]>()
+            .register_type::<pointer::PointerCapture>()
+            .register_type::<pointer::PointerGrab>()
             .register_type::<pointer::PointerInteraction>()
+            .register_type::<focus::PickingInteraction>()
+            .register_type::<focus::PickingInteractionSource>()
             .register_type::<Pickable>()
+            .register_type::<PickTarget>()
+            .register_type::<PickingShape>()
+            .register_type::<PickableEvents>()
+            .register_type::<FocusPolicy>()
+            .register_type::<replay::PointerInputRecorder>()
+            .register_type::<replay::PointerInputPlayer>()
+            .register_type::<replay::PointerInputRecording>()
+            .register_type::<replay::RecordedInput>()
+            .register_type::<replay::PointerInput>()
+            .register_type::<pointer::InputMove>()
+            .register_type::<pointer::InputPress>()
+            .register_type::<pointer::InputScroll>()
+            .register_type::<pointer::PressDirection>()
+            .register_type::<Vec<replay::RecordedInput>>()
+            .register_type::<report::PickingConfigReport>()
+            .register_type::<report::EntityPickingConfig>()
+            .register_type::<report::PickingConfigChange>()
+            .register_type::<PickLayers>()
             .register_type::<PickingPluginsSettings>()
+            .register_type::<PickingEventSettings>()
+            .register_type::<backend::PickingCamera>()
             .register_type::<backend::ray::RayId>();
     }
 }
 
+/// Only runs picking while the app is in one of the given [`States`], on top of the
+/// [`PickingPluginsSettings`].
+///
+/// The [`PickSet::ProcessInput`], [`PickSet::Backend`], and [`PickSet::Focus`] sets only run when
+/// the current `S` is one of the plugin's states, and the [`PickingPluginsSettings`] allow them to
+/// run. Outside of these states, pointers stop moving and nothing is hovered or pressed, so no
+/// [`Pointer`](events::Pointer) events are sent. Input events that are sent meanwhile are not
+/// processed once picking resumes. Picking doesn't run at all until the [`State<S>`] resource is
+/// added, such as by [`App::init_state`].
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_picking_core::*;
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum AppState {
+///     #[default]
+///     Loading,
+///     MainMenu,
+///     InGame,
+/// }
+///
+/// App::new().init_state::<AppState>().add_plugins((
+///     CorePlugin,
+///     InteractionPlugin,
+///     PickingStatePlugin::new(AppState::MainMenu).with_state(AppState::InGame),
+/// ));
+/// ```
+///
+/// ### Multiple states
+///
+/// List every state picking should run in on a single plugin with [`Self::with_state`], as above.
+/// This plugin can only be added once for each type of state. When it is added for several types
+/// of states, picking only runs while all of them allow it.
+pub struct PickingStatePlugin<S: States> {
+    states: Vec<S>,
+}
+
+impl<S: States> PickingStatePlugin<S> {
+    /// Runs picking only while the app is in `state`.
+    pub fn new(state: S) -> Self {
+        Self {
+            states: vec![state],
+        }
+    }
+
+    /// Also runs picking while the app is in `state`.
+    pub fn with_state(mut self, state: S) -> Self {
+        self.states.push(state);
+        self
+    }
+}
+
+impl<S: States> Plugin for PickingStatePlugin<S> {
+    fn build(&self, app: &mut App) {
+        let states = self.states.clone();
+        let in_states = move |state: Option<Res<State<S>>>| {
+            state.is_some_and(|state| states.contains(state.get()))
+        };
+        for set in [PickSet::ProcessInput, PickSet::Backend, PickSet::Focus] {
+            app.configure_sets(PreUpdate, set.run_if(in_states.clone()));
+        }
+    }
+}
+
+/// Calls `$macro` with its arguments, followed by a `;` and every built-in
+/// [`Pointer`](events::Pointer) event payload, so that each list of the event kinds is generated
+/// from this one.
+macro_rules! with_pointer_event_kinds {
+    ($macro:ident!($($args:tt)*)) => {
+        $macro! {
+            $($args)*;
+            Over,
+            Out,
+            HoverStart,
+            Down,
+            Up,
+            Click,
+            DoubleClick,
+            LongPress,
+            Pinch,
+            Rotate,
+            Move,
+            Scroll,
+            DragStart,
+            Drag,
+            DragEnd,
+            DragEnter,
+            DragOver,
+            DragLeave,
+            Drop,
+            DragViewportExit,
+            DragViewportEnter,
+            Idle,
+            Wake,
+            WindowEnter,
+            WindowLeave,
+            AnyPointerEvent
+        }
+    };
+}
+
+/// Implements [`PointerEventKind`] for each [`Pointer`](events::Pointer) event payload.
+macro_rules! impl_pointer_event_kinds {
+    (; $($kind:ident),*) => {
+        $(
+            impl sealed::Sealed for events::$kind {}
+            impl PointerEventKind for events::$kind {}
+        )*
+    };
+}
+
+/// Returns the [`TypeId`](std::any::TypeId)s of the [`Pointer`](events::Pointer) event payloads.
+macro_rules! pointer_event_type_ids {
+    (; $($kind:ident),*) => {
+        [$(std::any::TypeId::of::<events::$kind>()),*]
+    };
+}
+
+/// Registers the types of each [`Pointer`](events::Pointer) event kind and its payload for
+/// reflection.
+macro_rules! register_pointer_events {
+    ($app:expr; $($kind:ident),*) => {
+        $(
+            $app.register_type::<events::Pointer<events::$kind>>()
+                .register_type::<events::$kind>();
+        )*
+    };
+}
+
+/// Adds the listener plugin of each [`Pointer`](events::Pointer) event kind that `$builder`
+/// listens to, and only the event itself for the others.
+macro_rules! add_pointer_event_listeners {
+    ($app:expr, $builder:expr; $($kind:ident),*) => {
+        $(
+            if $builder.listens::<events::$kind>() {
+                $app.add_plugins(events::PointerListenerPlugin::<events::$kind>::default());
+            } else {
+                $app.add_event::<events::Pointer<events::$kind>>();
+            }
+        )*
+    };
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A built-in [`Pointer`](events::Pointer) event payload, whose dispatch to listeners can be
+/// configured with an [`InteractionPluginBuilder`].
+///
+/// This trait is sealed, and can't be implemented outside of this crate.
+pub trait PointerEventKind: sealed::Sealed + 'static {}
+
+with_pointer_event_kinds!(impl_pointer_event_kinds!());
+
 /// Generates [`Pointer`](events::Pointer) events and handles event bubbling.
+///
+/// Every kind of pointer event is dispatched to [`On`] listeners. Use [`InteractionPlugin::builder`]
+/// to only dispatch the kinds an app listens to.
+///
+/// [`On`]: bevy_eventlistener::prelude::On
 pub struct InteractionPlugin;
+
+impl InteractionPlugin {
+    /// Returns an [`InteractionPluginBuilder`] that dispatches every kind of pointer event, like
+    /// this plugin.
+    pub fn builder() -> InteractionPluginBuilder {
+        InteractionPluginBuilder::default()
+    }
+}
+
 impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        InteractionPluginBuilder::default().build(app);
+    }
+}
+
+/// A configurable [`InteractionPlugin`], which only dispatches some kinds of
+/// [`Pointer`](events::Pointer) events to [`On`] listeners. Add it instead of the
+/// [`InteractionPlugin`], not alongside it.
+///
+/// Focus, hover, and every event are still computed and sent as usual, so event readers, and
+/// systems such as the [`PickingInteraction`](focus::PickingInteraction) updates, work unchanged.
+/// Only the listener plugin of each skipped kind is left out, so its bubbling systems don't run,
+/// and its [`On`] listeners are never called.
+///
+/// When the [`InteractionPlugin`] is added by a plugin group, disable it in the group and add the
+/// builder in its place.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_picking_core::{events::*, *};
+/// App::new().add_plugins((
+///     CorePlugin,
+///     InteractionPlugin::builder()
+///         .none()
+///         .with::<Over>()
+///         .with::<Out>()
+///         .with::<Click>(),
+/// ));
+/// ```
+///
+/// [`On`]: bevy_eventlistener::prelude::On
+#[derive(Debug, Clone)]
+pub struct InteractionPluginBuilder {
+    listened: bevy_utils::HashSet<std::any::TypeId>,
+}
+
+impl Default for InteractionPluginBuilder {
+    fn default() -> Self {
+        Self {
+            listened: with_pointer_event_kinds!(pointer_event_type_ids!())
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl InteractionPluginBuilder {
+    /// Stops dispatching every kind of pointer event, so only the kinds added back with
+    /// [`Self::with`] are dispatched.
+    pub fn none(mut self) -> Self {
+        self.listened.clear();
+        self
+    }
+
+    /// Dispatches [`Pointer<E>`](events::Pointer) events to listeners.
+    pub fn with<E: PointerEventKind>(mut self) -> Self {
+        self.listened.insert(std::any::TypeId::of::<E>());
+        self
+    }
+
+    /// Stops dispatching [`Pointer<E>`](events::Pointer) events to listeners.
+    pub fn without<E: PointerEventKind>(mut self) -> Self {
+        self.listened.remove(&std::any::TypeId::of::<E>());
+        self
+    }
+
+    /// Returns `true` if [`Pointer<E>`](events::Pointer) events are dispatched to listeners.
+    pub fn listens<E: PointerEventKind>(&self) -> bool {
+        self.listened.contains(&std::any::TypeId::of::<E>())
+    }
+}
+
+impl Plugin for InteractionPluginBuilder {
     fn build(&self, app: &mut App) {
         use events::*;
-        use focus::{update_focus, update_interactions};
+        use focus::{apply_pointer_captures, update_focus, update_interactions};
 
         app.init_resource::<focus::HoverMap>()
+            .init_resource::<focus::AllHitsMap>()
+            .init_resource::<focus::DepthComparison>()
+            .init_resource::<focus::ExclusivePointer>()
             .init_resource::<focus::PreviousHoverMap>()
+            .init_resource::<focus::EventHoverMaps>()
+            .init_resource::<focus::PreviousEventHoverMaps>()
+            .init_resource::<focus::FocusBuffers>()
+            .init_resource::<focus::LateHits>()
+            .init_resource::<backend::BackendPriority>()
+            .init_resource::<backend::BackendDepthBias>()
+            .init_resource::<focus::FocusHysteresis>()
+            .init_resource::<PickingEventSettings>()
             .init_resource::<DragMap>()
+            .init_resource::<DragOverMap>()
+            .init_resource::<diagnostics::PickingDiagnostics>()
+            .init_resource::<DragSettings>()
+            .init_resource::<ClickSettings>()
+            .init_resource::<LongPressSettings>()
+            .init_resource::<GestureExclusions>()
+            .init_resource::<PointerMovedSettings>()
+            .init_resource::<MoveThrottle>()
+            .init_resource::<MoveDeltaSettings>()
+            .init_resource::<IdleSettings>()
+            .init_resource::<ScrollMap>()
+            .init_resource::<ScrollSettings>()
+            .init_resource::<ScrollMomentum>()
+            .init_resource::<ScrollMomentumMap>()
+            .init_resource::<PointerBubbling>()
+            .init_resource::<WithheldPointerEvents<Over>>()
+            .init_resource::<WithheldPointerEvents<Move>>()
+            .init_resource::<WithheldPointerEvents<Out>>()
+            .init_resource::<WithheldPointerEvents<Down>>()
+            .init_resource::<WithheldPointerEvents<Up>>()
+            .init_resource::<bevy_time::Time<bevy_time::Real>>()
             .add_event::<PointerCancel>()
+            .add_event::<PointerMoved>()
+            .add_event::<PointerDownGlobal>()
             .add_systems(
                 PreUpdate,
                 (
+                    cancel_despawned_interactions,
                     update_focus,
+                    apply_pointer_captures,
                     pointer_events,
+                    send_hover_start_events,
                     update_interactions,
+                    send_scroll_events,
                     send_click_and_drag_events,
+                    send_long_press_events,
+                    send_touch_gesture_events,
                     send_drag_over_events,
+                    send_drag_viewport_events,
                 )
                     .chain()
                     .in_set(PickSet::Focus),
             )
-            .add_plugins((
-                EventListenerPlugin::<Pointer<Over>>::default(),
-                EventListenerPlugin::<Pointer<Out>>::default(),
-                EventListenerPlugin::<Pointer<Down>>::default(),
-                EventListenerPlugin::<Pointer<Up>>::default(),
-                EventListenerPlugin::<Pointer<Click>>::default(),
-                EventListenerPlugin::<Pointer<Move>>::default(),
-                EventListenerPlugin::<Pointer<DragStart>>::default(),
-                EventListenerPlugin::<Pointer<Drag>>::default(),
-                EventListenerPlugin::<Pointer<DragEnd>>::default(),
-                EventListenerPlugin::<Pointer<DragEnter>>::default(),
-                EventListenerPlugin::<Pointer<DragOver>>::default(),
-                EventListenerPlugin::<Pointer<DragLeave>>::default(),
-                EventListenerPlugin::<Pointer<Drop>>::default(),
-            ));
+            .add_systems(
+                PreUpdate,
+                send_pointer_moved_events
+                    .run_if(PointerMovedSettings::should_run)
+                    .in_set(PickSet::Focus),
+            )
+            .add_systems(
+                PreUpdate,
+                send_pointer_down_global_events
+                    .after(update_interactions)
+                    .in_set(PickSet::Focus),
+            )
+            .add_systems(PreUpdate, send_idle_events.in_set(PickSet::Focus))
+            .add_systems(PreUpdate, send_window_events.in_set(PickSet::Focus))
+            .add_systems(
+                PreUpdate,
+                pointer::release_pointer_captures.in_set(PickSet::PostFocus),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    (
+                        forward_any_pointer_events::<Over>,
+                        forward_any_pointer_events::<Out>,
+                        forward_any_pointer_events::<HoverStart>,
+                        forward_any_pointer_events::<Down>,
+                        forward_any_pointer_events::<Up>,
+                        forward_any_pointer_events::<Click>,
+                        forward_any_pointer_events::<DoubleClick>,
+                        forward_any_pointer_events::<LongPress>,
+                        forward_any_pointer_events::<Pinch>,
+                        forward_any_pointer_events::<Rotate>,
+                        forward_any_pointer_events::<Move>,
+                    )
+                        .chain(),
+                    (
+                        forward_any_pointer_events::<Scroll>,
+                        forward_any_pointer_events::<DragStart>,
+                        forward_any_pointer_events::<Drag>,
+                        forward_any_pointer_events::<DragEnd>,
+                        forward_any_pointer_events::<DragEnter>,
+                        forward_any_pointer_events::<DragOver>,
+                        forward_any_pointer_events::<DragLeave>,
+                        forward_any_pointer_events::<Drop>,
+                        forward_any_pointer_events::<DragViewportExit>,
+                        forward_any_pointer_events::<DragViewportEnter>,
+                        forward_any_pointer_events::<Idle>,
+                        forward_any_pointer_events::<Wake>,
+                        forward_any_pointer_events::<WindowEnter>,
+                        forward_any_pointer_events::<WindowLeave>,
+                    )
+                        .chain(),
+                    diagnostics::update_picking_diagnostics,
+                )
+                    .chain()
+                    .in_set(PickSet::PostFocus),
+            )
+            .register_type::<focus::HoverGroup>()
+            .register_type::<diagnostics::PickingDiagnostics>()
+            .register_type::<DragPlane>()
+            .register_type::<DragModifier>()
+            .register_type::<ClickSettings>()
+            .register_type::<LongPressSettings>()
+            .register_type::<ClickEmission>()
+            .register_type::<ClickCooldown>()
+            .register_type::<BubbleBoundary>()
+            .register_type::<PointerBubbling>()
+            .register_type::<HoverDelay>()
+            .register_type::<DragSettings>()
+            .register_type::<GestureExclusions>()
+            .register_type::<ScrollSettings>()
+            .register_type::<ScrollMomentum>()
+            .register_type::<PointerMovedSettings>()
+            .register_type::<MoveThrottle>()
+            .register_type::<MoveDeltaSettings>()
+            .register_type::<IdleSettings>()
+            .register_type::<focus::DepthComparison>()
+            .register_type::<backend::BackendPriority>()
+            .register_type::<backend::BackendDepthBias>()
+            .register_type::<focus::FocusHysteresis>()
+            .register_type::<focus::ExclusivePointer>()
+            .register_type::<PointerCancel>()
+            .register_type::<PointerMoved>()
+            .register_type::<PointerDownGlobal>()
+            .register_type::<ViewportEdges>()
+            .register_type::<Option<backend::HitData>>()
+            .register_type::<[pointer::PointerId; 2]>();
+
+        with_pointer_event_kinds!(add_pointer_event_listeners!(app, self));
+        with_pointer_event_kinds!(register_pointer_events!(app));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pick_layers_add_and_remove_layers() {
+        let layers = PickLayers::layer(0).with(31);
+        assert_eq!(layers, PickLayers(1 | 1 << 31));
+        assert!(layers.intersects(&PickLayers::layer(31)));
+        assert!(!layers.without(31).intersects(&PickLayers::layer(31)));
+        assert!(!layers.intersects(&PickLayers::NONE));
+        assert!(layers.intersects(&PickLayers::ALL));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pick_layers_reject_out_of_range_layers() {
+        PickLayers::NONE.with(PickLayers::TOTAL_LAYERS);
     }
 }
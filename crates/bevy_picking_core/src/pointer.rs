@@ -1,22 +1,37 @@
 //! Types and systems for pointer inputs, such as position and buttons.
 
-use bevy_ecs::prelude::*;
+use bevy_core::FrameCount;
+use bevy_ecs::{
+    entity::{EntityMapper, MapEntities},
+    event::ManualEventReader,
+    prelude::*,
+    system::SystemParam,
+};
+use bevy_hierarchy::DespawnRecursiveExt;
+use bevy_input::{keyboard::KeyCode, mouse::MouseButton, ButtonInput};
 use bevy_math::{Rect, Vec2};
 use bevy_reflect::prelude::*;
 use bevy_render::camera::{Camera, NormalizedRenderTarget};
-use bevy_utils::{HashMap, Uuid};
-use bevy_window::PrimaryWindow;
+use bevy_time::{Real, Time};
+use bevy_utils::{HashMap, HashSet, Uuid};
+use bevy_window::{PrimaryWindow, Window, WindowRef, WindowResized, WindowScaleFactorChanged};
 
-use std::{fmt::Debug, ops::Deref};
+use std::{collections::VecDeque, fmt::Debug, ops::Deref, sync::Arc, time::Duration};
 
-use crate::backend::HitData;
+use crate::backend::{HitData, LateHitSettings};
 
 /// Identifies a unique pointer entity. `Mouse` and `Touch` pointers are automatically spawned.
 ///
 /// This component is needed because pointers can be spawned and despawned, but they need to have a
 /// stable ID that persists regardless of the Entity they are associated with.
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, Component, Reflect)]
+///
+/// The mouse and each touch are always separate pointers, even on devices that support both. This
+/// keeps multi-touch working, and means pointer events for both input types are handled by the
+/// same listeners. Apps that only care about a single pointer can use [`ActivePointer`] instead of
+/// matching on the `PointerId`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Component, Reflect)]
 #[reflect(Component, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointerId {
     /// The mouse pointer.
     #[default]
@@ -53,6 +68,10 @@ impl PointerId {
 
 /// Holds a list of entities this pointer is currently interacting with, sorted from nearest to
 /// farthest.
+///
+/// The list only contains hovered entities, sorted by [`HitData::depth`], so the first entry is the
+/// entity directly under the pointer. Use [`Self::nearest`] or [`Self::nearest_entity`] to get it,
+/// and [`Self::contains`] to check if an entity is hovered by this pointer.
 #[derive(Debug, Default, Clone, Component, Reflect)]
 #[reflect(Component, Default)]
 pub struct PointerInteraction {
@@ -64,6 +83,26 @@ impl PointerInteraction {
     pub fn get_nearest_hit(&self) -> Option<&(Entity, HitData)> {
         self.sorted_entities.first()
     }
+
+    /// Returns the nearest hovered entity, the one directly under the pointer, and its hit data.
+    /// Returns `None` if nothing is hovered.
+    pub fn nearest(&self) -> Option<(Entity, &HitData)> {
+        self.sorted_entities
+            .first()
+            .map(|(entity, hit)| (*entity, hit))
+    }
+
+    /// Returns the nearest hovered entity, the one directly under the pointer, if any.
+    pub fn nearest_entity(&self) -> Option<Entity> {
+        self.nearest().map(|(entity, _)| entity)
+    }
+
+    /// Returns `true` if `entity` is hovered by this pointer, at any depth.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.sorted_entities
+            .iter()
+            .any(|(hovered, _)| *hovered == entity)
+    }
 }
 
 impl Deref for PointerInteraction {
@@ -74,10 +113,11 @@ impl Deref for PointerInteraction {
     }
 }
 
-/// A resource that maps each [`PointerId`] to their [`Entity`] for easy lookups.
+/// A resource that maps each [`PointerId`] to their [`Entity`], and back, for easy lookups.
 #[derive(Debug, Clone, Default, Resource)]
 pub struct PointerMap {
     inner: HashMap<PointerId, Entity>,
+    ids: HashMap<Entity, PointerId>,
 }
 
 impl PointerMap {
@@ -85,25 +125,167 @@ impl PointerMap {
     pub fn get_entity(&self, pointer_id: PointerId) -> Option<Entity> {
         self.inner.get(&pointer_id).copied()
     }
+
+    /// Get the [`PointerId`] of the supplied pointer [`Entity`].
+    pub fn get_id(&self, entity: Entity) -> Option<PointerId> {
+        self.ids.get(&entity).copied()
+    }
 }
 
 /// Update the [`PointerMap`] resource with the current frame's data.
 pub fn update_pointer_map(pointers: Query<(Entity, &PointerId)>, mut map: ResMut<PointerMap>) {
     map.inner.clear();
+    map.ids.clear();
     for (entity, id) in &pointers {
         map.inner.insert(*id, entity);
+        map.ids.insert(entity, *id);
+    }
+}
+
+/// Settings for despawning pointers that have stopped receiving input, such as custom pointers whose
+/// input source has gone away. See [`despawn_stale_pointers`].
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct StalePointerSettings {
+    /// How long a pointer can go without an [`InputMove`] or [`InputPress`] before it is despawned.
+    /// Mouse pointers are never despawned. Disabled when `None`, which is the default.
+    pub timeout: Option<Duration>,
+}
+
+/// Despawns pointers that have not received an [`InputMove`] or [`InputPress`] within the
+/// [`StalePointerSettings::timeout`]. A pointer's timeout starts when it is spawned, and restarts
+/// with each input. Despawned pointers are removed from the [`PointerMap`] when it is next updated,
+/// and from the [`HoverMap`](crate::focus::HoverMap) when focus is next updated.
+pub fn despawn_stale_pointers(
+    mut commands: Commands,
+    settings: Res<StalePointerSettings>,
+    time: Res<Time<Real>>,
+    mut moves: EventReader<InputMove>,
+    mut presses: EventReader<InputPress>,
+    pointers: Query<(Entity, &PointerId)>,
+    mut last_inputs: Local<HashMap<PointerId, Duration>>,
+) {
+    let now = time.elapsed();
+    let inputs = moves
+        .read()
+        .map(|input| input.pointer_id)
+        .chain(presses.read().map(|input| input.pointer_id));
+    for pointer_id in inputs {
+        last_inputs.insert(pointer_id, now);
+    }
+    last_inputs.retain(|pointer_id, _| pointers.iter().any(|(_, id)| id == pointer_id));
+
+    let Some(timeout) = settings.timeout else {
+        return;
+    };
+    for (entity, pointer_id) in pointers.iter().filter(|(_, id)| !id.is_mouse()) {
+        let last_input = *last_inputs.entry(*pointer_id).or_insert(now);
+        if now.saturating_sub(last_input) > timeout {
+            commands.entity(entity).despawn_recursive();
+            last_inputs.remove(pointer_id);
+        }
+    }
+}
+
+/// Tracks the most recently active pointer, regardless of whether it is a mouse, touch, or custom
+/// pointer.
+///
+/// A pointer becomes active when it moves or presses a button. When both happen for different
+/// pointers in the same frame, the last button press wins. The active pointer is kept even after it
+/// is despawned, such as when a touch ends, until another pointer becomes active.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct ActivePointer {
+    pointer_id: Option<PointerId>,
+}
+
+impl ActivePointer {
+    /// Returns the [`PointerId`] of the most recently active pointer, if any pointer has been used.
+    pub fn get(&self) -> Option<PointerId> {
+        self.pointer_id
+    }
+
+    /// Returns `true` if `pointer_id` is the most recently active pointer.
+    pub fn is_active(&self, pointer_id: PointerId) -> bool {
+        self.pointer_id == Some(pointer_id)
+    }
+
+    /// Returns the [`Entity`] of the most recently active pointer, if it still exists.
+    pub fn entity(&self, pointer_map: &PointerMap) -> Option<Entity> {
+        pointer_map.get_entity(self.pointer_id?)
+    }
+
+    /// Returns the current [`Location`] of the most recently active pointer, if it still exists and
+    /// has a location.
+    pub fn location<'a>(
+        &self,
+        pointer_map: &PointerMap,
+        pointers: &'a Query<&PointerLocation>,
+    ) -> Option<&'a Location> {
+        pointers.get(self.entity(pointer_map)?).ok()?.location()
+    }
+}
+
+/// Updates the [`ActivePointer`] resource from pointer input events.
+pub fn update_active_pointer(
+    mut active_pointer: ResMut<ActivePointer>,
+    mut input_moves: EventReader<InputMove>,
+    mut input_presses: EventReader<InputPress>,
+    disabled: DisabledPointers,
+) {
+    let is_enabled = |pointer_id: &PointerId| !disabled.contains(*pointer_id);
+    let last_move = input_moves
+        .read()
+        .map(|moved| moved.pointer_id)
+        .filter(is_enabled)
+        .last();
+    let last_press = input_presses
+        .read()
+        .map(|press| press.pointer_id)
+        .filter(is_enabled)
+        .last();
+    if let Some(pointer_id) = last_press.or(last_move) {
+        if active_pointer.pointer_id != Some(pointer_id) {
+            active_pointer.pointer_id = Some(pointer_id);
+        }
     }
 }
 
-/// Tracks the state of the pointer's buttons in response to [`InputPress`]s.
+/// Tracks the state of the pointer's buttons in response to [`InputPress`]s, and how long each
+/// button has been held.
 #[derive(Debug, Default, Clone, Component, Reflect, PartialEq, Eq)]
 #[reflect(Component, Default)]
 pub struct PointerPress {
     primary: bool,
     secondary: bool,
     middle: bool,
+    /// When each button was last pressed, indexed by [`PointerPress::index`].
+    pressed_at: [Duration; 3],
+    /// How long each button has been held, or was held for if it has been released.
+    held: [Duration; 3],
 }
 impl PointerPress {
+    fn index(button: PointerButton) -> usize {
+        match button {
+            PointerButton::Primary => 0,
+            PointerButton::Secondary => 1,
+            PointerButton::Middle => 2,
+        }
+    }
+
+    /// Returns how long `button` has been held, as of the latest [`InputPress::receive`]. Once the
+    /// button is released, this is how long it was held for, until it is pressed again.
+    #[inline]
+    pub fn held_duration(&self, button: PointerButton) -> Duration {
+        self.held[Self::index(button)]
+    }
+
+    /// Releases every button without sending any events, for when the pointer's input is lost, for
+    /// example when its window loses focus. The held durations are reset.
+    pub fn cancel(&mut self) {
+        *self = Self::default();
+    }
+
     /// Returns true if the primary pointer button is pressed.
     #[inline]
     pub fn is_primary_pressed(&self) -> bool {
@@ -127,10 +309,99 @@ impl PointerPress {
     pub fn is_any_pressed(&self) -> bool {
         self.primary || self.middle || self.secondary
     }
+
+    /// Returns true if the given pointer button is pressed.
+    #[inline]
+    pub fn is_pressed(&self, button: PointerButton) -> bool {
+        match button {
+            PointerButton::Primary => self.primary,
+            PointerButton::Secondary => self.secondary,
+            PointerButton::Middle => self.middle,
+        }
+    }
+}
+
+/// Captures a pointer, so that it only hovers the given entity regardless of what backends report,
+/// like `setPointerCapture` in browsers. Insert this on a pointer entity, which can be found with
+/// the [`PointerMap`].
+///
+/// While captured, the pointer's `Move` and `Up` events, and any press or drag it starts, target
+/// the captured entity, even when the pointer moves off of it. This keeps widgets like sliders
+/// tracking the pointer when it overshoots. Hit data is taken from the backends when they report
+/// the captured entity, and otherwise the last hit on it is reused. If the captured entity has
+/// never been hit by the pointer, the pointer hovers normally until it is.
+///
+/// The capture is released, and this component removed, once the pointer has no buttons pressed
+/// or the captured entity is despawned. Capture the pointer while a button is held, for example
+/// in a `Down` or `DragStart` listener.
+#[derive(Debug, Clone, Copy, Component, Reflect, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct PointerCapture(pub Entity);
+
+/// Removes [`PointerCapture`]s once their pointer has released all buttons, or their captured
+/// entity no longer exists.
+pub fn release_pointer_captures(
+    mut commands: Commands,
+    pointers: Query<(Entity, &PointerPress, &PointerCapture)>,
+    entities: Query<()>,
+) {
+    for (pointer, press, capture) in &pointers {
+        if !press.is_any_pressed() || entities.get(capture.0).is_err() {
+            commands.entity(pointer).remove::<PointerCapture>();
+        }
+    }
+}
+
+/// Add this component to a pointer entity to grab the pointer, for example to switch from UI
+/// picking to mouselook. Remove it to release the grab.
+///
+/// While the pointer is grabbed, its [`PointerLocation`] stays pinned where it was grabbed, and it
+/// does not hover any entities, so the entities it was hovering receive `Out` events. Its
+/// [`InputMove`] events are still sent, and their [`delta`](InputMove::delta) reports the relative
+/// motion of the pointer. When the grab is released, the pointer hovers entities at its pinned
+/// location as usual, until it moves again.
+///
+/// The default mouse input reads relative motion for a grabbed mouse pointer, so the cursor can be
+/// locked with the window's `CursorGrabMode` during the grab.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct PointerGrab;
+
+/// Add this component to a pointer entity to disable it, without despawning it, for example to
+/// ignore a scripted pointer during a cutscene while the mouse keeps picking. Remove it to enable
+/// the pointer again.
+///
+/// The input events of a disabled pointer are ignored by the picking plugins, so its
+/// [`PointerLocation`] and [`PointerPress`] are not updated, and it does not hover any entities or
+/// send any pointer events. When a pointer is disabled, the entities it was hovering receive `Out`
+/// events, its drags end, and its pressed buttons are released without sending events. Once it is
+/// enabled again, it hovers entities at its current location as usual.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct PointerDisabled;
+
+/// A [`SystemParam`] for checking which pointers have a [`PointerDisabled`] component, so systems
+/// that read pointer input events can ignore the events of disabled pointers.
+#[derive(SystemParam)]
+pub struct DisabledPointers<'w, 's> {
+    pointers: Query<'w, 's, &'static PointerId, With<PointerDisabled>>,
+}
+
+impl DisabledPointers<'_, '_> {
+    /// Returns `true` if the pointer with this id is disabled.
+    pub fn contains(&self, pointer_id: PointerId) -> bool {
+        self.pointers.iter().any(|id| *id == pointer_id)
+    }
+
+    /// Iterates over the ids of the disabled pointers.
+    pub fn iter(&self) -> impl Iterator<Item = PointerId> + '_ {
+        self.pointers.iter().copied()
+    }
 }
 
 /// Pointer input event for button presses. Fires when a pointer button changes state.
 #[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct InputPress {
     /// The [`PointerId`] of the pointer that pressed a button.
     pub pointer_id: PointerId,
@@ -170,28 +441,245 @@ impl InputPress {
         self.button == button && self.direction == PressDirection::Up
     }
 
-    /// Receives [`InputPress`] events and updates corresponding [`PointerPress`] components.
+    /// Receives [`InputPress`] events and updates corresponding [`PointerPress`] components,
+    /// including the held duration of every pressed button. Events are debounced by the
+    /// [`InputDebounce`].
     pub fn receive(
-        mut events: EventReader<InputPress>,
-        mut pointers: Query<(&PointerId, &mut PointerPress)>,
+        mut events: DebouncedPresses,
+        mut pointers: Query<(&PointerId, &mut PointerPress, Has<PointerDisabled>)>,
+        time: Res<Time<Real>>,
     ) {
+        let now = time.elapsed();
+        for (_, mut pointer, is_disabled) in &mut pointers {
+            if is_disabled {
+                if PointerButton::iter().any(|button| pointer.is_pressed(button)) {
+                    pointer.cancel();
+                }
+                continue;
+            }
+            // Held durations change every frame, so they do not trigger change detection.
+            let pointer = pointer.bypass_change_detection();
+            for button in PointerButton::iter() {
+                if pointer.is_pressed(button) {
+                    let index = PointerPress::index(button);
+                    pointer.held[index] = now.saturating_sub(pointer.pressed_at[index]);
+                }
+            }
+        }
+
         for input_press_event in events.read() {
-            pointers.iter_mut().for_each(|(pointer_id, mut pointer)| {
-                if *pointer_id == input_press_event.pointer_id {
-                    let is_down = input_press_event.direction == PressDirection::Down;
-                    match input_press_event.button {
-                        PointerButton::Primary => pointer.primary = is_down,
-                        PointerButton::Secondary => pointer.secondary = is_down,
-                        PointerButton::Middle => pointer.middle = is_down,
+            pointers
+                .iter_mut()
+                .for_each(|(pointer_id, mut pointer, is_disabled)| {
+                    if *pointer_id == input_press_event.pointer_id && !is_disabled {
+                        let is_down = input_press_event.direction == PressDirection::Down;
+                        let index = PointerPress::index(input_press_event.button);
+                        if is_down {
+                            pointer.pressed_at[index] = now;
+                        }
+                        if is_down || pointer.is_pressed(input_press_event.button) {
+                            pointer.held[index] = now.saturating_sub(pointer.pressed_at[index]);
+                        }
+                        match input_press_event.button {
+                            PointerButton::Primary => pointer.primary = is_down,
+                            PointerButton::Secondary => pointer.secondary = is_down,
+                            PointerButton::Middle => pointer.middle = is_down,
+                        }
                     }
-                }
-            })
+                })
+        }
+    }
+}
+
+/// Debounces pointer buttons, for input devices that bounce, quickly releasing and pressing a
+/// button again when it is only pressed once, which sends phantom clicks.
+///
+/// A release followed by a press of the same button of the same pointer within the
+/// [`window`](Self::window) is ignored, so the button stays pressed. To tell a bounce from a
+/// release, every release is held back until the window has passed, so while debouncing is enabled,
+/// releases are reported up to a window later, usually on the next frame. Presses are never
+/// delayed, and clicks that are slower than the window are unaffected.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct InputDebounce {
+    /// The longest time between a release and a press of a button that is considered a bounce.
+    /// Disabled when zero, which is the default.
+    pub window: Duration,
+}
+
+/// The state of the [`InputDebounce`]: the [`InputPress`] events ignored this frame, and the
+/// releases held back until their debounce window has passed.
+#[derive(Debug, Default, Resource)]
+pub struct DebouncedPressState {
+    reader: ManualEventReader<InputPress>,
+    /// The ids of the events ignored this frame and the previous one, as long as events are kept.
+    /// The events kept are sent again after them.
+    ignored: [std::ops::Range<usize>; 2],
+    /// The releases held back, with the time they were received at.
+    pending: Vec<(InputPress, Duration)>,
+}
+
+/// Debounces the [`InputPress`] events sent this frame, as configured by the [`InputDebounce`].
+///
+/// Events are only changed on frames where a bounce is ignored or a release is held back or
+/// reported. Then, every event of the frame is ignored by the readers of [`DebouncedPresses`], and
+/// the debounced events are sent again, in order.
+pub fn debounce_input_presses(
+    mut events: ResMut<Events<InputPress>>,
+    debounce: Res<InputDebounce>,
+    time: Res<Time<Real>>,
+    mut state: ResMut<DebouncedPressState>,
+) {
+    let now = time.elapsed();
+    let window = debounce.window;
+    let DebouncedPressState {
+        reader,
+        ignored,
+        pending,
+    } = &mut *state;
+    ignored.swap(0, 1);
+    ignored[0] = 0..0;
+    let received: Vec<_> = reader.read_with_id(&events).collect();
+    if window.is_zero() && pending.is_empty() {
+        return;
+    }
+
+    let mut debounced = Vec::new();
+    pending.retain(|(release, released_at)| {
+        let is_expired = window.is_zero() || now.saturating_sub(*released_at) > window;
+        if is_expired {
+            debounced.push(*release);
+        }
+        !is_expired
+    });
+    let mut is_changed = !debounced.is_empty();
+    for (press, _) in &received {
+        let is_bounce = |(release, _): &(InputPress, Duration)| {
+            release.pointer_id == press.pointer_id && release.button == press.button
+        };
+        match press.direction {
+            PressDirection::Down if pending.iter().any(is_bounce) => {
+                pending.retain(|pending| !is_bounce(pending));
+                is_changed = true;
+            }
+            PressDirection::Up if !window.is_zero() => {
+                pending.push((**press, now));
+                is_changed = true;
+            }
+            _ => debounced.push(**press),
+        }
+    }
+    if !is_changed {
+        return;
+    }
+
+    if let (Some((_, first)), Some((_, last))) = (received.first(), received.last()) {
+        ignored[0] = first.id..last.id + 1;
+    }
+    for press in debounced {
+        events.send(press);
+    }
+    reader.clear(&events);
+}
+
+/// A [`SystemParam`] for reading [`InputPress`] events, debounced by the [`InputDebounce`].
+#[derive(SystemParam)]
+pub struct DebouncedPresses<'w, 's> {
+    presses: EventReader<'w, 's, InputPress>,
+    state: Res<'w, DebouncedPressState>,
+}
+
+impl DebouncedPresses<'_, '_> {
+    /// Iterates over the debounced [`InputPress`] events that have not been read yet.
+    pub fn read(&mut self) -> impl Iterator<Item = &InputPress> {
+        let ignored = self.state.ignored.clone();
+        self.presses
+            .read_with_id()
+            .filter(move |(_, id)| !ignored.iter().any(|ignored| ignored.contains(&id.id)))
+            .map(|(press, _)| press)
+    }
+
+    /// Marks every [`InputPress`] event as read.
+    pub fn clear(&mut self) {
+        self.presses.clear();
+    }
+}
+
+/// The keyboard modifier keys held when a pointer event was sent. Only captured when the
+/// [`ModifierCapture`] is enabled, otherwise this is always empty.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyModifiers {
+    /// Either shift key is held.
+    pub shift: bool,
+    /// Either control key is held.
+    pub ctrl: bool,
+    /// Either alt key is held.
+    pub alt: bool,
+    /// Either super key is held, such as the Windows or Command key.
+    pub super_key: bool,
+}
+
+impl KeyModifiers {
+    /// No modifier keys are held.
+    pub const NONE: Self = Self {
+        shift: false,
+        ctrl: false,
+        alt: false,
+        super_key: false,
+    };
+
+    /// Returns the modifier keys held in `keys`.
+    pub fn from_keys(keys: &ButtonInput<KeyCode>) -> Self {
+        Self {
+            shift: keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            ctrl: keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            alt: keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+            super_key: keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+        }
+    }
+
+    /// Returns true if no modifier keys are held.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+/// Captures the [`KeyModifiers`] held when each [`InputPress`] is processed, and includes them in
+/// the [`Down`](crate::events::Down), [`Up`](crate::events::Up), [`Click`](crate::events::Click),
+/// and [`DragStart`](crate::events::DragStart) events it causes. The modifiers are read from the
+/// same frame as the press, so listeners don't need to read the keyboard themselves.
+///
+/// Disabled by default. Apps without a keyboard, where the `ButtonInput<KeyCode>` resource does not
+/// exist, always report empty modifiers.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct ModifierCapture {
+    /// Whether modifier keys are captured.
+    pub enabled: bool,
+}
+
+/// A [`SystemParam`] that returns the [`KeyModifiers`] currently held, as configured by the
+/// [`ModifierCapture`].
+#[derive(SystemParam)]
+pub struct CurrentModifiers<'w> {
+    capture: Res<'w, ModifierCapture>,
+    keys: Option<Res<'w, ButtonInput<KeyCode>>>,
+}
+
+impl CurrentModifiers<'_> {
+    /// Returns the modifier keys held, or [`KeyModifiers::NONE`] if they are not captured.
+    pub fn get(&self) -> KeyModifiers {
+        match &self.keys {
+            Some(keys) if self.capture.enabled => KeyModifiers::from_keys(keys),
+            _ => KeyModifiers::NONE,
         }
     }
 }
 
 /// The stage of the pointer button press event
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum PressDirection {
     /// The pointer button was just pressed
     Down,
@@ -201,6 +689,7 @@ pub enum PressDirection {
 
 /// The button that was just pressed or released
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum PointerButton {
     /// The primary pointer button
     Primary,
@@ -217,6 +706,36 @@ impl PointerButton {
     }
 }
 
+/// Converts a [`MouseButton`] into the [`PointerButton`] it drives: `Left` is `Primary`, `Right` is
+/// `Secondary`, and `Middle` is `Middle`.
+///
+/// Picking only tracks these three buttons, so the extra mouse buttons, `Back`, `Forward`, and any
+/// `Other(u16)`, have no equivalent and are returned as the error.
+impl TryFrom<MouseButton> for PointerButton {
+    type Error = MouseButton;
+
+    fn try_from(button: MouseButton) -> Result<Self, Self::Error> {
+        match button {
+            MouseButton::Left => Ok(PointerButton::Primary),
+            MouseButton::Right => Ok(PointerButton::Secondary),
+            MouseButton::Middle => Ok(PointerButton::Middle),
+            MouseButton::Back | MouseButton::Forward | MouseButton::Other(_) => Err(button),
+        }
+    }
+}
+
+/// Converts a [`PointerButton`] into the [`MouseButton`] that drives it, the inverse of
+/// `PointerButton::try_from`.
+impl From<PointerButton> for MouseButton {
+    fn from(button: PointerButton) -> Self {
+        match button {
+            PointerButton::Primary => MouseButton::Left,
+            PointerButton::Secondary => MouseButton::Right,
+            PointerButton::Middle => MouseButton::Middle,
+        }
+    }
+}
+
 /// Component that tracks a pointer's current [`Location`].
 #[derive(Debug, Default, Clone, Component, Reflect, PartialEq)]
 #[reflect(Component, Default)]
@@ -227,15 +746,407 @@ pub struct PointerLocation {
     pub location: Option<Location>,
 }
 impl PointerLocation {
+    /// Create a new [`PointerLocation`] at the given [`Location`].
+    pub fn new(location: Location) -> Self {
+        Self {
+            location: Some(location),
+        }
+    }
+
     /// Returns `Some(&`[`Location`]`)` if the pointer is active, or `None` if the pointer is
     /// inactive.
     pub fn location(&self) -> Option<&Location> {
         self.location.as_ref()
     }
+
+    /// Returns the [`Location`] that backends should use when hit testing this pointer, given its
+    /// [`SmoothedHitTestPosition`], if it has one. This is the same as
+    /// [`PointerLocation::location`], unless [`HitTestSmoothing`] is enabled, in which case the
+    /// position is replaced with the smoothed position.
+    ///
+    /// Events always report the raw location; only hit testing is smoothed.
+    pub fn hit_test_location(
+        &self,
+        smoothed: Option<&SmoothedHitTestPosition>,
+    ) -> Option<Location> {
+        let mut location = self.location.clone()?;
+        if let Some(position) = smoothed.and_then(SmoothedHitTestPosition::get) {
+            location.position = position;
+        }
+        Some(location)
+    }
+}
+
+/// The smoothed position of a pointer, used for hit testing instead of its [`PointerLocation`]
+/// when [`HitTestSmoothing`] is enabled. Inserted on pointers and updated by
+/// [`smooth_hit_test_positions`].
+#[derive(Debug, Default, Clone, Copy, Component, Reflect, PartialEq)]
+#[reflect(Component, Default)]
+pub struct SmoothedHitTestPosition(Option<Vec2>);
+
+impl SmoothedHitTestPosition {
+    /// Returns the smoothed position, or `None` when smoothing is disabled, or the pointer has no
+    /// location.
+    pub fn get(&self) -> Option<Vec2> {
+        self.0
+    }
+}
+
+/// Tracks the velocity of a pointer, in logical pixels per second, from its successive
+/// [`PointerLocation`]s. Updated each frame by [`update_pointer_velocities`].
+///
+/// The velocity is zero until the pointer has had a location for two frames. It is also reset when
+/// the pointer moves to a different render target, because positions on different targets cannot
+/// be compared.
+#[derive(Debug, Clone, Component, Reflect, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PointerVelocity {
+    /// How far back the [`smoothed`](Self::smoothed) velocity is averaged over. Defaults to
+    /// 100 ms.
+    pub smoothing_window: Duration,
+    /// The velocity over the last frame.
+    pub instantaneous: Vec2,
+    /// The average velocity over the [`smoothing_window`](Self::smoothing_window), which is less
+    /// noisy than the instantaneous velocity.
+    pub smoothed: Vec2,
+    /// The real time and position of the pointer in recent frames, oldest first.
+    #[reflect(ignore)]
+    samples: VecDeque<(Duration, Vec2)>,
+    /// The render target of the samples.
+    #[reflect(ignore)]
+    target: Option<NormalizedRenderTarget>,
+}
+
+impl Default for PointerVelocity {
+    fn default() -> Self {
+        Self {
+            smoothing_window: Duration::from_millis(100),
+            instantaneous: Vec2::ZERO,
+            smoothed: Vec2::ZERO,
+            samples: VecDeque::new(),
+            target: None,
+        }
+    }
+}
+
+impl PointerVelocity {
+    /// Forgets the pointer's past positions, and resets its velocity to zero.
+    fn reset(&mut self) {
+        self.instantaneous = Vec2::ZERO;
+        self.smoothed = Vec2::ZERO;
+        self.samples.clear();
+        self.target = None;
+    }
+}
+
+/// Updates the [`PointerVelocity`] of each pointer from its current [`PointerLocation`].
+pub fn update_pointer_velocities(
+    time: Res<Time<Real>>,
+    mut pointers: Query<(&PointerLocation, &mut PointerVelocity)>,
+) {
+    let now = time.elapsed();
+    for (location, mut velocity) in &mut pointers {
+        let Some(location) = location.location() else {
+            velocity.reset();
+            continue;
+        };
+        if velocity.target.as_ref() != Some(&location.target) {
+            velocity.reset();
+            velocity.target = Some(location.target.clone());
+        }
+
+        let velocity = &mut *velocity;
+        match velocity.samples.back().copied() {
+            // Time has not advanced, so replace the sample instead of dividing by zero.
+            Some((time, _)) if time == now => {
+                velocity.samples.pop_back();
+            }
+            Some((time, position)) => {
+                velocity.instantaneous =
+                    (location.position - position) / (now - time).as_secs_f32();
+            }
+            None => {}
+        }
+        velocity.samples.push_back((now, location.position));
+
+        // Keep the newest sample that is at least as old as the window, so the average covers it.
+        while velocity
+            .samples
+            .get(1)
+            .is_some_and(|(time, _)| now.saturating_sub(*time) >= velocity.smoothing_window)
+        {
+            velocity.samples.pop_front();
+        }
+        let (Some((oldest_time, oldest)), Some((newest_time, newest))) =
+            (velocity.samples.front(), velocity.samples.back())
+        else {
+            continue;
+        };
+        velocity.smoothed = if newest_time > oldest_time {
+            (*newest - *oldest) / (*newest_time - *oldest_time).as_secs_f32()
+        } else {
+            Vec2::ZERO
+        };
+    }
+}
+
+/// Expands the hit area of every entity tested against this pointer by a radius, in logical pixels.
+///
+/// This is useful for imprecise pointers, such as a virtual cursor driven by a gamepad stick, so
+/// they can be forgiving without making picking less precise for the mouse. Pointers without this
+/// component are hit tested exactly.
+///
+/// The radius is applied by backends while hit testing, on top of the bounds of each entity. It is
+/// supported by the sprite and UI backends. Raycasting backends test a single ray per pointer, and
+/// ignore it.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PointerHitRadius(pub f32);
+
+/// Turns this pointer into an area, or brush, with a radius in logical pixels. Every entity within
+/// the radius is hovered, instead of only the top-most entity under the pointer.
+///
+/// Backends that support it report every entity they find within the radius, and all of them are
+/// hovered, as long as they are [`Pickable::is_hoverable`](crate::Pickable::is_hoverable). Because
+/// the hits are spread over an area, [`Pickable::should_block_lower`](crate::Pickable) does not
+/// stop lower entities from being hovered by this pointer, though [`HoverGroup`]s still apply.
+/// The raycast backend supports it, by casting the sample rays of
+/// [`RayMap::area_rays`](crate::backend::ray::RayMap::area_rays). Backends that don't support it
+/// fall back to picking under the pointer's location.
+///
+/// Unlike [`PointerHitRadius`], which makes a pointer more forgiving while it still picks a single
+/// entity, this is meant for selecting or painting many entities at once.
+///
+/// [`HoverGroup`]: crate::focus::HoverGroup
+#[derive(Debug, Default, Clone, Copy, Component, Reflect, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PointerRadius(pub f32);
+
+impl PointerRadius {
+    /// Returns `true` if the radius is positive. Pointers with a radius of zero pick like pointers
+    /// without a radius.
+    pub fn is_area(&self) -> bool {
+        self.0 > 0.0
+    }
+}
+
+/// Applies a low-pass filter to pointer positions before they are used for hit testing. This can
+/// reduce hover flickering between adjacent thin entities caused by sub-pixel jitter from high
+/// frequency input devices, without affecting the positions reported in pointer events.
+///
+/// `factor` is the weight of the previous smoothed position, in the range `[0.0, 1.0)`. A value of
+/// `0.0`, the default, disables smoothing. Higher values smooth more, at the cost of hit testing
+/// lagging behind the pointer.
+#[derive(Debug, Default, Clone, Copy, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct HitTestSmoothing {
+    /// The weight of the previous smoothed position, in the range `[0.0, 1.0)`.
+    pub factor: f32,
+}
+
+/// Updates the [`SmoothedHitTestPosition`] of every pointer using the [`HitTestSmoothing`]
+/// settings, inserting it on pointers that don't have one yet.
+pub fn smooth_hit_test_positions(
+    mut commands: Commands,
+    smoothing: Res<HitTestSmoothing>,
+    mut pointers: Query<(
+        Entity,
+        &PointerLocation,
+        Option<&mut SmoothedHitTestPosition>,
+    )>,
+    mut last_targets: Local<HashMap<Entity, NormalizedRenderTarget>>,
+) {
+    let factor = smoothing.factor.clamp(0.0, 0.99);
+    last_targets.retain(|entity, _| pointers.contains(*entity));
+    for (entity, pointer, hit_test_position) in &mut pointers {
+        let previous = hit_test_position.as_ref().and_then(|position| position.0);
+        let smoothed = match pointer.location() {
+            None => {
+                last_targets.remove(&entity);
+                None
+            }
+            Some(location) => {
+                // Restart smoothing when the pointer moves to a new render target, so the smoothed
+                // position is never interpolated between two unrelated coordinate spaces.
+                let same_target = last_targets.get(&entity) == Some(&location.target);
+                last_targets.insert(entity, location.target.clone());
+                match previous {
+                    _ if factor == 0.0 => None,
+                    Some(previous) if same_target => {
+                        Some(previous.lerp(location.position, 1.0 - factor))
+                    }
+                    _ => Some(location.position),
+                }
+            }
+        };
+        if let Some(mut hit_test_position) = hit_test_position {
+            hit_test_position.set_if_neq(SmoothedHitTestPosition(smoothed));
+        } else if smoothed.is_some() {
+            commands
+                .entity(entity)
+                .insert(SmoothedHitTestPosition(smoothed));
+        }
+    }
+}
+
+/// The hit test locations of every pointer during the last few frames, used to check whether hits
+/// that a backend reports late are still valid. See [`LateHitSettings`].
+///
+/// Locations are recorded by [`record_pointer_location_history`], keyed by the [`FrameCount`] of
+/// the frame they were recorded in. Nothing is recorded if the [`FrameCount`] resource doesn't
+/// exist, such as when the `FrameCountPlugin` isn't added.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct PointerLocationHistory {
+    locations: HashMap<PointerId, VecDeque<(u32, Option<Location>)>>,
+}
+
+impl PointerLocationHistory {
+    /// Returns the hit test location the pointer had during `frame`, or `None` if the pointer had
+    /// no location then, or the frame is no longer recorded.
+    pub fn location_at(&self, pointer_id: PointerId, frame: u32) -> Option<&Location> {
+        self.locations
+            .get(&pointer_id)?
+            .iter()
+            .find(|(recorded, _)| *recorded == frame)?
+            .1
+            .as_ref()
+    }
+}
+
+/// Records the hit test location of every pointer in the [`PointerLocationHistory`], keeping as
+/// many frames as the [`LateHitSettings`] need.
+pub fn record_pointer_location_history(
+    frame_count: Option<Res<FrameCount>>,
+    settings: Res<LateHitSettings>,
+    pointers: Query<(
+        &PointerId,
+        &PointerLocation,
+        Option<&SmoothedHitTestPosition>,
+    )>,
+    mut history: ResMut<PointerLocationHistory>,
+) {
+    let Some(frame_count) = frame_count else {
+        return;
+    };
+    let frame = frame_count.0;
+    let capacity = settings.max_age as usize + 1;
+    history
+        .locations
+        .retain(|pointer_id, _| pointers.iter().any(|(id, ..)| id == pointer_id));
+    for (pointer_id, pointer, smoothed) in &pointers {
+        let locations = history.locations.entry(*pointer_id).or_default();
+        if locations
+            .back()
+            .is_some_and(|(recorded, _)| *recorded == frame)
+        {
+            locations.pop_back();
+        }
+        locations.push_back((frame, pointer.hit_test_location(smoothed)));
+        while locations.len() > capacity {
+            locations.pop_front();
+        }
+    }
+}
+
+/// Settings for how pointers respond when the scale factor or resolution of a window changes, such
+/// as when the window is dragged to a monitor with a different DPI.
+#[derive(Debug, Clone, Copy, Resource, Reflect, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct RebaselineSettings {
+    /// When enabled, pointers on a window that changed scale factor or resolution are moved to the
+    /// window's current cursor position, and their hover state is held for that frame. This avoids
+    /// spurious [`Over`](crate::events::Over) and [`Out`](crate::events::Out) events caused by
+    /// positions that are briefly inconsistent with the new window size. Enabled by default.
+    pub enabled: bool,
+}
+
+impl Default for RebaselineSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// The pointers that were re-baselined this frame because their window changed scale factor or
+/// resolution. See [`RebaselineSettings`].
+#[derive(Debug, Default, Clone, Resource)]
+pub struct RebaselinedPointers {
+    pointers: HashSet<PointerId>,
+}
+
+impl RebaselinedPointers {
+    /// Returns `true` if the pointer was re-baselined this frame.
+    pub fn contains(&self, pointer_id: &PointerId) -> bool {
+        self.pointers.contains(pointer_id)
+    }
+
+    /// Iterates over the pointers that were re-baselined this frame.
+    pub fn iter(&self) -> impl Iterator<Item = &PointerId> {
+        self.pointers.iter()
+    }
+}
+
+/// Re-baselines the pointers of any window that changed scale factor or resolution this frame.
+///
+/// The mouse pointer is moved to the window's current cursor position. Other pointers keep their
+/// location until their next input, because the window does not track them. In both cases, hit
+/// test smoothing is restarted, and the pointer is recorded in [`RebaselinedPointers`].
+pub fn rebaseline_pointers(
+    settings: Res<RebaselineSettings>,
+    mut scale_factor_changes: EventReader<WindowScaleFactorChanged>,
+    mut resizes: EventReader<WindowResized>,
+    windows: Query<&Window>,
+    mut pointers: Query<(
+        &PointerId,
+        &mut PointerLocation,
+        Option<&mut SmoothedHitTestPosition>,
+    )>,
+    mapper: Res<PointerLocationMapper>,
+    mut rebaselined: ResMut<RebaselinedPointers>,
+) {
+    rebaselined.pointers.clear();
+    let changed_windows: HashSet<Entity> = scale_factor_changes
+        .read()
+        .map(|event| event.window)
+        .chain(resizes.read().map(|event| event.window))
+        .collect();
+    if !settings.enabled || changed_windows.is_empty() {
+        return;
+    }
+
+    for (pointer_id, mut pointer, hit_test_position) in &mut pointers {
+        let Some(window) = pointer
+            .location()
+            .and_then(|location| match location.target {
+                NormalizedRenderTarget::Window(window) => Some(window.entity()),
+                _ => None,
+            })
+        else {
+            continue;
+        };
+        if !changed_windows.contains(&window) {
+            continue;
+        }
+        if pointer_id.is_mouse() {
+            if let Some(position) = windows.get(window).ok().and_then(Window::cursor_position) {
+                if let Some(location) = pointer.location.as_mut() {
+                    let cursor = Location {
+                        target: location.target.clone(),
+                        position,
+                    };
+                    *location = mapper.map(*pointer_id, &cursor);
+                }
+            }
+        }
+        if let Some(mut hit_test_position) = hit_test_position {
+            hit_test_position.0 = None;
+        }
+        rebaselined.pointers.insert(*pointer_id);
+    }
 }
 
 /// Pointer input event for pointer moves. Fires when a pointer changes location.
-#[derive(Event, Debug, Clone, Reflect)]
+#[derive(Event, Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct InputMove {
     /// The [`PointerId`] of the pointer that is moving.
     pub pointer_id: PointerId,
@@ -243,28 +1154,169 @@ pub struct InputMove {
     pub location: Location,
     /// The distance moved (change in `position`) since the last event.
     pub delta: Vec2,
+    /// The pressure of the pointer, see [`PointerPressure`]. `None` for devices that do not report
+    /// pressure.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub pressure: Option<f32>,
 }
 impl InputMove {
-    /// Create a new [`InputMove`] event.
+    /// Create a new [`InputMove`] event, without pressure.
     pub fn new(id: PointerId, location: Location, delta: Vec2) -> InputMove {
         Self {
             pointer_id: id,
             location,
             delta,
+            pressure: None,
         }
     }
 
-    /// Receives [`InputMove`] events and updates corresponding [`PointerLocation`] components.
+    /// Sets the pressure of the pointer, normalized from `0.0` to `1.0`.
+    pub fn with_pressure(mut self, pressure: Option<f32>) -> Self {
+        self.pressure = pressure;
+        self
+    }
+
+    /// Receives [`InputMove`] events and updates corresponding [`PointerLocation`] and
+    /// [`PointerPressure`] components. The locations of pointers with a [`PointerGrab`] stay
+    /// pinned, unless they have no location yet. Locations are remapped by the
+    /// [`PointerLocationMapper`].
     pub fn receive(
         mut events: EventReader<InputMove>,
-        mut pointers: Query<(&PointerId, &mut PointerLocation)>,
+        mapper: Res<PointerLocationMapper>,
+        mut pointers: Query<(
+            &PointerId,
+            &mut PointerLocation,
+            Option<&mut PointerPressure>,
+            Has<PointerGrab>,
+            Has<PointerDisabled>,
+        )>,
     ) {
         for event_pointer in events.read() {
-            pointers.iter_mut().for_each(|(id, mut pointer)| {
-                if *id == event_pointer.pointer_id {
-                    pointer.location = Some(event_pointer.location.to_owned());
-                }
-            })
+            pointers
+                .iter_mut()
+                .for_each(|(id, mut pointer, pressure, is_grabbed, is_disabled)| {
+                    if *id != event_pointer.pointer_id || is_disabled {
+                        return;
+                    }
+                    if let Some(mut pressure) = pressure {
+                        pressure.set_if_neq(PointerPressure(event_pointer.pressure));
+                    }
+                    if !is_grabbed || pointer.location.is_none() {
+                        pointer.location = Some(mapper.map(*id, &event_pointer.location));
+                    }
+                })
+        }
+    }
+}
+
+/// Remaps the [`Location`]s of pointer moves before they are used for picking. For example, a game
+/// that renders its world to an image shown in the UI can map window positions over the image to
+/// locations on the image's render target, or a letterboxed game can clamp positions to its picking
+/// surface.
+///
+/// The mapper receives the id of the moving pointer and the location of its [`InputMove`], and
+/// returns the location to pick at, which can be on a different render target. By default,
+/// locations are not remapped.
+///
+/// ### Ordering
+///
+/// The [`InputMove`] events are not changed. Instead, the mapper is applied in
+/// [`PickSet::ProcessInput`](crate::PickSet::ProcessInput), when [`InputMove::receive`] updates
+/// the [`PointerLocation`] of each pointer, so backends, which run after it, only see mapped
+/// locations. The pointer events sent for moves in [`PickSet::Focus`](crate::PickSet::Focus) map
+/// each move with [`Self::map_move`]. Systems that read [`InputMove`] events directly, such as
+/// those in [`PickSet::Input`](crate::PickSet::Input) and the input recorder, see the original
+/// locations.
+#[derive(Resource, Clone, Default)]
+pub struct PointerLocationMapper(
+    Option<Arc<dyn Fn(PointerId, &Location) -> Location + Send + Sync>>,
+);
+
+impl PointerLocationMapper {
+    /// Create a new [`PointerLocationMapper`] from a function of the pointer id and location.
+    pub fn new(mapper: impl Fn(PointerId, &Location) -> Location + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(mapper)))
+    }
+
+    /// Maps the `location` of a pointer using this mapper.
+    pub fn map(&self, pointer_id: PointerId, location: &Location) -> Location {
+        match &self.0 {
+            Some(mapper) => mapper(pointer_id, location),
+            None => location.clone(),
+        }
+    }
+
+    /// Maps the location of a pointer move using this mapper. The delta of the move is mapped by
+    /// mapping the location it started from, and is zero if the move crossed render targets once
+    /// mapped.
+    pub fn map_move(&self, input: &InputMove) -> InputMove {
+        if self.0.is_none() {
+            return input.clone();
+        }
+        let location = self.map(input.pointer_id, &input.location);
+        let start = Location {
+            target: input.location.target.clone(),
+            position: input.location.position - input.delta,
+        };
+        let start = self.map(input.pointer_id, &start);
+        let delta = if start.target == location.target {
+            location.position - start.position
+        } else {
+            Vec2::ZERO
+        };
+        InputMove {
+            location,
+            delta,
+            ..input.clone()
+        }
+    }
+}
+
+impl Debug for PointerLocationMapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PointerLocationMapper")
+            .field("is_mapping", &self.0.is_some())
+            .finish()
+    }
+}
+
+/// The pressure of a pointer, normalized from `0.0` to `1.0`, such as the force of a stylus or a
+/// pressure-sensitive touch. Updated from each [`InputMove`], and `None` for devices that do not
+/// report pressure, like the mouse.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PointerPressure(pub Option<f32>);
+
+/// Pointer input event for scrolling, such as from a mouse wheel or touchpad. Fires when a pointer
+/// scrolls.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputScroll {
+    /// The [`PointerId`] of the pointer that is scrolling.
+    pub pointer_id: PointerId,
+    /// The amount scrolled on each axis, in [`unit`](Self::unit)s.
+    pub delta: Vec2,
+    /// The unit of the [`delta`](Self::delta).
+    pub unit: ScrollUnit,
+}
+
+/// The unit of a scroll delta.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollUnit {
+    /// The delta is in lines, such as the notches of a mouse wheel.
+    #[default]
+    Line,
+    /// The delta is in logical pixels, such as from a touchpad.
+    Pixel,
+}
+impl InputScroll {
+    /// Create a new [`InputScroll`] event.
+    pub fn new(id: PointerId, delta: Vec2, unit: ScrollUnit) -> InputScroll {
+        Self {
+            pointer_id: id,
+            delta,
+            unit,
         }
     }
 }
@@ -278,13 +1330,69 @@ impl InputMove {
 ///   render target. It is up to picking backends to associate a Pointer's `Location` with a
 ///   specific `Camera`, if any.
 #[derive(Debug, Clone, Component, Reflect, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
     /// The [`NormalizedRenderTarget`] associated with the pointer, usually a window.
+    #[cfg_attr(feature = "serialize", serde(with = "serialize_window_target"))]
     pub target: NormalizedRenderTarget,
     /// The position of the pointer in the `target`.
     pub position: Vec2,
 }
+impl MapEntities for Location {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        if let NormalizedRenderTarget::Window(window) = &self.target {
+            let window = entity_mapper.map_entity(window.entity());
+            self.target = NormalizedRenderTarget::Window(
+                WindowRef::Entity(window)
+                    .normalize(None)
+                    .expect("window entity references are always normalized"),
+            );
+        }
+    }
+}
+
+/// Serializes the [`Location::target`] of pointers on windows as the window entity. Image and
+/// texture view targets are asset handles, so locations on them cannot be serialized.
+#[cfg(feature = "serialize")]
+mod serialize_window_target {
+    use bevy_ecs::entity::Entity;
+    use bevy_render::camera::NormalizedRenderTarget;
+    use bevy_window::WindowRef;
+    use serde::{ser::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        target: &NormalizedRenderTarget,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match target {
+            NormalizedRenderTarget::Window(window) => window.entity().serialize(serializer),
+            _ => Err(S::Error::custom(
+                "only pointer locations on windows can be serialized",
+            )),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NormalizedRenderTarget, D::Error> {
+        let window = Entity::deserialize(deserializer)?;
+        Ok(NormalizedRenderTarget::Window(
+            WindowRef::Entity(window)
+                .normalize(None)
+                .expect("window entity references are always normalized"),
+        ))
+    }
+}
+
 impl Location {
+    /// Returns the entity of the window this location is on, if the `target` is a window.
+    pub fn window(&self) -> Option<Entity> {
+        match &self.target {
+            NormalizedRenderTarget::Window(window) => Some(window.entity()),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if this pointer's [`Location`] is within the [`Camera`]'s viewport.
     ///
     /// Note this returns `false` if the location and camera have different render targets.
@@ -316,3 +1424,209 @@ impl Location {
             .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::prelude::*;
+
+    use super::*;
+    use crate::{
+        backend::PointerHits, events::*, testing::*, CorePlugin, InteractionPlugin, PickSet,
+    };
+
+    #[test]
+    fn mouse_buttons_convert_to_pointer_buttons() {
+        for button in PointerButton::iter() {
+            assert_eq!(
+                PointerButton::try_from(MouseButton::from(button)),
+                Ok(button)
+            );
+        }
+        assert_eq!(
+            PointerButton::try_from(MouseButton::Left),
+            Ok(PointerButton::Primary)
+        );
+        assert_eq!(
+            PointerButton::try_from(MouseButton::Right),
+            Ok(PointerButton::Secondary)
+        );
+        assert_eq!(
+            PointerButton::try_from(MouseButton::Middle),
+            Ok(PointerButton::Middle)
+        );
+        for extra in [
+            MouseButton::Back,
+            MouseButton::Forward,
+            MouseButton::Other(7),
+        ] {
+            assert_eq!(PointerButton::try_from(extra), Err(extra));
+        }
+    }
+
+    #[test]
+    fn debounce_ignores_bounces_and_delays_releases() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(InputDebounce {
+                window: Duration::from_millis(10),
+            });
+        let button = app.world.spawn_empty().id();
+
+        simulate_press(&mut app, PointerId::Mouse, PointerButton::Primary, button);
+        // The button bounces, and is released then pressed again within the same frame.
+        app.world
+            .send_event(InputPress::new_up(PointerId::Mouse, PointerButton::Primary));
+        simulate_press(&mut app, PointerId::Mouse, PointerButton::Primary, button);
+        assert!(sent_pointer_events::<Down>(&app).is_empty());
+
+        // The release is reported once the window has passed without a bounce.
+        simulate_release(&mut app, PointerId::Mouse, PointerButton::Primary, button);
+        assert!(sent_pointer_events::<Click>(&app).is_empty());
+        app.world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(20));
+        simulate_hover(&mut app, PointerId::Mouse, button);
+        assert_eq!(sent_pointer_events::<Click>(&app).len(), 1);
+    }
+
+    #[test]
+    fn captured_modifiers_are_reported_in_clicks() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(ModifierCapture { enabled: true })
+            .init_resource::<ButtonInput<KeyCode>>();
+        let item = app.world.spawn_empty().id();
+
+        app.world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ShiftLeft);
+        simulate_click(&mut app, PointerId::Mouse, item);
+
+        let click = &sent_pointer_events::<Click>(&app)[0];
+        assert!(click.modifiers.shift && !click.modifiers.ctrl);
+    }
+
+    #[test]
+    fn active_pointer_follows_alternating_mouse_and_touch() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin));
+        let touch = PointerId::Touch(0);
+        let active = |app: &App| app.world.resource::<ActivePointer>().get();
+        assert_eq!(active(&app), None);
+
+        simulate_pointer_move(&mut app, PointerId::Mouse, simulated_location(Vec2::ONE));
+        assert_eq!(active(&app), Some(PointerId::Mouse));
+
+        let location = simulated_location(Vec2::ZERO);
+        simulate_press(&mut app, touch, PointerButton::Primary, location.clone());
+        assert_eq!(active(&app), Some(touch));
+
+        simulate_pointer_move(&mut app, PointerId::Mouse, simulated_location(Vec2::X));
+        assert_eq!(active(&app), Some(PointerId::Mouse));
+
+        simulate_release(&mut app, touch, PointerButton::Primary, location);
+        assert_eq!(active(&app), Some(touch));
+
+        // An update without input keeps the last active pointer.
+        app.update();
+        assert_eq!(active(&app), Some(touch));
+
+        // When the mouse moves in the same frame as the touch presses, the press wins.
+        let moved = InputMove::new(PointerId::Mouse, simulated_location(Vec2::Y), Vec2::ONE);
+        app.world
+            .send_event(InputPress::new_down(touch, PointerButton::Primary));
+        app.world.send_event(moved);
+        app.update();
+        assert_eq!(active(&app), Some(touch));
+    }
+
+    /// Hovers an entity, then changes the window's scale factor during a frame where the backend
+    /// briefly misses it, and counts the [`Over`] and [`Out`] events sent after the first hover.
+    fn hover_changes_on_rescale(settings: RebaselineSettings) -> usize {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(settings);
+        let button = app.world.spawn_empty().id();
+        simulate_hover(&mut app, PointerId::Mouse, button);
+        assert_eq!(sent_pointer_events::<Over>(&app).len(), 1);
+
+        let count = |app: &App| {
+            sent_pointer_events::<Over>(app).len() + sent_pointer_events::<Out>(app).len()
+        };
+        app.world.send_event(WindowScaleFactorChanged {
+            window: Entity::PLACEHOLDER,
+            scale_factor: 2.0,
+        });
+        app.update();
+        let mut changes = count(&app);
+        simulate_hover(&mut app, PointerId::Mouse, button);
+        changes += count(&app);
+        changes
+    }
+
+    #[test]
+    fn rebaselining_holds_hovers_when_the_scale_factor_changes() {
+        assert_eq!(
+            hover_changes_on_rescale(RebaselineSettings { enabled: false }),
+            2
+        );
+        assert_eq!(hover_changes_on_rescale(RebaselineSettings::default()), 0);
+    }
+
+    /// The entities a stub backend reports as hit on either side of `x = 0`.
+    #[derive(Resource)]
+    struct Halves {
+        left: Entity,
+        right: Entity,
+    }
+
+    fn pick_halves(
+        halves: Res<Halves>,
+        pointers: Query<(
+            &PointerId,
+            &PointerLocation,
+            Option<&SmoothedHitTestPosition>,
+        )>,
+        mut output: EventWriter<PointerHits>,
+    ) {
+        for (pointer_id, pointer, smoothed) in &pointers {
+            let Some(location) = pointer.hit_test_location(smoothed) else {
+                continue;
+            };
+            let entity = if location.position.x < 0.0 {
+                halves.left
+            } else {
+                halves.right
+            };
+            let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+            output.send(PointerHits::new(*pointer_id, vec![(entity, hit)], 0.0));
+        }
+    }
+
+    /// Jitters the pointer across the boundary between the halves, and counts the [`Over`] events.
+    fn hover_changes(smoothing: HitTestSmoothing) -> usize {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .insert_resource(smoothing)
+            .add_systems(PreUpdate, pick_halves.in_set(PickSet::Backend));
+        let left = app.world.spawn_empty().id();
+        let right = app.world.spawn_empty().id();
+        app.world.insert_resource(Halves { left, right });
+
+        let mut overs = 0;
+        for frame in 0..20 {
+            // The pointer rests just right of the boundary, with jitter that crosses it.
+            let x = if frame % 2 == 0 { -0.2 } else { 0.8 };
+            let location = simulated_location(Vec2::new(x, 0.0));
+            simulate_pointer_move(&mut app, PointerId::Mouse, location);
+            overs += sent_pointer_events::<Over>(&app).len();
+        }
+        overs
+    }
+
+    #[test]
+    fn hit_test_smoothing_reduces_hover_flicker() {
+        assert_eq!(hover_changes(HitTestSmoothing::default()), 20);
+        assert!(hover_changes(HitTestSmoothing { factor: 0.9 }) <= 2);
+    }
+}
@@ -0,0 +1,300 @@
+//! Recording and replaying pointer inputs, for deterministic tests and reproducing bugs.
+//!
+//! The [`PointerInputRecorder`] captures every [`InputMove`], [`InputPress`], and [`InputScroll`]
+//! while it is enabled, along with when it was sent. The resulting [`PointerInputRecording`] can be
+//! replayed with the [`PointerInputPlayer`], which sends the same events in the
+//! [`PickSet::Input`](crate::PickSet::Input) set, at the same times relative to the start of
+//! playback. Both are driven by [`Time<Real>`], like the rest of the picking plugins.
+//!
+//! Recordings are [`Reflect`], and with the `serialize` feature they also implement serde's
+//! `Serialize` and `Deserialize`, so they can be saved to a file and loaded later. Like other
+//! pointer events, only inputs with locations on windows can be serialized with serde.
+//!
+//! ### Pointers
+//!
+//! Inputs are replayed with the [`PointerId`](crate::pointer::PointerId)s they were recorded with,
+//! so their pointers must exist during playback. Replaying inputs does not spawn pointers, so
+//! pointers that input plugins spawn from their own input events, such as touch pointers, must be
+//! spawned with a [`PointerCoreBundle`](crate::PointerCoreBundle) before they are replayed.
+//! Backends are not involved, and hit test replayed pointers like any other.
+
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use bevy_time::{Real, Time};
+
+use crate::pointer::{DebouncedPresses, InputMove, InputPress, InputScroll};
+
+/// A pointer input event captured by the [`PointerInputRecorder`].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointerInput {
+    /// A recorded [`InputMove`].
+    Move(InputMove),
+    /// A recorded [`InputPress`].
+    Press(InputPress),
+    /// A recorded [`InputScroll`].
+    Scroll(InputScroll),
+}
+
+/// A [`PointerInput`], and when it was sent.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedInput {
+    /// When the input was sent, relative to the start of the recording.
+    pub time: Duration,
+    /// The recorded input.
+    pub input: PointerInput,
+}
+
+/// A sequence of pointer inputs, recorded by the [`PointerInputRecorder`]. See the
+/// [module docs](self).
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointerInputRecording {
+    /// The recorded inputs, in the order they were sent. Within a frame, moves are recorded before
+    /// presses, and presses before scrolls.
+    pub inputs: Vec<RecordedInput>,
+}
+
+impl PointerInputRecording {
+    /// The time of the last input in the recording.
+    pub fn duration(&self) -> Duration {
+        self.inputs
+            .last()
+            .map_or(Duration::ZERO, |input| input.time)
+    }
+}
+
+/// Records pointer inputs while enabled. See the [module docs](self).
+#[derive(Debug, Default, Clone, Resource, Reflect)]
+#[reflect(Resource, Default)]
+pub struct PointerInputRecorder {
+    /// Whether inputs are being recorded. Disabled by default. Disabling the recorder directly
+    /// pauses the recording, and the time it was paused for is kept in the timestamps of later
+    /// inputs. Use [`Self::start`] to start a new recording instead.
+    pub enabled: bool,
+    /// The inputs recorded so far.
+    pub recording: PointerInputRecording,
+    /// When the recording started, once its first frame has been recorded.
+    #[reflect(ignore)]
+    started: Option<Duration>,
+}
+
+impl PointerInputRecorder {
+    /// Discards the current recording, and starts a new one.
+    pub fn start(&mut self) {
+        *self = Self {
+            enabled: true,
+            ..Default::default()
+        };
+    }
+
+    /// Stops recording, and returns the recorded inputs.
+    pub fn stop(&mut self) -> PointerInputRecording {
+        std::mem::take(self).recording
+    }
+}
+
+/// Records the pointer inputs sent this frame, if the [`PointerInputRecorder`] is enabled.
+pub fn record_pointer_inputs(
+    mut recorder: ResMut<PointerInputRecorder>,
+    time: Res<Time<Real>>,
+    mut moves: EventReader<InputMove>,
+    mut presses: DebouncedPresses,
+    mut scrolls: EventReader<InputScroll>,
+) {
+    if !recorder.enabled {
+        moves.clear();
+        presses.clear();
+        scrolls.clear();
+        return;
+    }
+
+    let now = time.elapsed();
+    let time = now.saturating_sub(*recorder.started.get_or_insert(now));
+    let inputs = moves
+        .read()
+        .cloned()
+        .map(PointerInput::Move)
+        .chain(presses.read().copied().map(PointerInput::Press))
+        .chain(scrolls.read().copied().map(PointerInput::Scroll))
+        .map(|input| RecordedInput { time, input });
+    recorder.recording.inputs.extend(inputs);
+}
+
+/// Replays a [`PointerInputRecording`]. See the [module docs](self).
+#[derive(Debug, Default, Clone, Resource, Reflect)]
+#[reflect(Resource, Default)]
+pub struct PointerInputPlayer {
+    recording: PointerInputRecording,
+    /// The index of the next input to send.
+    next: usize,
+    /// When playback started, once its first frame has been played.
+    #[reflect(ignore)]
+    started: Option<Duration>,
+}
+
+impl PointerInputPlayer {
+    /// Starts playing `recording` from the beginning, replacing any recording being played.
+    pub fn play(&mut self, recording: PointerInputRecording) {
+        *self = Self {
+            recording,
+            ..Default::default()
+        };
+    }
+
+    /// Stops playback. Inputs that have not been sent yet are discarded.
+    pub fn stop(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns `true` until every input of the recording has been sent.
+    pub fn is_playing(&self) -> bool {
+        self.next < self.recording.inputs.len()
+    }
+}
+
+/// Sends the inputs of the [`PointerInputPlayer`]'s recording that are due this frame.
+pub fn replay_pointer_inputs(
+    mut player: ResMut<PointerInputPlayer>,
+    time: Res<Time<Real>>,
+    mut moves: EventWriter<InputMove>,
+    mut presses: EventWriter<InputPress>,
+    mut scrolls: EventWriter<InputScroll>,
+) {
+    if !player.is_playing() {
+        return;
+    }
+
+    let player = &mut *player;
+    let now = time.elapsed();
+    let elapsed = now.saturating_sub(*player.started.get_or_insert(now));
+    while let Some(recorded) = player.recording.inputs.get(player.next) {
+        if recorded.time > elapsed {
+            break;
+        }
+        match &recorded.input {
+            PointerInput::Move(input) => {
+                moves.send(input.clone());
+            }
+            PointerInput::Press(input) => {
+                presses.send(*input);
+            }
+            PointerInput::Scroll(input) => {
+                scrolls.send(*input);
+            }
+        }
+        player.next += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::prelude::*;
+    use bevy_math::Vec2;
+
+    use super::*;
+    use crate::{
+        pointer::{PointerButton, PointerId, ScrollUnit},
+        testing::simulated_location,
+        CorePlugin, InteractionPlugin,
+    };
+
+    /// The pointer inputs sent during each update, in the order they are recorded.
+    #[derive(Resource, Default)]
+    struct SentInputs(Vec<Vec<PointerInput>>);
+
+    fn collect_inputs(
+        mut sent: ResMut<SentInputs>,
+        mut moves: EventReader<InputMove>,
+        mut presses: EventReader<InputPress>,
+        mut scrolls: EventReader<InputScroll>,
+    ) {
+        let inputs = moves
+            .read()
+            .cloned()
+            .map(PointerInput::Move)
+            .chain(presses.read().copied().map(PointerInput::Press))
+            .chain(scrolls.read().copied().map(PointerInput::Scroll));
+        sent.0.push(inputs.collect());
+    }
+
+    fn input_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .init_resource::<SentInputs>()
+            .add_systems(Last, collect_inputs);
+        app
+    }
+
+    fn send_input(app: &mut App, input: &PointerInput) {
+        match input {
+            PointerInput::Move(input) => {
+                app.world.send_event(input.clone());
+            }
+            PointerInput::Press(input) => {
+                app.world.send_event(*input);
+            }
+            PointerInput::Scroll(input) => {
+                app.world.send_event(*input);
+            }
+        }
+    }
+
+    /// Advances real time by `millis`, then updates the app.
+    fn update_after(app: &mut App, millis: u64) {
+        let mut time = app.world.resource_mut::<Time<Real>>();
+        time.advance_by(Duration::from_millis(millis));
+        app.update();
+    }
+
+    #[test]
+    fn replays_recorded_inputs() {
+        let mut app = input_app();
+        app.world.resource_mut::<PointerInputRecorder>().start();
+        let (mouse, primary) = (PointerId::Mouse, PointerButton::Primary);
+        let moved = |x| InputMove::new(mouse, simulated_location(Vec2::new(x, 0.0)), Vec2::X);
+        let frames = [
+            (0, vec![PointerInput::Move(moved(1.0))]),
+            (
+                16,
+                vec![PointerInput::Press(InputPress::new_down(mouse, primary))],
+            ),
+            (16, vec![]),
+            (
+                16,
+                vec![
+                    PointerInput::Move(moved(2.0)),
+                    PointerInput::Press(InputPress::new_up(mouse, primary)),
+                    PointerInput::Scroll(InputScroll::new(mouse, Vec2::Y, ScrollUnit::Line)),
+                ],
+            ),
+        ];
+        for (millis, inputs) in &frames {
+            inputs.iter().for_each(|input| send_input(&mut app, input));
+            update_after(&mut app, *millis);
+        }
+        let recording = app.world.resource_mut::<PointerInputRecorder>().stop();
+        assert_eq!(recording.inputs.len(), 5);
+        assert_eq!(recording.duration(), Duration::from_millis(48));
+
+        #[cfg(feature = "serialize")]
+        let recording: PointerInputRecording =
+            serde_json::from_str(&serde_json::to_string(&recording).unwrap()).unwrap();
+
+        let mut replay = input_app();
+        replay
+            .world
+            .resource_mut::<PointerInputPlayer>()
+            .play(recording);
+        for (millis, _) in &frames {
+            update_after(&mut replay, *millis);
+        }
+        assert!(!replay.world.resource::<PointerInputPlayer>().is_playing());
+        let expected: Vec<_> = frames.into_iter().map(|(_, inputs)| inputs).collect();
+        assert_eq!(replay.world.resource::<SentInputs>().0, expected);
+        assert_eq!(app.world.resource::<SentInputs>().0, expected);
+    }
+}
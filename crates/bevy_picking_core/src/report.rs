@@ -0,0 +1,326 @@
+//! Snapshots of the picking configuration of a scene, for catching accidental changes to picking
+//! behavior in tests and tooling.
+//!
+//! A [`PickingConfigReport`] records the picking components of every entity that has at least one
+//! of them. Reports are [`Reflect`], so they can be serialized with bevy's reflection serializers,
+//! such as [`ReflectSerializer`](bevy_reflect::serde::ReflectSerializer). Their types are
+//! registered by the [`CorePlugin`](crate::CorePlugin), and the primitive types they contain, such
+//! as `String` and `Entity`, by bevy's default plugins. Two reports can be compared with
+//! [`PickingConfigReport::diff`].
+//!
+//! ### Entity keys
+//!
+//! Entity ids are not stable across runs, so entries are identified by a [`key`] instead. When an
+//! entity has a [`Name`], its name is used as the key, otherwise the key is the entity's index and
+//! generation, such as `"5v1"`. When several entities share a name, each of their keys is the name
+//! followed by the entity's index and generation, such as `"Button#5v1"`, so keys are unique within
+//! a report. Unnamed keys are only stable if entities are spawned in the same order, so give the
+//! entities you want to compare in a baseline unique names. The recorded [`entity`] is ignored when
+//! diffing, and a [`PickTarget`] is compared by the key of its target.
+//!
+//! [`key`]: EntityPickingConfig::key
+//! [`entity`]: EntityPickingConfig::entity
+
+use std::cmp::Ordering;
+
+use bevy_core::Name;
+use bevy_ecs::{entity::EntityHashMap, prelude::*, world::EntityRef};
+use bevy_reflect::prelude::*;
+use bevy_utils::HashMap;
+
+use crate::{
+    events::{BubbleBoundary, ClickCooldown, ClickEmission},
+    focus::HoverGroup,
+    FocusPolicy, PickLayers, PickTarget, Pickable, PickableEvents, PickingShape,
+};
+
+/// The picking configuration of a single entity. See the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct EntityPickingConfig {
+    /// Identifies this entity when comparing reports. See the [module docs](self#entity-keys).
+    pub key: String,
+    /// The entity this configuration was read from.
+    pub entity: Entity,
+    /// The entity's [`Pickable`] component, if any.
+    pub pickable: Option<Pickable>,
+    /// The entity's [`PickableEvents`] component, if any.
+    pub pickable_events: Option<PickableEvents>,
+    /// The entity's [`FocusPolicy`] component, if any.
+    pub focus_policy: Option<FocusPolicy>,
+    /// The entity's [`PickLayers`] component, if any.
+    pub layers: Option<PickLayers>,
+    /// The entity's [`HoverGroup`] component, if any.
+    pub hover_group: Option<HoverGroup>,
+    /// The entity's [`ClickEmission`] component, if any.
+    pub click_emission: Option<ClickEmission>,
+    /// The entity's [`ClickCooldown`] component, if any.
+    pub click_cooldown: Option<ClickCooldown>,
+    /// Whether the entity has a [`BubbleBoundary`].
+    pub bubble_boundary: bool,
+    /// The entity's [`PickTarget`] component, if any.
+    pub pick_target: Option<PickTarget>,
+    /// The entity's [`PickingShape`] component, if any.
+    pub shape: Option<PickingShape>,
+}
+
+impl EntityPickingConfig {
+    /// Reads the picking configuration of `entity`, returning `None` if it has no picking
+    /// components.
+    pub fn from_entity(entity: EntityRef) -> Option<Self> {
+        let config = Self {
+            key: entity.get::<Name>().map_or_else(
+                || entity_id_key(entity.id()),
+                |name| name.as_str().to_owned(),
+            ),
+            entity: entity.id(),
+            pickable: entity.get::<Pickable>().cloned(),
+            pickable_events: entity.get::<PickableEvents>().copied(),
+            focus_policy: entity.get::<FocusPolicy>().copied(),
+            layers: entity.get::<PickLayers>().copied(),
+            hover_group: entity.get::<HoverGroup>().copied(),
+            click_emission: entity.get::<ClickEmission>().copied(),
+            click_cooldown: entity.get::<ClickCooldown>().copied(),
+            bubble_boundary: entity.contains::<BubbleBoundary>(),
+            pick_target: entity.get::<PickTarget>().copied(),
+            shape: entity.get::<PickingShape>().cloned(),
+        };
+        let has_config = config.pickable.is_some()
+            || config.pickable_events.is_some()
+            || config.focus_policy.is_some()
+            || config.layers.is_some()
+            || config.hover_group.is_some()
+            || config.click_emission.is_some()
+            || config.click_cooldown.is_some()
+            || config.bubble_boundary
+            || config.pick_target.is_some()
+            || config.shape.is_some();
+        has_config.then_some(config)
+    }
+
+    /// Returns `true` if both configurations are the same, ignoring the recorded entity and
+    /// comparing pick targets with `target_keys`.
+    fn same_config(
+        &self,
+        other: &Self,
+        target_keys: (&EntityHashMap<&str>, &EntityHashMap<&str>),
+    ) -> bool {
+        let target_key = |config: &Self, keys: &EntityHashMap<&str>| {
+            config.pick_target.map(|PickTarget(target)| {
+                keys.get(&target)
+                    .map_or_else(|| entity_id_key(target), |key| (*key).to_owned())
+            })
+        };
+        target_key(self, target_keys.0) == target_key(other, target_keys.1)
+            && Self {
+                entity: other.entity,
+                pick_target: other.pick_target,
+                ..self.clone()
+            } == *other
+    }
+}
+
+/// The key of an unnamed entity.
+fn entity_id_key(entity: Entity) -> String {
+    format!("{}v{}", entity.index(), entity.generation())
+}
+
+/// The picking configuration of every entity in a world that has picking components. See the
+/// [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+pub struct PickingConfigReport {
+    /// The configuration of each entity, sorted by [`key`](EntityPickingConfig::key).
+    pub entities: Vec<EntityPickingConfig>,
+}
+
+impl PickingConfigReport {
+    /// Builds a report from every entity in `world` that has picking components.
+    pub fn from_world(world: &World) -> Self {
+        let mut entities: Vec<_> = world
+            .iter_entities()
+            .filter_map(EntityPickingConfig::from_entity)
+            .collect();
+        let mut counts = HashMap::<String, usize>::new();
+        for config in &entities {
+            *counts.entry(config.key.clone()).or_default() += 1;
+        }
+        for config in &mut entities {
+            if counts[&config.key] > 1 {
+                config.key = format!("{}#{}", config.key, entity_id_key(config.entity));
+            }
+        }
+        entities.sort_by(|a, b| a.key.cmp(&b.key));
+        Self { entities }
+    }
+
+    /// Returns the entry with the given key, if any.
+    pub fn get(&self, key: &str) -> Option<&EntityPickingConfig> {
+        self.entities
+            .binary_search_by(|config| config.key.as_str().cmp(key))
+            .ok()
+            .map(|index| &self.entities[index])
+    }
+
+    /// Lists the differences between this baseline report and `other`, matching entities by key,
+    /// in key order. An empty result means the configurations match.
+    pub fn diff(&self, other: &Self) -> Vec<PickingConfigChange> {
+        let target_keys = (self.keys(), other.keys());
+        let mut changes = Vec::new();
+        let mut before = self.entities.iter().peekable();
+        let mut after = other.entities.iter().peekable();
+        loop {
+            let change = match (before.peek().copied(), after.peek().copied()) {
+                (None, None) => break,
+                (Some(old), None) => {
+                    before.next();
+                    PickingConfigChange::Removed(old.clone())
+                }
+                (None, Some(new)) => {
+                    after.next();
+                    PickingConfigChange::Added(new.clone())
+                }
+                (Some(old), Some(new)) => match old.key.cmp(&new.key) {
+                    Ordering::Less => {
+                        before.next();
+                        PickingConfigChange::Removed(old.clone())
+                    }
+                    Ordering::Greater => {
+                        after.next();
+                        PickingConfigChange::Added(new.clone())
+                    }
+                    Ordering::Equal => {
+                        before.next();
+                        after.next();
+                        if old.same_config(new, (&target_keys.0, &target_keys.1)) {
+                            continue;
+                        }
+                        PickingConfigChange::Changed {
+                            before: old.clone(),
+                            after: new.clone(),
+                        }
+                    }
+                },
+            };
+            changes.push(change);
+        }
+        changes
+    }
+
+    /// Maps each recorded entity to its key.
+    fn keys(&self) -> EntityHashMap<&str> {
+        self.entities
+            .iter()
+            .map(|config| (config.entity, config.key.as_str()))
+            .collect()
+    }
+}
+
+/// A difference between two [`PickingConfigReport`]s, returned by [`PickingConfigReport::diff`].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub enum PickingConfigChange {
+    /// An entity is only in the compared report.
+    Added(EntityPickingConfig),
+    /// An entity is only in the baseline report.
+    Removed(EntityPickingConfig),
+    /// An entity is in both reports, with a different configuration.
+    Changed {
+        /// The configuration in the baseline report.
+        before: EntityPickingConfig,
+        /// The configuration in the compared report.
+        after: EntityPickingConfig,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(world: &World) -> PickingConfigReport {
+        PickingConfigReport::from_world(world)
+    }
+
+    #[test]
+    fn diff_lists_added_removed_and_changed_entities() {
+        let mut world = World::new();
+        let changed = world
+            .spawn((Name::new("changed"), Pickable::default()))
+            .id();
+        let removed = world.spawn((Name::new("removed"), BubbleBoundary)).id();
+        world.spawn((Name::new("same"), FocusPolicy::Pass));
+        world.spawn(Name::new("unconfigured"));
+        let baseline = report(&world);
+        assert_eq!(baseline.entities.len(), 3);
+        assert_eq!(report(&world).diff(&baseline), []);
+
+        world.entity_mut(changed).insert(Pickable::IGNORE);
+        world.despawn(removed);
+        world.spawn((
+            Name::new("added"),
+            PickingShape::Circle {
+                center: bevy_math::Vec2::ZERO,
+                radius: 1.0,
+            },
+        ));
+        let current = report(&world);
+        assert_eq!(
+            baseline.diff(&current),
+            [
+                PickingConfigChange::Added(current.get("added").unwrap().clone()),
+                PickingConfigChange::Changed {
+                    before: baseline.get("changed").unwrap().clone(),
+                    after: current.get("changed").unwrap().clone(),
+                },
+                PickingConfigChange::Removed(baseline.get("removed").unwrap().clone()),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_names_get_unique_keys() {
+        let mut world = World::new();
+        let first = world.spawn((Name::new("button"), Pickable::default())).id();
+        let second = world.spawn((Name::new("button"), Pickable::IGNORE)).id();
+        let report = report(&world);
+
+        let keys: Vec<_> = report
+            .entities
+            .iter()
+            .map(|config| config.key.as_str())
+            .collect();
+        assert_eq!(keys, ["button#0v1", "button#1v1"]);
+        assert_eq!(report.get("button#0v1").unwrap().entity, first);
+        assert_eq!(report.get("button#1v1").unwrap().entity, second);
+        assert_eq!(report.get("button"), None);
+    }
+
+    #[test]
+    fn unnamed_entities_are_keyed_by_id() {
+        let mut world = World::new();
+        world.spawn_empty().despawn();
+        let entity = world.spawn(BubbleBoundary).id();
+        let report = report(&world);
+
+        assert_eq!(entity.generation(), 2);
+        assert_eq!(report.entities.len(), 1);
+        assert_eq!(report.get("0v2").unwrap().entity, entity);
+    }
+
+    #[test]
+    fn pick_targets_are_compared_by_key() {
+        let build = |spawn_first: bool| {
+            let mut world = World::new();
+            if spawn_first {
+                world.spawn(Name::new("padding"));
+            }
+            let target = world.spawn((Name::new("target"), Pickable::default())).id();
+            world.spawn((Name::new("part"), PickTarget(target)));
+            report(&world)
+        };
+        let baseline = build(false);
+        let current = build(true);
+        assert_ne!(
+            baseline.get("part").unwrap().pick_target,
+            current.get("part").unwrap().pick_target
+        );
+        assert_eq!(baseline.diff(&current), []);
+    }
+}
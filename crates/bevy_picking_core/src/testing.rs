@@ -0,0 +1,189 @@
+//! Helpers for testing picking logic in a headless [`App`], without windows, cameras, or input
+//! devices.
+//!
+//! The helpers send the same [`InputMove`] and [`InputPress`] events as the input plugins, then
+//! [`update`](App::update) the app, so assertions can be made on the
+//! [`HoverMap`](crate::focus::HoverMap) and on the [`Pointer`] events sent during that update,
+//! which can be read with [`sent_pointer_events`]. They only need the [`CorePlugin`] and
+//! [`InteractionPlugin`](crate::InteractionPlugin). Pointers that do not exist yet are spawned at
+//! the origin of a [`simulated_location`] the first time a helper uses them.
+//!
+//! ### Targets
+//!
+//! Without a backend, nothing is under a pointer. Interactions aimed at an [`Entity`] stand in for
+//! a backend, by reporting the entity as the only hit under the pointer for each update they run.
+//! Interactions aimed at a [`Location`] only move the pointer, so they rely on a backend that is
+//! added to the app. Once a helper returns, the simulated hits are no longer reported, so the
+//! entity is left on the next update unless it is hovered again with [`simulate_hover`].
+//!
+//! ```
+//! # use bevy_app::prelude::*;
+//! # use bevy_picking_core::{events::*, pointer::*, testing::*, *};
+//! let mut app = App::new();
+//! app.add_plugins((CorePlugin, InteractionPlugin));
+//! let button = app.world.spawn_empty().id();
+//!
+//! simulate_click(&mut app, PointerId::Mouse, button);
+//!
+//! let clicks = sent_pointer_events::<Click>(&app);
+//! assert_eq!(clicks.len(), 1);
+//! assert_eq!(clicks[0].target, button);
+//! ```
+//!
+//! [`CorePlugin`]: crate::CorePlugin
+
+use std::fmt::Debug;
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_reflect::Reflect;
+use bevy_render::camera::NormalizedRenderTarget;
+use bevy_window::WindowRef;
+
+use crate::{
+    backend::{HitData, PointerHits},
+    events::Pointer,
+    pointer::{InputMove, InputPress, Location, PointerButton, PointerId, PointerLocation},
+    PointerCoreBundle,
+};
+
+/// Where a simulated interaction is aimed. See the [module docs](self#targets).
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimulatedTarget {
+    /// Simulates a hit on this entity, without moving the pointer.
+    Entity(Entity),
+    /// Moves the pointer to this location, and relies on a backend for hits.
+    Location(Location),
+}
+
+impl From<Entity> for SimulatedTarget {
+    fn from(entity: Entity) -> Self {
+        Self::Entity(entity)
+    }
+}
+
+impl From<Location> for SimulatedTarget {
+    fn from(location: Location) -> Self {
+        Self::Location(location)
+    }
+}
+
+/// Returns a [`Location`] at `position` on a window that does not need to exist.
+pub fn simulated_location(position: Vec2) -> Location {
+    Location {
+        target: NormalizedRenderTarget::Window(
+            WindowRef::Entity(Entity::PLACEHOLDER)
+                .normalize(None)
+                .expect("window entity references are always normalized"),
+        ),
+        position,
+    }
+}
+
+/// Moves the pointer to `location`, then updates the app.
+pub fn simulate_pointer_move(app: &mut App, pointer_id: PointerId, location: Location) {
+    send_pointer_move(app, pointer_id, location);
+    app.update();
+}
+
+/// Reports `entity` as the only hit under the pointer, then updates the app.
+pub fn simulate_hover(app: &mut App, pointer_id: PointerId, entity: Entity) {
+    send_hit(app, pointer_id, entity);
+    app.update();
+}
+
+/// Presses `button` on the pointer over `target`, then updates the app.
+pub fn simulate_press(
+    app: &mut App,
+    pointer_id: PointerId,
+    button: PointerButton,
+    target: impl Into<SimulatedTarget>,
+) {
+    simulate_input(
+        app,
+        pointer_id,
+        target,
+        InputPress::new_down(pointer_id, button),
+    );
+}
+
+/// Releases `button` on the pointer over `target`, then updates the app.
+pub fn simulate_release(
+    app: &mut App,
+    pointer_id: PointerId,
+    button: PointerButton,
+    target: impl Into<SimulatedTarget>,
+) {
+    simulate_input(
+        app,
+        pointer_id,
+        target,
+        InputPress::new_up(pointer_id, button),
+    );
+}
+
+/// Clicks the primary button of the pointer on `target`, updating the app once for the press and
+/// once for the release.
+pub fn simulate_click(app: &mut App, pointer_id: PointerId, target: impl Into<SimulatedTarget>) {
+    let target = target.into();
+    simulate_press(app, pointer_id, PointerButton::Primary, target.clone());
+    simulate_release(app, pointer_id, PointerButton::Primary, target);
+}
+
+/// Returns the [`Pointer<E>`] events sent during the last update.
+pub fn sent_pointer_events<E: Debug + Clone + Reflect>(app: &App) -> Vec<Pointer<E>> {
+    app.world
+        .resource::<Events<Pointer<E>>>()
+        .iter_current_update_events()
+        .cloned()
+        .collect()
+}
+
+fn simulate_input(
+    app: &mut App,
+    pointer_id: PointerId,
+    target: impl Into<SimulatedTarget>,
+    press: InputPress,
+) {
+    match target.into() {
+        SimulatedTarget::Entity(entity) => send_hit(app, pointer_id, entity),
+        SimulatedTarget::Location(location) => send_pointer_move(app, pointer_id, location),
+    }
+    app.world.send_event(press);
+    app.update();
+}
+
+/// Returns the current location of the pointer, spawning it if it does not exist.
+fn pointer_location(app: &mut App, pointer_id: PointerId) -> Option<Location> {
+    let mut pointers = app.world.query::<(&PointerId, &PointerLocation)>();
+    if let Some((_, pointer)) = pointers.iter(&app.world).find(|(id, _)| **id == pointer_id) {
+        return pointer.location.clone();
+    }
+    let location = simulated_location(Vec2::ZERO);
+    app.world
+        .spawn(PointerCoreBundle::new(pointer_id).with_location(location.clone()));
+    Some(location)
+}
+
+fn send_pointer_move(app: &mut App, pointer_id: PointerId, location: Location) {
+    let delta = pointer_location(app, pointer_id)
+        .filter(|previous| previous.target == location.target)
+        .map_or(Vec2::ZERO, |previous| location.position - previous.position);
+    app.world
+        .send_event(InputMove::new(pointer_id, location, delta));
+}
+
+fn send_hit(app: &mut App, pointer_id: PointerId, entity: Entity) {
+    // Pointer events are only sent for pointers with a location.
+    if pointer_location(app, pointer_id).is_none() {
+        app.world.send_event(InputMove::new(
+            pointer_id,
+            simulated_location(Vec2::ZERO),
+            Vec2::ZERO,
+        ));
+    }
+    let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+    app.world
+        .send_event(PointerHits::new(pointer_id, vec![(entity, hit)], 0.0));
+}
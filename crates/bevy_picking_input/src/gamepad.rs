@@ -0,0 +1,168 @@
+//! Provides a virtual pointer driven by a gamepad, so controller users can use picking.
+//!
+//! This is not part of the [`InputPlugin`](crate::InputPlugin). Add the [`GamepadPointerPlugin`]
+//! to spawn a pointer with the [`GAMEPAD_POINTER_ID`], which is moved around the primary window by
+//! a gamepad stick, and pressed with gamepad buttons. It sends the same [`InputMove`] and
+//! [`InputPress`] events as the mouse, so the rest of the picking pipeline works unchanged.
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads},
+    Axis, ButtonInput,
+};
+use bevy_math::Vec2;
+use bevy_reflect::prelude::*;
+use bevy_render::camera::RenderTarget;
+use bevy_time::{Real, Time};
+use bevy_utils::Uuid;
+use bevy_window::{PrimaryWindow, Window, WindowRef};
+
+use bevy_picking_core::{
+    pointer::{InputMove, InputPress, Location, PointerButton, PointerId},
+    PickSet, PointerCoreBundle,
+};
+
+/// The id of the pointer spawned by the [`GamepadPointerPlugin`].
+pub const GAMEPAD_POINTER_ID: PointerId =
+    PointerId::Custom(Uuid::from_u128(0x5c1e_7a4b_85a4_4c33_9f3e_d0c7_0b5a_e1f2));
+
+/// Adds a virtual pointer driven by a gamepad. See the [module docs](self).
+pub struct GamepadPointerPlugin;
+impl Plugin for GamepadPointerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GamepadPointerSettings>()
+            .init_resource::<Time<Real>>()
+            .add_systems(Startup, spawn_gamepad_pointer)
+            .add_systems(First, gamepad_pick_events.in_set(PickSet::Input))
+            .register_type::<GamepadPointerSettings>();
+    }
+}
+
+/// Configures how gamepad input drives the pointer spawned by the [`GamepadPointerPlugin`].
+#[derive(Debug, Clone, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct GamepadPointerSettings {
+    /// The gamepad that drives the pointer. If `None`, the first connected gamepad is used.
+    pub gamepad: Option<Gamepad>,
+    /// The axis that moves the pointer horizontally.
+    pub x_axis: GamepadAxisType,
+    /// The axis that moves the pointer vertically, where positive values move the pointer up.
+    pub y_axis: GamepadAxisType,
+    /// The stick deflection, from `0.0` to `1.0`, below which the stick is ignored.
+    pub deadzone: f32,
+    /// The speed of the pointer at full stick deflection, in logical pixels per second.
+    pub max_speed: f32,
+    /// How quickly the pointer speeds up towards the speed set by the stick, in logical pixels per
+    /// second squared. The pointer stops as soon as the stick returns to the deadzone, and a value
+    /// of `f32::INFINITY` disables acceleration.
+    pub acceleration: f32,
+    /// The gamepad buttons that press each pointer button.
+    pub buttons: Vec<(GamepadButtonType, PointerButton)>,
+}
+
+impl Default for GamepadPointerSettings {
+    fn default() -> Self {
+        Self {
+            gamepad: None,
+            x_axis: GamepadAxisType::LeftStickX,
+            y_axis: GamepadAxisType::LeftStickY,
+            deadzone: 0.15,
+            max_speed: 800.0,
+            acceleration: 3000.0,
+            buttons: vec![
+                (GamepadButtonType::South, PointerButton::Primary),
+                (GamepadButtonType::West, PointerButton::Secondary),
+            ],
+        }
+    }
+}
+
+/// Spawns the gamepad pointer.
+pub fn spawn_gamepad_pointer(mut commands: Commands) {
+    commands.spawn((
+        PointerCoreBundle::new(GAMEPAD_POINTER_ID),
+        #[cfg(feature = "selection")]
+        bevy_picking_selection::PointerMultiselect::default(),
+    ));
+}
+
+/// Sends gamepad pointer events to be processed by the core plugin. The pointer starts in the
+/// center of the primary window, and is kept within its bounds.
+pub fn gamepad_pick_events(
+    // Input
+    settings: Res<GamepadPointerSettings>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    time: Res<Time<Real>>,
+    // Local
+    mut position: Local<Option<Vec2>>,
+    mut velocity: Local<Vec2>,
+    // Output
+    mut pointer_move: EventWriter<InputMove>,
+    mut pointer_presses: EventWriter<InputPress>,
+) {
+    let Ok((window_entity, window)) = windows.get_single() else {
+        return;
+    };
+    let Some(target) =
+        RenderTarget::Window(WindowRef::Entity(window_entity)).normalize(Some(window_entity))
+    else {
+        return;
+    };
+    let size = Vec2::new(window.width(), window.height());
+    let location = |position| Location {
+        target: target.clone(),
+        position,
+    };
+
+    let last_position = *position;
+    let new_position = last_position.unwrap_or(size / 2.0);
+    let gamepad = settings.gamepad.or_else(|| gamepads.iter().next());
+
+    let stick = gamepad.map_or(Vec2::ZERO, |gamepad| {
+        let axis = |axis_type| {
+            axes.get(GamepadAxis::new(gamepad, axis_type))
+                .unwrap_or_default()
+        };
+        // Window coordinates increase downwards, while the stick's y axis increases upwards.
+        Vec2::new(axis(settings.x_axis), -axis(settings.y_axis))
+    });
+    let deflection = stick.length().min(1.0);
+    if deflection <= settings.deadzone {
+        *velocity = Vec2::ZERO;
+    } else {
+        // Rescale the deflection so movement starts from zero at the edge of the deadzone.
+        let speed =
+            (deflection - settings.deadzone) / (1.0 - settings.deadzone) * settings.max_speed;
+        let target_velocity = stick.normalize() * speed;
+        let max_change = settings.acceleration * time.delta_seconds();
+        let change = target_velocity - *velocity;
+        *velocity += change.clamp_length_max(max_change);
+    }
+    let new_position = (new_position + *velocity * time.delta_seconds()).clamp(Vec2::ZERO, size);
+
+    if last_position != Some(new_position) {
+        pointer_move.send(InputMove::new(
+            GAMEPAD_POINTER_ID,
+            location(new_position),
+            new_position - last_position.unwrap_or(new_position),
+        ));
+        *position = Some(new_position);
+    }
+
+    let Some(gamepad) = gamepad else {
+        return;
+    };
+    for (button_type, button) in settings.buttons.iter().copied() {
+        let gamepad_button = GamepadButton::new(gamepad, button_type);
+        if buttons.just_pressed(gamepad_button) {
+            pointer_presses.send(InputPress::new_down(GAMEPAD_POINTER_ID, button));
+        }
+        if buttons.just_released(gamepad_button) {
+            pointer_presses.send(InputPress::new_up(GAMEPAD_POINTER_ID, button));
+        }
+    }
+}
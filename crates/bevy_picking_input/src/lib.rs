@@ -1,6 +1,8 @@
 //! `bevy_picking_input` is a thin layer that provides unsurprising default inputs to `bevy_picking
 //! core`. The included systems are responsible for sending  mouse and touch inputs to their
-//! respective `Pointer`s.
+//! respective `Pointer`s. A virtual pointer driven by a gamepad can be added with the
+//! [`GamepadPointerPlugin`](gamepad::GamepadPointerPlugin), and keyboard and gamepad focus
+//! navigation with the [`FocusNavigationPlugin`](navigation::FocusNavigationPlugin).
 //!
 //! Because this resides in its own crate, it's easy to omit it, and provide your own inputs as
 //! needed. Because `Pointer`s aren't coupled to the underlying input hardware, you can easily mock
@@ -14,18 +16,30 @@
 #![allow(clippy::too_many_arguments)]
 #![deny(missing_docs)]
 
+use std::time::Duration;
+
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_reflect::prelude::*;
+use bevy_time::{Real, Time};
 
 use bevy_picking_core::PickSet;
 
+pub mod gamepad;
 pub mod mouse;
+pub mod navigation;
 pub mod touch;
 
 /// Common imports for `bevy_picking_input`.
 pub mod prelude {
-    pub use crate::{InputPlugin, InputPluginSettings};
+    pub use crate::{
+        gamepad::{GamepadPointerPlugin, GamepadPointerSettings},
+        navigation::{
+            FocusNavigable, FocusNavigationPlugin, FocusNavigationSettings, NavigateFocus,
+            NavigationFocus,
+        },
+        InputPlugin, InputPluginSettings,
+    };
 }
 
 /// Adds mouse and touch inputs for picking pointers to your app. This is a default input plugin,
@@ -34,12 +48,19 @@ pub struct InputPlugin;
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<InputPluginSettings>()
+            .init_resource::<touch::TouchPointerIds>()
+            .init_resource::<Time<Real>>()
             .add_systems(Startup, mouse::spawn_mouse_pointer)
             .add_systems(
                 First,
                 (
                     touch::touch_pick_events.run_if(InputPluginSettings::is_touch_enabled),
-                    mouse::mouse_pick_events.run_if(InputPluginSettings::is_mouse_enabled),
+                    (
+                        mouse::cancel_mouse_presses_on_focus_loss,
+                        mouse::mouse_pick_events,
+                    )
+                        .chain()
+                        .run_if(InputPluginSettings::is_mouse_enabled),
                     // IMPORTANT: the commands must be flushed after `touch_pick_events` is run
                     // because we need pointer spawning to happen immediately to prevent issues with
                     // missed events during drag and drop.
@@ -67,6 +88,17 @@ pub struct InputPluginSettings {
     pub is_touch_enabled: bool,
     /// Should mouse inputs be updated?
     pub is_mouse_enabled: bool,
+    /// Should touch pointers be assigned ids that are unique across windows, instead of using the
+    /// raw touch id? Enable this if the same touch id can be reported by multiple windows at once.
+    /// See [`TouchPointerIds`](touch::TouchPointerIds). Disabled by default.
+    pub is_touch_namespaced_by_window: bool,
+    /// How long after a touch ends a new touch nearby reuses its pointer id. See
+    /// [`TouchPointerIds`](touch::TouchPointerIds#re-touching). Set this to zero to always assign
+    /// new touches their own id. Defaults to 200 ms.
+    pub touch_retouch_window: Duration,
+    /// How far, in logical pixels, a new touch can start from where a touch ended and still reuse
+    /// its pointer id. Defaults to 30 pixels.
+    pub touch_retouch_distance: f32,
 }
 
 impl Default for InputPluginSettings {
@@ -74,6 +106,9 @@ impl Default for InputPluginSettings {
         Self {
             is_touch_enabled: true,
             is_mouse_enabled: true,
+            is_touch_namespaced_by_window: false,
+            touch_retouch_window: Duration::from_millis(200),
+            touch_retouch_distance: 30.0,
         }
     }
 }
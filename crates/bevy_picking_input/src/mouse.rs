@@ -1,13 +1,19 @@
 //! Provides sensible defaults for mouse picking inputs.
 
 use bevy_ecs::prelude::*;
-use bevy_input::{mouse::MouseButtonInput, prelude::*, ButtonState};
+use bevy_input::{
+    mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+    ButtonState,
+};
 use bevy_math::Vec2;
 use bevy_render::camera::RenderTarget;
-use bevy_window::{CursorMoved, PrimaryWindow, Window, WindowRef};
+use bevy_window::{CursorMoved, PrimaryWindow, Window, WindowFocused, WindowRef};
 
 use bevy_picking_core::{
-    pointer::{InputMove, InputPress, Location, PointerButton, PointerId},
+    pointer::{
+        InputMove, InputPress, InputScroll, Location, PointerButton, PointerGrab, PointerId,
+        PointerLocation, PointerPress, ScrollUnit,
+    },
     PointerCoreBundle,
 };
 
@@ -20,45 +26,65 @@ pub fn spawn_mouse_pointer(mut commands: Commands) {
     ));
 }
 
-/// Sends mouse pointer events to be processed by the core plugin
+/// Sends mouse pointer events to be processed by the core plugin. When the cursor moves to another
+/// window, the pointer's location moves to that window, and the move has no delta.
+///
+/// While the mouse pointer has a [`PointerGrab`], moves are sent from relative mouse motion
+/// instead of the cursor position, at the pointer's pinned location, so they keep working when
+/// the cursor is locked. The first cursor move after the grab is released has no delta.
 pub fn mouse_pick_events(
     // Input
     windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    grabbed: Query<(&PointerId, &PointerLocation), With<PointerGrab>>,
     mut cursor_moves: EventReader<CursorMoved>,
-    mut cursor_last: Local<Vec2>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut cursor_last: Local<Option<(Entity, Vec2)>>,
     mut mouse_inputs: EventReader<MouseButtonInput>,
+    mut mouse_wheel: EventReader<MouseWheel>,
     // Output
     mut pointer_move: EventWriter<InputMove>,
     mut pointer_presses: EventWriter<InputPress>,
+    mut pointer_scroll: EventWriter<InputScroll>,
 ) {
+    let grabbed_location = grabbed
+        .iter()
+        .find(|(id, _)| id.is_mouse())
+        .and_then(|(_, pointer)| pointer.location().cloned());
+    if let Some(location) = grabbed_location {
+        cursor_moves.clear();
+        *cursor_last = None;
+        let delta: Vec2 = mouse_motion.read().map(|motion| motion.delta).sum();
+        if delta != Vec2::ZERO {
+            pointer_move.send(InputMove::new(PointerId::Mouse, location, delta));
+        }
+    } else {
+        mouse_motion.clear();
+    }
+
     for event in cursor_moves.read() {
+        let Some(target) = RenderTarget::Window(WindowRef::Entity(event.window))
+            .normalize(windows.get_single().ok().map(|(window, _)| window))
+        else {
+            continue;
+        };
+        let delta = match *cursor_last {
+            Some((window, position)) if window == event.window => event.position - position,
+            _ => Vec2::ZERO,
+        };
         pointer_move.send(InputMove::new(
             PointerId::Mouse,
             Location {
-                target: RenderTarget::Window(WindowRef::Entity(event.window))
-                    .normalize(Some(
-                        match windows.get_single() {
-                            Ok(w) => w,
-                            Err(_) => continue,
-                        }
-                        .0,
-                    ))
-                    .unwrap(),
+                target,
                 position: event.position,
             },
-            event.position - *cursor_last,
+            delta,
         ));
-        *cursor_last = event.position;
+        *cursor_last = Some((event.window, event.position));
     }
 
     for input in mouse_inputs.read() {
-        let button = match input.button {
-            MouseButton::Left => PointerButton::Primary,
-            MouseButton::Right => PointerButton::Secondary,
-            MouseButton::Middle => PointerButton::Middle,
-            MouseButton::Other(_) => continue,
-            MouseButton::Back => continue,
-            MouseButton::Forward => continue,
+        let Ok(button) = PointerButton::try_from(input.button) else {
+            continue;
         };
 
         match input.state {
@@ -70,4 +96,70 @@ pub fn mouse_pick_events(
             }
         }
     }
+
+    for wheel in mouse_wheel.read() {
+        let unit = match wheel.unit {
+            MouseScrollUnit::Line => ScrollUnit::Line,
+            MouseScrollUnit::Pixel => ScrollUnit::Pixel,
+        };
+        pointer_scroll.send(InputScroll::new(
+            PointerId::Mouse,
+            Vec2::new(wheel.x, wheel.y),
+            unit,
+        ));
+    }
+}
+
+/// Releases the mouse pointer's buttons when a window loses focus. Windows do not receive the
+/// button releases that happen while they are unfocused, so the buttons would otherwise stay
+/// pressed, and their held durations would keep growing. No [`InputPress`] events are sent, so
+/// this does not cause clicks, and drags of the released buttons are
+/// [`cancelled`](bevy_picking_core::events::DragEnd::cancelled) instead of dropped.
+pub fn cancel_mouse_presses_on_focus_loss(
+    mut focus_events: EventReader<WindowFocused>,
+    mut pointers: Query<(&PointerId, &mut PointerPress)>,
+) {
+    if !focus_events.read().any(|event| !event.focused) {
+        return;
+    }
+    for (_, mut press) in pointers.iter_mut().filter(|(id, _)| id.is_mouse()) {
+        press.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::prelude::*;
+    use bevy_picking_core::{events::*, testing::*, CorePlugin, InteractionPlugin};
+
+    use super::*;
+
+    #[test]
+    fn focus_loss_cancels_drags() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .add_event::<WindowFocused>()
+            .add_systems(First, cancel_mouse_presses_on_focus_loss);
+        let dragged = app.world.spawn_empty().id();
+
+        simulate_press(&mut app, PointerId::Mouse, PointerButton::Primary, dragged);
+        let away = simulated_location(Vec2::X * 20.0);
+        simulate_pointer_move(&mut app, PointerId::Mouse, away);
+        assert_eq!(sent_pointer_events::<DragStart>(&app).len(), 1);
+
+        // The window loses focus, and the button is released outside of it.
+        let window = app.world.spawn_empty().id();
+        app.world.send_event(WindowFocused {
+            window,
+            focused: false,
+        });
+        app.update();
+        let drag_ends = sent_pointer_events::<DragEnd>(&app);
+        assert_eq!(drag_ends.len(), 1);
+        assert!(drag_ends[0].cancelled);
+        assert!(!app
+            .world
+            .resource::<DragMap>()
+            .is_dragging(PointerId::Mouse));
+    }
 }
@@ -0,0 +1,372 @@
+//! Moves a logical focus between pickable entities with the keyboard or a gamepad, for
+//! accessibility and controller navigation of menus.
+//!
+//! This is not part of the [`InputPlugin`](crate::InputPlugin). Add the [`FocusNavigationPlugin`],
+//! then add a [`FocusNavigable`] component to the entities that can be focused. Directional input
+//! moves the [`NavigationFocus`] to the nearest navigable entity in that direction on screen, and
+//! activation input presses it.
+//!
+//! The focus is followed by a virtual pointer with the [`NAVIGATION_POINTER_ID`], which is moved
+//! to the center of the focused entity, and pressed when the focus is activated. It sends the same
+//! [`InputMove`] and [`InputPress`] events as the mouse, so the focused entity receives the same
+//! [`Over`](bevy_picking_core::events::Over), [`Out`](bevy_picking_core::events::Out), and
+//! [`Click`](bevy_picking_core::events::Click) events as it would from a pointer, and existing
+//! listeners work unchanged. While nothing is focused, the pointer is disabled with a
+//! [`PointerDisabled`] component, so it doesn't hover anything.
+//!
+//! ### Screen positions
+//!
+//! Navigation happens on the primary window. With the `bevy_ui` feature, UI nodes are navigated
+//! by their on-screen rectangles, so moving through a grid of buttons moves along its rows and
+//! columns. Other entities are navigated by the position of their [`GlobalTransform`], as seen by
+//! the highest order camera rendering to the primary window that can see it. Entities that are
+//! hidden, not hoverable, or off screen can't be focused, and the focus is cleared when the
+//! focused entity can no longer be focused.
+//!
+//! The [`NavigationFocus`] can also be set directly, such as to focus a default button when a menu
+//! opens, and [`NavigateFocus`] events can be sent to navigate from other input sources.
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    gamepad::{Gamepad, GamepadButton, GamepadButtonType, Gamepads},
+    keyboard::KeyCode,
+    ButtonInput,
+};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::prelude::*;
+use bevy_render::{
+    camera::{Camera, RenderTarget},
+    view::InheritedVisibility,
+};
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::Uuid;
+use bevy_window::{PrimaryWindow, WindowRef};
+
+use bevy_picking_core::{
+    pointer::{InputMove, InputPress, Location, PointerButton, PointerDisabled, PointerId},
+    PickSet, Pickable, PointerCoreBundle,
+};
+
+/// The id of the pointer spawned by the [`FocusNavigationPlugin`].
+pub const NAVIGATION_POINTER_ID: PointerId =
+    PointerId::Custom(Uuid::from_u128(0x2d9b_61f0_3c7e_4a18_b5d2_8e4f_a0c3_79b6));
+
+/// Adds keyboard and gamepad focus navigation. See the [module docs](self).
+pub struct FocusNavigationPlugin;
+impl Plugin for FocusNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusNavigationSettings>()
+            .init_resource::<NavigationFocus>()
+            .add_event::<NavigateFocus>()
+            .add_systems(Startup, spawn_navigation_pointer)
+            .add_systems(
+                First,
+                (navigation_input_events, navigate_focus)
+                    .chain()
+                    .in_set(PickSet::Input),
+            )
+            .register_type::<FocusNavigable>()
+            .register_type::<NavigationFocus>()
+            .register_type::<NavigationDirection>()
+            .register_type::<NavigationAction>()
+            .register_type::<FocusNavigationSettings>();
+    }
+}
+
+/// Marks an entity that can be focused with the [`FocusNavigationPlugin`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct FocusNavigable;
+
+/// The entity that is currently focused by navigation, if any.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct NavigationFocus {
+    /// The focused entity. Set this to focus an entity directly.
+    pub entity: Option<Entity>,
+}
+
+/// A direction on screen to move the [`NavigationFocus`] in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum NavigationDirection {
+    /// Towards the top of the window.
+    Up,
+    /// Towards the bottom of the window.
+    Down,
+    /// Towards the left of the window.
+    Left,
+    /// Towards the right of the window.
+    Right,
+}
+
+impl NavigationDirection {
+    /// The direction as a unit vector, in window coordinates, where y increases downwards.
+    pub fn as_vec2(self) -> Vec2 {
+        match self {
+            NavigationDirection::Up => Vec2::NEG_Y,
+            NavigationDirection::Down => Vec2::Y,
+            NavigationDirection::Left => Vec2::NEG_X,
+            NavigationDirection::Right => Vec2::X,
+        }
+    }
+}
+
+/// Moves the [`NavigationFocus`] to the nearest [`FocusNavigable`] entity in a direction. When
+/// nothing is focused, the entity nearest to the top left of the window is focused instead.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NavigateFocus(pub NavigationDirection);
+
+/// What an input does when it is pressed, in the [`FocusNavigationSettings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum NavigationAction {
+    /// Moves the focus in this direction.
+    Move(NavigationDirection),
+    /// Presses this button of the navigation pointer on the focused entity, while the input is
+    /// held.
+    Press(PointerButton),
+}
+
+/// Configures which inputs drive the [`FocusNavigationPlugin`].
+#[derive(Debug, Clone, Resource, Reflect, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct FocusNavigationSettings {
+    /// The keys that navigate, and what they do. Defaults to the arrow keys to move, and enter and
+    /// space to press the primary button.
+    pub keys: Vec<(KeyCode, NavigationAction)>,
+    /// The gamepad that navigates. If `None`, the first connected gamepad is used.
+    pub gamepad: Option<Gamepad>,
+    /// The gamepad buttons that navigate, and what they do. Defaults to the d-pad to move, and the
+    /// south button to press the primary button. Note that the south button also presses the
+    /// primary button of the [`GamepadPointerPlugin`](crate::gamepad::GamepadPointerPlugin)'s
+    /// pointer by default, if it is added too.
+    pub gamepad_buttons: Vec<(GamepadButtonType, NavigationAction)>,
+}
+
+impl Default for FocusNavigationSettings {
+    fn default() -> Self {
+        use NavigationAction::*;
+        use NavigationDirection::*;
+        Self {
+            keys: vec![
+                (KeyCode::ArrowUp, Move(Up)),
+                (KeyCode::ArrowDown, Move(Down)),
+                (KeyCode::ArrowLeft, Move(Left)),
+                (KeyCode::ArrowRight, Move(Right)),
+                (KeyCode::Enter, Press(PointerButton::Primary)),
+                (KeyCode::Space, Press(PointerButton::Primary)),
+            ],
+            gamepad: None,
+            gamepad_buttons: vec![
+                (GamepadButtonType::DPadUp, Move(Up)),
+                (GamepadButtonType::DPadDown, Move(Down)),
+                (GamepadButtonType::DPadLeft, Move(Left)),
+                (GamepadButtonType::DPadRight, Move(Right)),
+                (GamepadButtonType::South, Press(PointerButton::Primary)),
+            ],
+        }
+    }
+}
+
+/// Spawns the navigation pointer, disabled until something is focused.
+pub fn spawn_navigation_pointer(mut commands: Commands) {
+    commands.spawn((
+        PointerCoreBundle::new(NAVIGATION_POINTER_ID),
+        PointerDisabled,
+        #[cfg(feature = "selection")]
+        bevy_picking_selection::PointerMultiselect::default(),
+    ));
+}
+
+/// Sends [`NavigateFocus`] events, and navigation pointer presses, from the keyboard and gamepad
+/// inputs in the [`FocusNavigationSettings`]. Presses are only sent while something is focused.
+pub fn navigation_input_events(
+    // Input
+    settings: Res<FocusNavigationSettings>,
+    focus: Res<NavigationFocus>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    // Output
+    mut navigate: EventWriter<NavigateFocus>,
+    mut pointer_presses: EventWriter<InputPress>,
+) {
+    let gamepad = settings.gamepad.or_else(|| gamepads.iter().next());
+    let gamepad_buttons = &gamepad_buttons;
+    let key_actions = settings
+        .keys
+        .iter()
+        .map(|(key, action)| (keys.just_pressed(*key), keys.just_released(*key), action));
+    let gamepad_actions = gamepad.into_iter().flat_map(|gamepad| {
+        settings
+            .gamepad_buttons
+            .iter()
+            .map(move |(button, action)| {
+                let button = GamepadButton::new(gamepad, *button);
+                (
+                    gamepad_buttons.just_pressed(button),
+                    gamepad_buttons.just_released(button),
+                    action,
+                )
+            })
+    });
+    for (pressed, released, action) in key_actions.chain(gamepad_actions) {
+        match *action {
+            NavigationAction::Move(direction) if pressed => {
+                navigate.send(NavigateFocus(direction));
+            }
+            NavigationAction::Press(button) if focus.entity.is_some() => {
+                if pressed {
+                    pointer_presses.send(InputPress::new_down(NAVIGATION_POINTER_ID, button));
+                }
+                if released {
+                    pointer_presses.send(InputPress::new_up(NAVIGATION_POINTER_ID, button));
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Moves the [`NavigationFocus`] for each [`NavigateFocus`] event, then moves the navigation
+/// pointer to the focused entity, or disables it while nothing is focused. See the
+/// [module docs](self#screen-positions).
+pub fn navigate_focus(
+    // Input
+    mut navigate: EventReader<NavigateFocus>,
+    navigables: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            Option<&Pickable>,
+            Option<&InheritedVisibility>,
+        ),
+        With<FocusNavigable>,
+    >,
+    #[cfg(feature = "bevy_ui")] nodes: Query<&bevy_ui::Node>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    pointers: Query<(Entity, &PointerId, Has<PointerDisabled>)>,
+    // Local
+    mut last_position: Local<Option<Vec2>>,
+    // Output
+    mut focus: ResMut<NavigationFocus>,
+    mut commands: Commands,
+    mut pointer_move: EventWriter<InputMove>,
+) {
+    let Ok(window_entity) = primary_window.get_single() else {
+        return;
+    };
+    let Some(target) =
+        RenderTarget::Window(WindowRef::Entity(window_entity)).normalize(Some(window_entity))
+    else {
+        return;
+    };
+    let mut cameras: Vec<_> = cameras
+        .iter()
+        .filter(|(camera, _)| {
+            camera.is_active
+                && camera.target.normalize(Some(window_entity)).as_ref() == Some(&target)
+        })
+        .collect();
+    cameras.sort_by_key(|(camera, _)| std::cmp::Reverse(camera.order));
+
+    // The on-screen rectangle of a navigable entity, in logical pixels.
+    let screen_rect = |entity: Entity| -> Option<Rect> {
+        let (_, transform, pickable, visibility) = navigables.get(entity).ok()?;
+        if pickable.is_some_and(|pickable| !pickable.is_hoverable)
+            || visibility.is_some_and(|visibility| !visibility.get())
+        {
+            return None;
+        }
+        #[cfg(feature = "bevy_ui")]
+        if let Ok(node) = nodes.get(entity) {
+            return Some(node.logical_rect(transform));
+        }
+        cameras.iter().find_map(|(camera, camera_transform)| {
+            let viewport = camera.logical_viewport_rect()?;
+            let position =
+                camera.world_to_viewport(camera_transform, transform.translation())? + viewport.min;
+            viewport
+                .contains(position)
+                .then(|| Rect::from_center_size(position, Vec2::ZERO))
+        })
+    };
+
+    for NavigateFocus(direction) in navigate.read() {
+        let current = focus.entity.and_then(screen_rect);
+        let candidates = navigables
+            .iter()
+            .filter(|(entity, ..)| Some(*entity) != focus.entity)
+            .filter_map(|(entity, ..)| Some((entity, screen_rect(entity)?)));
+        let next = match current {
+            Some(current) => candidates
+                .filter_map(|(entity, rect)| {
+                    Some((
+                        entity,
+                        navigation_score(current, rect, direction.as_vec2())?,
+                    ))
+                })
+                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(entity, _)| entity),
+            None => candidates
+                .min_by(|(_, a), (_, b)| a.min.length().total_cmp(&b.min.length()))
+                .map(|(entity, _)| entity),
+        };
+        if let Some(next) = next {
+            focus.entity = Some(next);
+        }
+    }
+
+    let position = focus.entity.and_then(screen_rect).map(|rect| rect.center());
+    if position.is_none() {
+        focus.entity = None;
+    }
+    let Some((pointer_entity, _, is_disabled)) = pointers
+        .iter()
+        .find(|(_, pointer_id, _)| **pointer_id == NAVIGATION_POINTER_ID)
+    else {
+        return;
+    };
+    match position {
+        Some(position) => {
+            if is_disabled {
+                commands.entity(pointer_entity).remove::<PointerDisabled>();
+            }
+            if *last_position != Some(position) {
+                pointer_move.send(InputMove::new(
+                    NAVIGATION_POINTER_ID,
+                    Location { target, position },
+                    position - last_position.unwrap_or(position),
+                ));
+                *last_position = Some(position);
+            }
+        }
+        None if !is_disabled => {
+            commands.entity(pointer_entity).insert(PointerDisabled);
+        }
+        None => (),
+    }
+}
+
+/// Scores how good a candidate is to move the focus to from `current`, in `direction`, where lower
+/// is better, or returns `None` if the candidate isn't in that direction. Candidates that overlap
+/// the current entity across the direction, such as the next button in the same row of a grid, are
+/// always preferred and compared by how far they are along the direction. Other candidates are
+/// compared by how far they are along the direction, plus twice how far they are off to the side.
+fn navigation_score(current: Rect, candidate: Rect, direction: Vec2) -> Option<(bool, f32)> {
+    let delta = candidate.center() - current.center();
+    let along = delta.dot(direction);
+    if along <= f32::EPSILON {
+        return None;
+    }
+    let across = Vec2::new(direction.y.abs(), direction.x.abs());
+    let (current_min, current_max) = (current.min.dot(across), current.max.dot(across));
+    let (candidate_min, candidate_max) = (candidate.min.dot(across), candidate.max.dot(across));
+    let overlaps = candidate_min <= current_max && current_min <= candidate_max;
+    if overlaps {
+        return Some((false, along));
+    }
+    let off_axis = (delta - direction * along).length();
+    Some((true, along + 2.0 * off_axis))
+}
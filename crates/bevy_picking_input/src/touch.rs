@@ -1,10 +1,13 @@
 //! Provides sensible defaults for touch picking inputs.
 
+use std::time::Duration;
+
 use bevy_ecs::prelude::*;
 use bevy_hierarchy::DespawnRecursiveExt;
-use bevy_input::touch::{TouchInput, TouchPhase};
+use bevy_input::touch::{ForceTouch, TouchInput, TouchPhase};
 use bevy_math::Vec2;
 use bevy_render::camera::RenderTarget;
+use bevy_time::{Real, Time};
 use bevy_utils::{tracing::debug, HashMap, HashSet};
 use bevy_window::{PrimaryWindow, WindowRef};
 
@@ -14,6 +17,118 @@ use bevy_picking_core::{
     PointerCoreBundle,
 };
 
+use crate::InputPluginSettings;
+
+/// Maps the touches reported by `winit` to the [`PointerId`]s of their pointers.
+///
+/// By default, a touch's pointer is `PointerId::Touch` with its raw touch id. Raw ids are not
+/// guaranteed to be unique across windows, so a touch in one window can be mistaken for a
+/// continuing touch in another. When [`InputPluginSettings::is_touch_namespaced_by_window`] is
+/// enabled, each new touch is instead assigned a fresh id, keyed by both its window and its raw id,
+/// and [`PointerId::get_touch_id`] no longer matches the raw touch id. Use [`Self::get`] to find the
+/// pointer for a touch in either mode.
+///
+/// ### Re-touching
+///
+/// When a finger lifts and touches again within the
+/// [`InputPluginSettings::touch_retouch_window`], near where it lifted, the new touch reuses the
+/// pointer id of the old one, so gestures keyed by pointer id are not interrupted. The pointer
+/// entity itself is still despawned when the touch ends, and spawned again for the new touch. Ids
+/// that are not reused within the window are forgotten, and touches that start anywhere else get a
+/// fresh id as usual.
+#[derive(Debug, Default, Resource)]
+pub struct TouchPointerIds {
+    active: HashMap<(Entity, u64), PointerId>,
+    ended: Vec<PointerId>,
+    released: Vec<ReleasedTouch>,
+    next_id: u64,
+}
+
+/// A touch that ended recently, whose pointer id can still be reused.
+#[derive(Debug, Clone, Copy)]
+struct ReleasedTouch {
+    pointer: PointerId,
+    window: Entity,
+    position: Vec2,
+    time: Duration,
+}
+
+impl TouchPointerIds {
+    /// Returns the pointer of the active touch with the raw `touch_id` in `window`, if any.
+    pub fn get(&self, window: Entity, touch_id: u64) -> Option<PointerId> {
+        self.active.get(&(window, touch_id)).copied()
+    }
+
+    /// Returns `true` if `pointer` belongs to an active touch, or to an ended touch whose entity has
+    /// not been despawned yet.
+    fn is_in_use(&self, pointer: PointerId) -> bool {
+        self.active.values().any(|active| *active == pointer) || self.ended.contains(&pointer)
+    }
+
+    /// Returns a touch pointer id that is not used by any touch.
+    fn fresh_id(&mut self) -> PointerId {
+        loop {
+            self.next_id = self.next_id.wrapping_add(1);
+            let pointer = PointerId::Touch(self.next_id);
+            if !self.is_in_use(pointer) {
+                return pointer;
+            }
+        }
+    }
+
+    /// Forgets released touches that can no longer be reused.
+    fn forget_released(&mut self, now: Duration, settings: &InputPluginSettings) {
+        self.released
+            .retain(|touch| now.saturating_sub(touch.time) <= settings.touch_retouch_window);
+    }
+
+    /// Assigns a pointer to a touch that just started, at `position` in `window`.
+    fn start(
+        &mut self,
+        window: Entity,
+        touch_id: u64,
+        position: Vec2,
+        now: Duration,
+        settings: &InputPluginSettings,
+    ) -> PointerId {
+        self.forget_released(now, settings);
+        let retouched = self.released.iter().position(|touch| {
+            touch.window == window
+                && touch.position.distance(position) <= settings.touch_retouch_distance
+                && !self.is_in_use(touch.pointer)
+        });
+        let pointer = match retouched {
+            Some(index) => self.released.swap_remove(index).pointer,
+            None if settings.is_touch_namespaced_by_window => self.fresh_id(),
+            None if self.is_in_use(PointerId::Touch(touch_id)) => self.fresh_id(),
+            None => PointerId::Touch(touch_id),
+        };
+        // This id belongs to a new touch now, so it must not be reused by a later one.
+        self.released.retain(|touch| touch.pointer != pointer);
+        self.active.insert((window, touch_id), pointer);
+        pointer
+    }
+
+    /// Releases the pointer of a touch that just ended at `position`, so it can be despawned.
+    fn end(
+        &mut self,
+        window: Entity,
+        touch_id: u64,
+        position: Vec2,
+        now: Duration,
+    ) -> Option<PointerId> {
+        let pointer = self.active.remove(&(window, touch_id))?;
+        self.ended.push(pointer);
+        self.released.push(ReleasedTouch {
+            pointer,
+            window,
+            position,
+            time: now,
+        });
+        Some(pointer)
+    }
+}
+
 /// Sends touch pointer events to be consumed by the core plugin
 ///
 /// IMPORTANT: the commands must be flushed after this system is run because we need spawning to
@@ -22,16 +137,18 @@ pub fn touch_pick_events(
     // Input
     mut touches: EventReader<TouchInput>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
+    settings: Res<InputPluginSettings>,
+    time: Res<Time<Real>>,
     // Local
-    mut location_cache: Local<HashMap<u64, TouchInput>>,
+    mut location_cache: Local<HashMap<PointerId, TouchInput>>,
     // Output
     mut commands: Commands,
+    mut touch_pointers: ResMut<TouchPointerIds>,
     mut input_moves: EventWriter<InputMove>,
     mut input_presses: EventWriter<InputPress>,
     mut cancel_events: EventWriter<PointerCancel>,
 ) {
     for touch in touches.read() {
-        let pointer = PointerId::Touch(touch.id);
         let location = Location {
             target: match RenderTarget::Window(WindowRef::Entity(touch.window))
                 .normalize(primary_window.get_single().ok())
@@ -43,6 +160,13 @@ pub fn touch_pick_events(
         };
         match touch.phase {
             TouchPhase::Started => {
+                let pointer = touch_pointers.start(
+                    touch.window,
+                    touch.id,
+                    touch.position,
+                    time.elapsed(),
+                    &settings,
+                );
                 debug!("Spawning pointer {:?}", pointer);
                 commands.spawn((
                     PointerCoreBundle::new(pointer).with_location(location.clone()),
@@ -50,27 +174,36 @@ pub fn touch_pick_events(
                     bevy_picking_selection::PointerMultiselect::default(),
                 ));
 
-                input_moves.send(InputMove::new(pointer, location, Vec2::ZERO));
+                input_moves.send(
+                    InputMove::new(pointer, location, Vec2::ZERO).with_pressure(pressure(touch)),
+                );
                 input_presses.send(InputPress::new_down(pointer, PointerButton::Primary));
-                location_cache.insert(touch.id, *touch);
+                location_cache.insert(pointer, *touch);
             }
             TouchPhase::Moved => {
+                let Some(pointer) = touch_pointers.get(touch.window, touch.id) else {
+                    continue;
+                };
                 // Send a move event only if it isn't the same as the last one
-                if let Some(last_touch) = location_cache.get(&touch.id) {
+                if let Some(last_touch) = location_cache.get(&pointer) {
                     if last_touch == touch {
                         continue;
                     }
-                    input_moves.send(InputMove::new(
-                        pointer,
-                        location,
-                        touch.position - last_touch.position,
-                    ));
+                    input_moves.send(
+                        InputMove::new(pointer, location, touch.position - last_touch.position)
+                            .with_pressure(pressure(touch)),
+                    );
                 }
-                location_cache.insert(touch.id, *touch);
+                location_cache.insert(pointer, *touch);
             }
             TouchPhase::Ended | TouchPhase::Canceled => {
+                let Some(pointer) =
+                    touch_pointers.end(touch.window, touch.id, touch.position, time.elapsed())
+                else {
+                    continue;
+                };
                 input_presses.send(InputPress::new_up(pointer, PointerButton::Primary));
-                location_cache.remove(&touch.id);
+                location_cache.remove(&pointer);
                 cancel_events.send(PointerCancel {
                     pointer_id: pointer,
                 });
@@ -79,26 +212,37 @@ pub fn touch_pick_events(
     }
 }
 
+/// Returns the pressure of a touch, normalized from `0.0` to `1.0`, if the device reports force.
+fn pressure(touch: &TouchInput) -> Option<f32> {
+    match touch.force? {
+        ForceTouch::Calibrated {
+            force,
+            max_possible_force,
+            ..
+        } => (max_possible_force > 0.0).then(|| (force / max_possible_force) as f32),
+        ForceTouch::Normalized(force) => Some(force as f32),
+    }
+}
+
 /// Deactivates unused touch pointers.
 ///
 /// Because each new touch gets assigned a new ID, we need to remove the pointers associated with
-/// touches that are no longer active.
+/// touches that are no longer active. This also forgets the ids of released touches once they can
+/// no longer be reused.
 pub fn deactivate_touch_pointers(
     mut commands: Commands,
     mut despawn_list: Local<HashSet<(Entity, PointerId)>>,
     pointers: Query<(Entity, &PointerId)>,
-    mut touches: EventReader<TouchInput>,
+    settings: Res<InputPluginSettings>,
+    time: Res<Time<Real>>,
+    mut touch_pointers: ResMut<TouchPointerIds>,
 ) {
-    for touch in touches.read() {
-        match touch.phase {
-            TouchPhase::Ended | TouchPhase::Canceled => {
-                for (entity, pointer) in &pointers {
-                    if pointer.get_touch_id() == Some(touch.id) {
-                        despawn_list.insert((entity, *pointer));
-                    }
-                }
+    touch_pointers.forget_released(time.elapsed(), &settings);
+    for ended in touch_pointers.ended.drain(..) {
+        for (entity, pointer) in &pointers {
+            if *pointer == ended {
+                despawn_list.insert((entity, *pointer));
             }
-            _ => {}
         }
     }
     // A hash set is used to prevent despawning the same entity twice.
@@ -107,3 +251,82 @@ pub fn deactivate_touch_pointers(
         commands.entity(entity).despawn_recursive();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::prelude::*;
+    use bevy_picking_core::{CorePlugin, InteractionPlugin};
+
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .add_event::<TouchInput>()
+            .init_resource::<InputPluginSettings>()
+            .init_resource::<TouchPointerIds>()
+            .init_resource::<Time<Real>>()
+            .add_systems(First, (touch_pick_events, apply_deferred).chain())
+            .add_systems(Last, deactivate_touch_pointers);
+        app
+    }
+
+    fn touch(window: Entity, id: u64, phase: TouchPhase, position: Vec2) -> TouchInput {
+        TouchInput {
+            phase,
+            position,
+            window,
+            force: None,
+            id,
+        }
+    }
+
+    fn pointer_ids(app: &mut App) -> Vec<PointerId> {
+        let mut pointers = app.world.query::<&PointerId>();
+        pointers.iter(&app.world).copied().collect()
+    }
+
+    #[test]
+    fn retouch_within_a_frame_keeps_the_new_pointer() {
+        let mut app = app();
+        let window = app.world.spawn_empty().id();
+        app.world
+            .send_event(touch(window, 0, TouchPhase::Started, Vec2::ZERO));
+        app.update();
+        let lifted = app.world.resource::<TouchPointerIds>().get(window, 0);
+
+        // The finger lifts and touches again nearby, before the old pointer is despawned.
+        app.world
+            .send_event(touch(window, 0, TouchPhase::Ended, Vec2::ZERO));
+        app.world
+            .send_event(touch(window, 1, TouchPhase::Started, Vec2::X));
+        app.update();
+
+        let retouched = app.world.resource::<TouchPointerIds>().get(window, 1);
+        assert!(retouched.is_some());
+        assert_ne!(retouched, lifted);
+        assert_eq!(pointer_ids(&mut app), vec![retouched.unwrap()]);
+    }
+
+    #[test]
+    fn same_touch_id_in_two_windows_gets_two_pointers() {
+        let mut app = app();
+        app.world
+            .resource_mut::<InputPluginSettings>()
+            .is_touch_namespaced_by_window = true;
+        let windows = [app.world.spawn_empty().id(), app.world.spawn_empty().id()];
+        for window in windows {
+            app.world
+                .send_event(touch(window, 0, TouchPhase::Started, Vec2::ZERO));
+        }
+        app.update();
+
+        let touch_pointers = app.world.resource::<TouchPointerIds>();
+        let first = touch_pointers.get(windows[0], 0).unwrap();
+        let second = touch_pointers.get(windows[1], 0).unwrap();
+        assert_ne!(first, second);
+        let pointers = pointer_ids(&mut app);
+        assert_eq!(pointers.len(), 2);
+        assert!(pointers.contains(&first) && pointers.contains(&second));
+    }
+}
@@ -13,11 +13,9 @@ use bevy_input::{keyboard::KeyCode, ButtonInput};
 use bevy_reflect::prelude::*;
 use bevy_utils::hashbrown::HashSet;
 
-use bevy_eventlistener::prelude::*;
-
 use bevy_picking_core::{
-    events::{Click, Down, Pointer},
-    pointer::{InputPress, PointerButton, PointerId, PointerLocation},
+    events::{Click, Down, Pointer, PointerEventWriter, PointerListenerPlugin},
+    pointer::{DebouncedPresses, PointerButton, PointerDisabled, PointerId, PointerLocation},
     PickSet, PickingPluginsSettings,
 };
 
@@ -66,8 +64,8 @@ impl Plugin for SelectionPlugin {
             .add_event::<Pointer<Select>>()
             .add_event::<Pointer<Deselect>>()
             .add_plugins((
-                EventListenerPlugin::<Pointer<Select>>::default(),
-                EventListenerPlugin::<Pointer<Deselect>>::default(),
+                PointerListenerPlugin::<Select>::default(),
+                PointerListenerPlugin::<Deselect>::default(),
             ))
             .add_systems(
                 PreUpdate,
@@ -142,14 +140,14 @@ pub fn multiselect_events(
 pub fn send_selection_events(
     settings: Res<SelectionPluginSettings>,
     mut pointer_down: EventReader<Pointer<Down>>,
-    mut presses: EventReader<InputPress>,
+    mut presses: DebouncedPresses,
     mut pointer_click: EventReader<Pointer<Click>>,
-    pointers: Query<(&PointerId, &PointerMultiselect, &PointerLocation)>,
+    pointers: Query<(&PointerId, &PointerMultiselect, &PointerLocation), Without<PointerDisabled>>,
     no_deselect: Query<&NoDeselect>,
     selectables: Query<(Entity, &PickSelection)>,
     // Output
-    mut selections: EventWriter<Pointer<Select>>,
-    mut deselections: EventWriter<Pointer<Deselect>>,
+    mut selections: PointerEventWriter<Select>,
+    mut deselections: PointerEventWriter<Deselect>,
 ) {
     // Pointers that have clicked on something.
     let mut pointer_down_list = HashSet::new();
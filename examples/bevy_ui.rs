@@ -6,7 +6,7 @@ use bevy_mod_picking::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins.set(low_latency_window_plugin()))
-        .add_plugins(DefaultPickingPlugins)
+        .add_plugins((DefaultPickingPlugins, CursorIconPlugin))
         .add_systems(Startup, (setup_3d, setup_ui).chain())
         .add_systems(Update, (update_button_colors, set_camera_viewports))
         .insert_resource(UiScale(1.5))
@@ -186,6 +186,8 @@ impl<'a> NewButton for EntityCommands<'a> {
                 On::<Pointer<Click>>::run(move || info!("Button {text_string} pressed!")),
                 // Buttons should not deselect other things:
                 NoDeselect,
+                // Show a hand cursor while the button is hovered:
+                HoverCursor(CursorIcon::Pointer),
             ))
             .with_children(|parent| {
                 parent.spawn((
@@ -39,6 +39,12 @@ fn setup(
             ..default()
         },
         PickableBundle::default(), // <- Makes the mesh pickable.
+        // Events include the window the pointer was on, so each window can be handled differently.
+        On::<Pointer<Click>>::run(|event: Listener<Pointer<Click>>, windows: Query<&Window>| {
+            if let Some(window) = event.window().and_then(|window| windows.get(window).ok()) {
+                info!("Clicked the cube in {:?}", window.title);
+            }
+        }),
     ));
     commands.spawn(PointLightBundle {
         point_light: PointLight {
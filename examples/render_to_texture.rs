@@ -108,6 +108,7 @@ fn ui(
                             position: Vec2::new(pos.x, pos.y),
                         },
                         delta: Vec2::ZERO,
+                        pressure: None,
                     });
                 }
             });
@@ -0,0 +1,103 @@
+//! Sets the cursor icon of windows from the entities hovered by their pointers.
+//!
+//! This is not part of the [`DefaultPickingPlugins`](crate::DefaultPickingPlugins). Add the
+//! [`CursorIconPlugin`], then add a [`HoverCursor`] to pickable entities to choose the icon shown
+//! while they are hovered:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_window::CursorIcon;
+//! # use bevy_mod_picking::{cursor::HoverCursor, prelude::*};
+//! fn setup(mut commands: Commands) {
+//!     commands.spawn((PickableBundle::default(), HoverCursor(CursorIcon::Pointer)));
+//! }
+//! ```
+//!
+//! ### Pointers and windows
+//!
+//! Each window shows the icon of the nearest entity with a [`HoverCursor`] in the
+//! [`HoverMap`](focus::HoverMap) of a pointer on that window. When several pointers are on the
+//! same window, the mouse pointer wins, and the other pointers are only used while the mouse isn't
+//! hovering an entity with a [`HoverCursor`]. Once no pointer on a window hovers such an entity,
+//! the window's cursor icon is set back to the icon it had before the plugin changed it.
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use bevy_utils::HashMap;
+use bevy_window::{CursorIcon, Window};
+
+use crate::{focus::HoverMap, pointer::PointerLocation, *};
+
+/// Sets the cursor icon of windows from the hovered [`HoverCursor`]s. See the
+/// [module docs](self).
+pub struct CursorIconPlugin;
+impl Plugin for CursorIconPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            update_cursor_icons.in_set(picking_core::PickSet::PostFocus),
+        )
+        .register_type::<HoverCursor>();
+    }
+}
+
+/// The cursor icon to show while this entity is hovered, when the [`CursorIconPlugin`] is added.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct HoverCursor(pub CursorIcon);
+
+/// Sets the cursor icon of each window to the [`HoverCursor`] hovered by its pointers, and restores
+/// it once none is hovered. See the [module docs](self#pointers-and-windows).
+pub fn update_cursor_icons(
+    hover_map: Res<HoverMap>,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cursors: Query<&HoverCursor>,
+    mut windows: Query<(Entity, &mut Window)>,
+    // The icon each window had before it was changed by this system.
+    mut original_icons: Local<HashMap<Entity, CursorIcon>>,
+) {
+    // The hovered icon of each window, the depth of its entity, and whether it is from the mouse.
+    let mut hovered_icons: HashMap<Entity, (CursorIcon, f32, bool)> = HashMap::new();
+    for (pointer_id, pointer) in &pointers {
+        let Some(window) = pointer.location().and_then(|location| location.window()) else {
+            continue;
+        };
+        let Some((icon, depth)) = hover_map
+            .get(pointer_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|(entity, hit)| Some((cursors.get(*entity).ok()?.0, hit.depth)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        else {
+            continue;
+        };
+        let is_mouse = pointer_id.is_mouse();
+        let replaces = |(_, current_depth, current_is_mouse): &(CursorIcon, f32, bool)| {
+            (is_mouse && !current_is_mouse)
+                || (is_mouse == *current_is_mouse && depth < *current_depth)
+        };
+        if hovered_icons.get(&window).map_or(true, replaces) {
+            hovered_icons.insert(window, (icon, depth, is_mouse));
+        }
+    }
+
+    for (window_entity, mut window) in &mut windows {
+        let icon = match hovered_icons.get(&window_entity) {
+            Some((icon, ..)) => {
+                original_icons
+                    .entry(window_entity)
+                    .or_insert(window.cursor.icon);
+                *icon
+            }
+            None => match original_icons.remove(&window_entity) {
+                Some(original) => original,
+                None => continue,
+            },
+        };
+        if window.cursor.icon != icon {
+            window.cursor.icon = icon;
+        }
+    }
+    original_icons.retain(|window, _| windows.contains(*window));
+}
@@ -94,21 +94,43 @@ impl Plugin for DebugPickingPlugin {
                 (
                     // This leaves room to easily change the log-level associated
                     // with different events, should that be desired.
-                    log_event_debug::<pointer::InputMove>.run_if(DebugPickingMode::is_noisy),
-                    log_event_debug::<pointer::InputPress>.run_if(DebugPickingMode::is_noisy),
-                    log_pointer_event_debug::<events::Over>,
-                    log_pointer_event_debug::<events::Out>,
-                    log_pointer_event_debug::<events::Down>,
-                    log_pointer_event_debug::<events::Up>,
-                    log_pointer_event_debug::<events::Click>,
-                    log_pointer_event_trace::<events::Move>.run_if(DebugPickingMode::is_noisy),
-                    log_pointer_event_debug::<events::DragStart>,
-                    log_pointer_event_trace::<events::Drag>.run_if(DebugPickingMode::is_noisy),
-                    log_pointer_event_debug::<events::DragEnd>,
-                    log_pointer_event_debug::<events::DragEnter>,
-                    log_pointer_event_trace::<events::DragOver>.run_if(DebugPickingMode::is_noisy),
-                    log_pointer_event_debug::<events::DragLeave>,
-                    log_pointer_event_debug::<events::Drop>,
+                    (
+                        log_event_debug::<pointer::InputMove>.run_if(DebugPickingMode::is_noisy),
+                        log_event_debug::<pointer::InputPress>.run_if(DebugPickingMode::is_noisy),
+                        log_event_debug::<pointer::InputScroll>.run_if(DebugPickingMode::is_noisy),
+                    ),
+                    (
+                        log_pointer_event_debug::<events::Over>,
+                        log_pointer_event_debug::<events::Out>,
+                        log_pointer_event_debug::<events::HoverStart>,
+                        log_pointer_event_debug::<events::Down>,
+                        log_pointer_event_debug::<events::Up>,
+                        log_pointer_event_debug::<events::Click>,
+                        log_pointer_event_debug::<events::DoubleClick>,
+                        log_pointer_event_debug::<events::LongPress>,
+                        log_pointer_event_trace::<events::Pinch>.run_if(DebugPickingMode::is_noisy),
+                        log_pointer_event_trace::<events::Rotate>
+                            .run_if(DebugPickingMode::is_noisy),
+                        log_pointer_event_trace::<events::Move>.run_if(DebugPickingMode::is_noisy),
+                        log_pointer_event_trace::<events::Scroll>
+                            .run_if(DebugPickingMode::is_noisy),
+                    ),
+                    (
+                        log_pointer_event_debug::<events::DragStart>,
+                        log_pointer_event_trace::<events::Drag>.run_if(DebugPickingMode::is_noisy),
+                        log_pointer_event_debug::<events::DragEnd>,
+                        log_pointer_event_debug::<events::DragEnter>,
+                        log_pointer_event_trace::<events::DragOver>
+                            .run_if(DebugPickingMode::is_noisy),
+                        log_pointer_event_debug::<events::DragLeave>,
+                        log_pointer_event_debug::<events::Drop>,
+                        log_pointer_event_debug::<events::DragViewportExit>,
+                        log_pointer_event_debug::<events::DragViewportEnter>,
+                        log_pointer_event_debug::<events::Idle>,
+                        log_pointer_event_debug::<events::Wake>,
+                        log_pointer_event_debug::<events::WindowEnter>,
+                        log_pointer_event_debug::<events::WindowLeave>,
+                    ),
                 )
                     .distributive_run_if(DebugPickingMode::is_enabled)
                     .in_set(picking_core::PickSet::Last),
@@ -255,9 +277,9 @@ pub fn update_debug_data(
             PointerButton::iter()
                 .flat_map(|button| {
                     drag_map
-                        .get(&(id, button))
-                        .and_then(|entry| entry.values().next())
-                        .map(|entry| (button, entry.start_pos))
+                        .drags(id, button)
+                        .next()
+                        .map(|(_, entry)| (button, entry.start_pos))
                 })
                 .collect()
         };
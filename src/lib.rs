@@ -26,7 +26,8 @@
 //! This is where event listeners come in. Reacting to these interaction events on a specific entity
 //! is made possible with the [`On<Event>`](On) component. When events are generated, they bubble up
 //! the entity hierarchy starting from their target, looking for these event listener components.
-//! (See [`bevy_eventlistener`] for details.)
+//! (See [`bevy_eventlistener`] for details.) Bubbling can be turned off for individual event types
+//! with [`PointerBubbling`](events::PointerBubbling).
 //!
 //! This allows you to run callbacks when any children of an entity are interacted with, and leads
 //! to succinct, expressive code:
@@ -137,7 +138,8 @@
 //! controlled by some other input, you can do that here.
 //!
 //! Because pointer positions and presses are driven by these events, you can use them to mock
-//! inputs for testing.
+//! inputs for testing. The `testing` feature adds helpers that send these events to a headless
+//! app.
 //!
 //! After inputs are generated, they are then collected to update the current [`PointerLocation`]
 //! for each pointer.
@@ -196,6 +198,7 @@ pub use bevy_picking_input::{self as input};
 pub use bevy_picking_highlight as highlight;
 #[cfg(feature = "selection")]
 pub use bevy_picking_selection as selection;
+pub mod cursor;
 #[cfg(feature = "debug")]
 pub mod debug;
 
@@ -221,15 +224,24 @@ pub mod prelude {
     pub use crate::debug::{DebugPickingMode, DebugPickingPlugin};
     pub use crate::{
         backends,
+        cursor::{CursorIconPlugin, HoverCursor},
         events::{
-            Click, Down, Drag, DragEnd, DragEnter, DragLeave, DragOver, DragStart, Drop, Move, Out,
-            Over, Pointer, Up,
+            AnyPointerEvent, BubbleBoundary, Click, ClickEmission, DoubleClick, Down, Drag,
+            DragConstraint, DragEnd, DragEnter, DragLeave, DragModifier, DragOver, DragPayload,
+            DragPlane, DragStart, DragViewportEnter, DragViewportExit, Drop, HoverDelay,
+            HoverStart, Idle, LongPress, Move, Out, Over, Pinch, Pointer, PointerBubbling, Rotate,
+            Scroll, Up, Wake, WindowEnter, WindowLeave,
         },
-        focus::PickingInteraction,
+        focus::{PickingInteraction, PickingInteractionSource},
         input::prelude::*,
-        picking_core::Pickable,
+        picking_core::{
+            FocusPolicy, PickLayers, PickTarget, Pickable, PickableEvents, PickingShape,
+            PickingStatePlugin,
+        },
         pointer::{
-            PointerButton, PointerId, PointerInteraction, PointerLocation, PointerMap, PointerPress,
+            ActivePointer, KeyModifiers, ModifierCapture, PointerButton, PointerCapture,
+            PointerDisabled, PointerGrab, PointerHitRadius, PointerId, PointerInteraction,
+            PointerLocation, PointerMap, PointerPress, PointerPressure, PointerRadius,
         },
         *,
     };